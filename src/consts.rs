@@ -4,6 +4,9 @@
 /// Constant value for `sqrt(2 * pi)`
 pub const SQRT_2PI: f64 = 2.5066282746310005024157652848110452530069867406099;
 
+/// Constant value for `ln(2)`
+pub const LN_2: f64 = core::f64::consts::LN_2;
+
 /// Constant value for `ln(pi)`
 pub const LN_PI: f64 = 1.1447298858494001741434273513530587116472948129153;
 
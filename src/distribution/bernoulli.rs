@@ -161,6 +161,12 @@ impl Max<u64> for Bernoulli {
 }
 
 impl Distribution<f64> for Bernoulli {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its two-point support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the bernoulli
     /// distribution
     ///
@@ -321,4 +327,31 @@ mod testing {
         test_absolute(0.3, 0.3, 1e-15, sf(0));
         test_absolute(0.7, 0.7, 1e-15, sf(0));
     }
+
+    #[test]
+    fn test_pmf() {
+        let pmf = |arg: u64| move |x: Bernoulli| x.pmf(arg);
+        test_absolute(0.3, 0.7, 1e-15, pmf(0));
+        test_absolute(0.3, 0.3, 1e-15, pmf(1));
+        test_exact(0.3, 0.0, pmf(2));
+        test_exact(0.0, 1.0, pmf(0));
+        test_exact(1.0, 1.0, pmf(1));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let ln_pmf = |arg: u64| move |x: Bernoulli| x.ln_pmf(arg);
+        test_absolute(0.3, 0.7f64.ln(), 1e-15, ln_pmf(0));
+        test_absolute(0.3, 0.3f64.ln(), 1e-15, ln_pmf(1));
+        test_exact(0.3, f64::NEG_INFINITY, ln_pmf(2));
+    }
+
+    #[test]
+    fn test_moments() {
+        test_exact(0.3, 0.3, |x| x.mean().unwrap());
+        test_absolute(0.3, 0.21, 1e-15, |x| x.variance().unwrap());
+        test_absolute(0.3, 0.8728715609439696, 1e-15, |x| x.skewness().unwrap());
+        test_absolute(0.3, 0.6108643020548935, 1e-15, |x| x.entropy().unwrap());
+        test_absolute(0.5, 2f64.ln(), 1e-15, |x| x.entropy().unwrap());
+    }
 }
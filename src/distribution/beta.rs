@@ -222,6 +222,12 @@ impl Max<f64> for Beta {
 }
 
 impl Distribution<f64> for Beta {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its bounded support `[0, 1]`
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the beta distribution.
     ///
     /// # Formula
@@ -203,6 +203,12 @@ impl Max<u64> for Binomial {
 }
 
 impl Distribution<f64> for Binomial {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its finite support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the binomial distribution
     ///
     /// # Formula
@@ -582,4 +588,26 @@ mod tests {
         test::check_discrete_distribution(&create_ok(0.3, 5), 5);
         test::check_discrete_distribution(&create_ok(0.7, 10), 10);
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_integer() {
+        use ::rand::distributions::Distribution as _;
+        use ::rand::{rngs::StdRng, SeedableRng};
+
+        // integer samples are exact counts, and agree with the f64 samples
+        // drawn from the same stream
+        let n = create_ok(0.5, 200_000);
+        let mut a = StdRng::seed_from_u64(1600);
+        let mut b = StdRng::seed_from_u64(1600);
+        for _ in 0..5 {
+            let k: u64 = n.sample(&mut a);
+            let x: f64 = n.sample(&mut b);
+            assert!(k <= 200_000);
+            assert_eq!(k as f64, x);
+            assert_almost_eq!(k as f64, 100_000.0, 2_000.0);
+        }
+        let k: u64 = create_ok(1.0, 1 << 20).sample(&mut a);
+        assert_eq!(k, 1 << 20);
+    }
 }
@@ -0,0 +1,571 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::{beta, gamma};
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the [Burr Type XII](https://en.wikipedia.org/wiki/Burr_distribution)
+/// (Singh–Maddala) distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Burr, Continuous, ContinuousCDF};
+/// use statrs::prec;
+///
+/// let n = Burr::new(2.0, 3.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.pdf(0.5), 1.2288, 1e-15));
+/// assert!(prec::almost_eq(n.cdf(2.0), 0.992, 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Burr {
+    c: f64,
+    k: f64,
+    scale: f64,
+}
+
+/// Represents the errors that can occur when creating a [`Burr`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum BurrError {
+    /// The shape c is NaN, infinite, zero or less than zero.
+    ShapeCInvalid,
+
+    /// The shape k is NaN, infinite, zero or less than zero.
+    ShapeKInvalid,
+
+    /// The scale is NaN, infinite, zero or less than zero.
+    ScaleInvalid,
+}
+
+impl std::fmt::Display for BurrError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BurrError::ShapeCInvalid => {
+                write!(f, "Shape c is NaN, infinite, zero or less than zero.")
+            }
+            BurrError::ShapeKInvalid => {
+                write!(f, "Shape k is NaN, infinite, zero or less than zero.")
+            }
+            BurrError::ScaleInvalid => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BurrError {}
+
+impl Burr {
+    /// Constructs a new Burr Type XII distribution with shapes `c` and `k`
+    /// and a scale (λ) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `c`, `k` or `scale` are `NaN`, infinite,
+    /// zero or less than zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Burr;
+    ///
+    /// let mut result = Burr::new(2.0, 3.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Burr::new(0.0, 3.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(c: f64, k: f64, scale: f64) -> Result<Burr, BurrError> {
+        if !c.is_finite() || c <= 0.0 {
+            return Err(BurrError::ShapeCInvalid);
+        }
+
+        if !k.is_finite() || k <= 0.0 {
+            return Err(BurrError::ShapeKInvalid);
+        }
+
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(BurrError::ScaleInvalid);
+        }
+
+        Ok(Burr { c, k, scale })
+    }
+
+    /// Returns the shape c of the Burr distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Burr;
+    ///
+    /// let n = Burr::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.c(), 2.0);
+    /// ```
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    /// Returns the shape k of the Burr distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Burr;
+    ///
+    /// let n = Burr::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.k(), 3.0);
+    /// ```
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Returns the scale of the Burr distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Burr;
+    ///
+    /// let n = Burr::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the raw moment `E[X^r]`, which only exists for `r < ck`
+    fn raw_moment(&self, r: f64) -> Option<f64> {
+        if r >= self.c * self.k {
+            return None;
+        }
+        let rc = r / self.c;
+        Some(self.scale.powf(r) * self.k * beta::beta(self.k - rc, 1.0 + rc))
+    }
+}
+
+impl std::fmt::Display for Burr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Burr({},{},{})", self.c, self.k, self.scale)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for Burr {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.gen();
+        self.inverse_cdf(u)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Burr {
+    /// Calculates the cumulative distribution function for the Burr
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - (1 + (x / λ)^c)^(-k)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.k * (x / self.scale).powf(self.c).ln_1p()).exp_m1()
+        }
+    }
+
+    /// Calculates the survival function for the Burr
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (1 + (x / λ)^c)^(-k)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            (-self.k * (x / self.scale).powf(self.c).ln_1p()).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the Burr
+    /// distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in `[0, 1]`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λ ((1 - p)^(-1 / k) - 1)^(1 / c)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("x must be in [0, 1]");
+        }
+
+        self.scale * (-(-p).ln_1p() / self.k).exp_m1().powf(1.0 / self.c)
+    }
+}
+
+impl Min<f64> for Burr {
+    /// Returns the minimum value in the domain of the Burr
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Burr {
+    /// Returns the maximum value in the domain of the Burr
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::INFINITY
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Burr {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for orders below `c k`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.c * self.k
+    }
+
+    /// Returns the mean of the Burr distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ck <= 1`, in which case the mean does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λ k B(k - 1 / c, 1 + 1 / c)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes, `λ` is the scale and `B` is the
+    /// beta function
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1.0)
+    }
+
+    /// Returns the variance of the Burr distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ck <= 2`, in which case the variance does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λ^2 k B(k - 2 / c, 1 + 2 / c) - μ^2
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes, `λ` is the scale, `B` is the
+    /// beta function and `μ` is the mean
+    fn variance(&self) -> Option<f64> {
+        let m2 = self.raw_moment(2.0)?;
+        let mean = self.mean()?;
+        Some(m2 - mean * mean)
+    }
+
+    /// Returns the entropy of the Burr distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(λ / (ck)) + (1 - 1 / c)(ψ(k) + γ) + 1 + 1 / k
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes, `λ` is the scale, `ψ` is the
+    /// digamma function and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        let entr = (self.scale / (self.c * self.k)).ln()
+            + (1.0 - 1.0 / self.c) * (gamma::digamma(self.k) + crate::consts::EULER_MASCHERONI)
+            + 1.0
+            + 1.0 / self.k;
+        Some(entr)
+    }
+
+    /// Returns the skewness of the Burr distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ck <= 3`, in which case the skewness does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (m3 - 3 m1 m2 + 2 m1^3) / (m2 - m1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `mr = λ^r k B(k - r / c, 1 + r / c)` is the `r`-th raw moment
+    fn skewness(&self) -> Option<f64> {
+        let m3 = self.raw_moment(3.0)?;
+        let m2 = self.raw_moment(2.0)?;
+        let m1 = self.raw_moment(1.0)?;
+        Some(skewness_from_moments(m1, m2, m3))
+    }
+}
+
+impl Median<f64> for Burr {
+    /// Returns the median of the Burr distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λ (2^(1 / k) - 1)^(1 / c)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn median(&self) -> f64 {
+        self.scale * (f64::consts::LN_2 / self.k).exp_m1().powf(1.0 / self.c)
+    }
+}
+
+impl Mode<Option<f64>> for Burr {
+    /// Returns the mode of the Burr distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// if c <= 1 {
+    ///     0
+    /// } else {
+    ///     λ ((c - 1) / (ck + 1))^(1 / c)
+    /// }
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn mode(&self) -> Option<f64> {
+        let mode = if self.c <= 1.0 {
+            0.0
+        } else {
+            self.scale * ((self.c - 1.0) / (self.c * self.k + 1.0)).powf(1.0 / self.c)
+        };
+        Some(mode)
+    }
+}
+
+impl Continuous<f64, f64> for Burr {
+    /// Calculates the probability density function for the Burr
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (ck / λ) (x / λ)^(c - 1) (1 + (x / λ)^c)^(-k - 1)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            0.0
+        } else if x == 0.0 {
+            if self.c < 1.0 {
+                f64::INFINITY
+            } else if self.c == 1.0 {
+                self.k / self.scale
+            } else {
+                0.0
+            }
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the Burr
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(ck / λ) + (c - 1) ln(x / λ) - (k + 1) ln(1 + (x / λ)^c)
+    /// ```
+    ///
+    /// where `c` and `k` are the shapes and `λ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            self.pdf(x).ln()
+        } else {
+            let z = x / self.scale;
+            (self.c * self.k / self.scale).ln() + (self.c - 1.0) * z.ln()
+                - (self.k + 1.0) * z.powf(self.c).ln_1p()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+    use crate::function::integration;
+
+    crate::testing_boiler!(c: f64, k: f64, scale: f64; Burr; BurrError);
+
+    #[test]
+    fn test_create() {
+        create_ok(2.0, 3.0, 1.0);
+        create_ok(0.5, 0.5, 0.1);
+        create_ok(10.0, 10.0, 10.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(0.0, 1.0, 1.0, BurrError::ShapeCInvalid);
+        test_create_err(1.0, -1.0, 1.0, BurrError::ShapeKInvalid);
+        test_create_err(1.0, 1.0, f64::NAN, BurrError::ScaleInvalid);
+        create_err(f64::NAN, 1.0, 1.0);
+        create_err(f64::INFINITY, 1.0, 1.0);
+        create_err(1.0, f64::INFINITY, 1.0);
+        create_err(1.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Burr| x.mean().unwrap();
+        test_relative(2.0, 3.0, 1.0, 0.589048622548086232211745634365, mean);
+        test_relative(3.0, 2.0, 2.5, 2.01533262692690872288230917516, mean);
+        test_relative(5.0, 0.5, 1.0, 1.54969627774735302956219538317, mean);
+        test_none(1.0, 1.0, 1.0, |x: Burr| x.mean());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Burr| x.variance().unwrap();
+        test_relative(2.0, 3.0, 1.0, 0.153021720274202236056599925786, variance);
+        test_relative(3.0, 2.0, 2.5, 0.97676597016115714924919227951, variance);
+        test_relative(5.0, 0.5, 1.0, 2.36076073321748271668618187675, variance);
+        test_none(1.0, 1.0, 1.0, |x: Burr| x.variance());
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Burr| x.skewness().unwrap();
+        test_relative(2.0, 3.0, 1.0, 1.90864868054182852952061397576, skewness);
+        test_relative(3.0, 2.0, 2.5, 1.5891292152782785014267034034, skewness);
+        test_none(5.0, 0.5, 1.0, |x: Burr| x.skewness());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Burr| x.entropy().unwrap();
+        test_relative(2.0, 3.0, 1.0, 0.291573864105278332520855974952, entropy);
+        test_relative(3.0, 2.0, 2.5, 1.29119792931276673103771652005, entropy);
+        test_relative(1.0, 1.0, 1.0, 2.0, entropy);
+        test_relative(5.0, 0.5, 1.0, 0.974673779229932439748901393899, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: Burr| x.median();
+        test_relative(2.0, 3.0, 1.0, 0.509824528533958598086613462042, median);
+        test_relative(3.0, 2.0, 2.5, 1.86358031161814049140722025021, median);
+        test_relative(1.0, 1.0, 1.0, 1.0, median);
+        let mode = |x: Burr| x.mode().unwrap();
+        test_exact(1.0, 1.0, 1.0, 0.0, mode);
+        test_relative(2.0, 3.0, 1.0, (1.0f64 / 7.0).sqrt(), mode);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Burr| x.pdf(arg);
+        test_relative(2.0, 3.0, 1.0, 1.2288, pdf(0.5));
+        test_relative(2.0, 3.0, 1.0, 0.0192, pdf(2.0));
+        test_relative(3.0, 2.0, 2.5, 0.0937323783128771790903310177687, pdf(0.5));
+        test_relative(3.0, 2.0, 2.5, 0.444360904594380700872680380533, pdf(2.0));
+        test_relative(1.0, 1.0, 1.0, 4.0 / 9.0, pdf(0.5));
+        test_exact(1.0, 1.0, 1.0, 1.0, pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, pdf(0.0));
+        test_exact(0.5, 3.0, 1.0, f64::INFINITY, pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Burr| x.ln_pdf(arg);
+        test_relative(2.0, 3.0, 1.0, 1.2288f64.ln(), ln_pdf(0.5));
+        test_relative(3.0, 2.0, 2.5, 0.444360904594380700872680380533f64.ln(), ln_pdf(2.0));
+        test_exact(1.0, 1.0, 1.0, 0.0, ln_pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::NEG_INFINITY, ln_pdf(-1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Burr| x.cdf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.488, cdf(0.5));
+        test_relative(2.0, 3.0, 1.0, 0.992, cdf(2.0));
+        test_relative(3.0, 2.0, 2.5, 0.0158100277147896195515243134291, cdf(0.5));
+        test_relative(3.0, 2.0, 2.5, 0.562582234539906497578455250413, cdf(2.0));
+        test_relative(1.0, 1.0, 1.0, 1.0 / 3.0, cdf(0.5));
+        test_exact(2.0, 3.0, 1.0, 0.0, cdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, cdf(-1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Burr| x.sf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.000000970590147927644445652289916674, sf(10.0));
+        test_relative(3.0, 2.0, 2.5, 0.000236686390532544378698224852118, sf(10.0));
+        test_relative(1.0, 1.0, 1.0, 1.0 / 11.0, sf(10.0));
+        test_exact(2.0, 3.0, 1.0, 1.0, sf(0.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Burr| x.inverse_cdf(arg);
+        test_relative(2.0, 3.0, 1.0, 1.07444622482089987645832005798, inverse_cdf(0.9));
+        test_relative(3.0, 2.0, 2.5, 3.23278753187623738315858980057, inverse_cdf(0.9));
+        test_relative(1.0, 1.0, 1.0, 9.0, inverse_cdf(0.9));
+        test_exact(2.0, 3.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        let n = create_ok(3.0, 2.0, 2.5);
+        for &x in &[0.1, 1.0, 2.0, 5.0] {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cdf_is_integrated_pdf() {
+        for &(c, k, scale) in &[(2.0, 3.0, 1.0), (3.0, 2.0, 2.5), (5.0, 0.5, 1.0), (1.0, 1.0, 1.0)] {
+            let n = create_ok(c, k, scale);
+            for &x in &[0.25, 1.0, 3.0, 10.0] {
+                let integral = integration::adaptive(|t| n.pdf(t), 0.0, x, 1e-12);
+                assert_almost_eq!(integral, n.cdf(x), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&create_ok(2.0, 3.0, 1.0), 0.0, 10.0);
+        test::check_continuous_distribution(&create_ok(3.0, 2.0, 2.5), 0.0, 50.0);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::distribution::{Discrete, DiscreteCDF};
+use crate::function::exponential::log_sum_exp;
 use crate::statistics::*;
 use std::f64;
 
@@ -23,6 +24,7 @@ pub struct Categorical {
     norm_pmf: Vec<f64>,
     cdf: Vec<f64>,
     sf: Vec<f64>,
+    ln_normalizer: f64,
 }
 
 /// Represents the errors that can occur when creating a [`Categorical`].
@@ -109,7 +111,29 @@ impl Categorical {
             .iter_mut()
             .zip(prob_mass.iter())
             .for_each(|(np, pm)| *np = *pm / sum);
-        Ok(Categorical { norm_pmf, cdf, sf })
+        let ln_weights: Vec<f64> = prob_mass.iter().map(|p| p.ln()).collect();
+        let ln_normalizer = log_sum_exp(&ln_weights);
+        Ok(Categorical {
+            norm_pmf,
+            cdf,
+            sf,
+            ln_normalizer,
+        })
+    }
+
+    /// Returns the log of the normalizing constant, i.e. the log of the sum
+    /// of the un-normalized probability masses passed to the constructor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Categorical;
+    ///
+    /// let n = Categorical::new(&[1.0, 2.0, 3.0]).unwrap();
+    /// assert!((n.log_normalizer() - 6f64.ln()).abs() < 1e-15);
+    /// ```
+    pub fn log_normalizer(&self) -> f64 {
+        self.ln_normalizer
     }
 
     fn cdf_max(&self) -> f64 {
@@ -239,6 +263,12 @@ impl Max<u64> for Categorical {
 }
 
 impl Distribution<f64> for Categorical {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its finite support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the categorical distribution
     ///
     /// # Formula
@@ -432,6 +462,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_log_normalizer() {
+        let log_normalizer = |x: Categorical| x.log_normalizer();
+        test_absolute(&[1.0, 2.0, 3.0], 6f64.ln(), 1e-15, log_normalizer);
+        test_absolute(&[0.0, 0.25, 0.5, 0.25], 0.0, 1e-15, log_normalizer);
+        test_absolute(&[4.0], 4f64.ln(), 1e-15, log_normalizer);
+        test_absolute(&[0.5, 1e300, 1e300], (2e300f64).ln(), 1e-12, log_normalizer);
+    }
+
     #[test]
     fn test_mean() {
         let mean = |x: Categorical| x.mean().unwrap();
@@ -209,6 +209,12 @@ impl Distribution<f64> for Cauchy {
     fn entropy(&self) -> Option<f64> {
         Some((4.0 * f64::consts::PI * self.scale).ln())
     }
+
+    /// Returns whether the moment of the given order exists, which is
+    /// only the case for order `0`
+    fn moment_exists(&self, order: u32) -> bool {
+        order == 0
+    }
 }
 
 impl Median<f64> for Cauchy {
@@ -318,6 +324,13 @@ mod tests {
         test_exact(10.0, 11.0, 4.92891951976766133704, entropy);
     }
 
+    #[test]
+    fn test_moment_exists() {
+        test_exact(0.0, 1.0, true, |x| x.moment_exists(0));
+        test_exact(0.0, 1.0, false, |x| x.moment_exists(1));
+        test_exact(10.0, 11.0, false, |x| x.moment_exists(2));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Cauchy| x.mode().unwrap();
@@ -356,6 +369,7 @@ mod tests {
         test_exact(0.0, 0.1, 0.001272730452554141029739, pdf(5.0));
         test_absolute(0.0, 1.0, 0.01224268793014579505914, 1e-17, pdf(-5.0));
         test_exact(0.0, 1.0, 0.1591549430918953357689, pdf(-1.0));
+        #[allow(clippy::approx_constant)]
         test_exact(0.0, 1.0, 0.3183098861837906715378, pdf(0.0));
         test_exact(0.0, 1.0, 0.1591549430918953357689, pdf(1.0));
         test_absolute(0.0, 1.0, 0.01224268793014579505914, 1e-17, pdf(5.0));
@@ -516,4 +530,16 @@ mod tests {
         test::check_continuous_distribution(&create_ok(-1.2, 3.4), -1500.0, 1500.0);
         test::check_continuous_distribution(&create_ok(-4.5, 6.7), -5000.0, 5000.0);
     }
+
+    #[test]
+    fn test_partial_moments() {
+        // the tails have no moments of order one or more
+        let n = create_ok(0.0, 1.0);
+        let upper: f64 = n.upper_partial_moment(1, 0.0);
+        let lower: f64 = n.lower_partial_moment(2, 0.0);
+        assert_eq!(upper, f64::INFINITY);
+        assert_eq!(lower, f64::INFINITY);
+        let mass: f64 = n.lower_partial_moment(0, 0.0);
+        assert_almost_eq!(mass, 0.5, 1e-9);
+    }
 }
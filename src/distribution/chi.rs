@@ -177,6 +177,12 @@ impl Max<f64> for Chi {
 }
 
 impl Distribution<f64> for Chi {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays like `e^(-x^2 / 2)`
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the chi distribution
     ///
     /// # Remarks
@@ -508,6 +514,42 @@ mod tests {
         test_exact(1, 1.0, sf(-1.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        // with two degrees of freedom this is the Rayleigh distribution,
+        // F^-1(p) = sqrt(-2 ln(1 - p))
+        let n = create_ok(2);
+        for &p in &[1e-6f64, 0.1, 0.5, 0.9, 0.999] {
+            let exact = (-2.0 * (-p).ln_1p()).sqrt();
+            assert_almost_eq!(n.inverse_cdf(p), exact, 1e-12);
+        }
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_cdf_with() {
+        use crate::distribution::InverseCdfError;
+
+        let n = create_ok(2);
+        let exact = (-2.0 * 0.25f64.ln()).sqrt();
+        let err = |tol: f64| (n.inverse_cdf_with(0.75, tol, 200).unwrap() - exact).abs();
+        assert!(err(1e-2) < 1e-2);
+        assert!(err(1e-12) < 1e-12);
+        assert!(err(1e-12) < err(1e-4));
+
+        assert_eq!(n.inverse_cdf_with(0.75, 1e-12, 3), Err(InverseCdfError::NotConverged));
+        assert_eq!(n.inverse_cdf_with(1.5, 1e-12, 200), Err(InverseCdfError::ProbabilityInvalid));
+        assert_eq!(n.inverse_cdf_with(f64::NAN, 1e-12, 200), Err(InverseCdfError::ProbabilityInvalid));
+        assert_eq!(n.inverse_cdf_with(0.0, 1e-12, 0), Ok(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_bad_p() {
+        create_ok(2).inverse_cdf(-0.5);
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&create_ok(1), 0.0, 10.0);
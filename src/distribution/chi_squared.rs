@@ -186,6 +186,12 @@ impl Max<f64> for ChiSquared {
 }
 
 impl Distribution<f64> for ChiSquared {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the chi-squared distribution
     ///
     /// # Formula
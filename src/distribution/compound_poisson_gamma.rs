@@ -0,0 +1,358 @@
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the compound Poisson-Gamma distribution, the member of the
+/// [Tweedie](https://en.wikipedia.org/wiki/Tweedie_distribution) family with
+/// power parameter between 1 and 2.
+///
+/// It describes `S = Y_1 + ... + Y_N` where `N ~ Poisson(λ)` and the `Y_i`
+/// are independent `Gamma(α, β)` variates, e.g. the aggregate of `N` claims
+/// of gamma-distributed size. `S` has a point mass at zero and a continuous
+/// density on `(0, ∞)`, so it does not implement
+/// [`Continuous`](crate::distribution::Continuous).
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::CompoundPoissonGamma;
+/// use statrs::statistics::Distribution;
+///
+/// let n = CompoundPoissonGamma::new(2.0, 3.0, 1.5).unwrap();
+/// assert_eq!(n.mean().unwrap(), 4.0);
+/// assert_eq!(n.probability_zero(), (-2.0f64).exp());
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CompoundPoissonGamma {
+    lambda: f64,
+    shape: f64,
+    rate: f64,
+}
+
+/// Represents the errors that can occur when creating a
+/// [`CompoundPoissonGamma`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CompoundPoissonGammaError {
+    /// The Poisson rate is NaN, infinite, zero or less than zero.
+    LambdaInvalid,
+
+    /// The gamma shape is NaN, infinite, zero or less than zero.
+    ShapeInvalid,
+
+    /// The gamma rate is NaN, infinite, zero or less than zero.
+    RateInvalid,
+}
+
+impl std::fmt::Display for CompoundPoissonGammaError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CompoundPoissonGammaError::LambdaInvalid => {
+                write!(f, "Lambda is NaN, infinite, zero or less than zero")
+            }
+            CompoundPoissonGammaError::ShapeInvalid => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+            CompoundPoissonGammaError::RateInvalid => {
+                write!(f, "Rate is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompoundPoissonGammaError {}
+
+impl CompoundPoissonGamma {
+    /// Constructs a new compound Poisson-Gamma distribution with a Poisson
+    /// rate of `lambda` and gamma-distributed summands with shape `shape`
+    /// and rate `rate`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `lambda`, `shape` or `rate` are `NaN`,
+    /// infinite, zero or less than zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::CompoundPoissonGamma;
+    ///
+    /// let mut result = CompoundPoissonGamma::new(2.0, 3.0, 1.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = CompoundPoissonGamma::new(0.0, 3.0, 1.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        lambda: f64,
+        shape: f64,
+        rate: f64,
+    ) -> Result<CompoundPoissonGamma, CompoundPoissonGammaError> {
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return Err(CompoundPoissonGammaError::LambdaInvalid);
+        }
+
+        if !shape.is_finite() || shape <= 0.0 {
+            return Err(CompoundPoissonGammaError::ShapeInvalid);
+        }
+
+        if !rate.is_finite() || rate <= 0.0 {
+            return Err(CompoundPoissonGammaError::RateInvalid);
+        }
+
+        Ok(CompoundPoissonGamma {
+            lambda,
+            shape,
+            rate,
+        })
+    }
+
+    /// Returns the Poisson rate of the number of summands
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::CompoundPoissonGamma;
+    ///
+    /// let n = CompoundPoissonGamma::new(2.0, 3.0, 1.5).unwrap();
+    /// assert_eq!(n.lambda(), 2.0);
+    /// ```
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Returns the shape of the gamma-distributed summands
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::CompoundPoissonGamma;
+    ///
+    /// let n = CompoundPoissonGamma::new(2.0, 3.0, 1.5).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the rate of the gamma-distributed summands
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::CompoundPoissonGamma;
+    ///
+    /// let n = CompoundPoissonGamma::new(2.0, 3.0, 1.5).unwrap();
+    /// assert_eq!(n.rate(), 1.5);
+    /// ```
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns the probability of the point mass at zero, i.e. of there
+    /// being no summands
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// e^(-λ)
+    /// ```
+    ///
+    /// where `λ` is the Poisson rate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::CompoundPoissonGamma;
+    ///
+    /// let n = CompoundPoissonGamma::new(1.0, 3.0, 1.5).unwrap();
+    /// assert_eq!(n.probability_zero(), (-1.0f64).exp());
+    /// ```
+    pub fn probability_zero(&self) -> f64 {
+        (-self.lambda).exp()
+    }
+}
+
+impl std::fmt::Display for CompoundPoissonGamma {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CompoundPoissonGamma({},{},{})",
+            self.lambda, self.shape, self.rate
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for CompoundPoissonGamma {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let n = super::poisson::sample_unchecked(rng, self.lambda);
+        if n == 0.0 {
+            0.0
+        } else {
+            // a sum of n independent Gamma(α, β) variates is Gamma(nα, β)
+            super::gamma::sample_unchecked(rng, n * self.shape, self.rate)
+        }
+    }
+}
+
+impl Min<f64> for CompoundPoissonGamma {
+    /// Returns the minimum value in the domain of the compound
+    /// Poisson-Gamma distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for CompoundPoissonGamma {
+    /// Returns the maximum value in the domain of the compound
+    /// Poisson-Gamma distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::INFINITY
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for CompoundPoissonGamma {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
+    /// Returns the mean of the compound Poisson-Gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λα / β
+    /// ```
+    ///
+    /// where `λ` is the Poisson rate, `α` is the gamma shape and `β` is the
+    /// gamma rate
+    fn mean(&self) -> Option<f64> {
+        Some(self.lambda * self.shape / self.rate)
+    }
+
+    /// Returns the variance of the compound Poisson-Gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// λα(α + 1) / β^2
+    /// ```
+    ///
+    /// where `λ` is the Poisson rate, `α` is the gamma shape and `β` is the
+    /// gamma rate
+    fn variance(&self) -> Option<f64> {
+        Some(self.lambda * self.shape * (self.shape + 1.0) / (self.rate * self.rate))
+    }
+
+    /// Returns the skewness of the compound Poisson-Gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (α + 2) / sqrt(λα(α + 1))
+    /// ```
+    ///
+    /// where `λ` is the Poisson rate and `α` is the gamma shape
+    fn skewness(&self) -> Option<f64> {
+        Some((self.shape + 2.0) / (self.lambda * self.shape * (self.shape + 1.0)).sqrt())
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::testing_boiler!(lambda: f64, shape: f64, rate: f64; CompoundPoissonGamma; CompoundPoissonGammaError);
+
+    #[test]
+    fn test_create() {
+        create_ok(2.0, 3.0, 1.5);
+        create_ok(0.01, 0.5, 100.0);
+        create_ok(500.0, 10.0, 0.1);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(0.0, 1.0, 1.0, CompoundPoissonGammaError::LambdaInvalid);
+        test_create_err(f64::INFINITY, 1.0, 1.0, CompoundPoissonGammaError::LambdaInvalid);
+        test_create_err(1.0, -1.0, 1.0, CompoundPoissonGammaError::ShapeInvalid);
+        test_create_err(1.0, 1.0, f64::NAN, CompoundPoissonGammaError::RateInvalid);
+        create_err(f64::NAN, 1.0, 1.0);
+        create_err(1.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: CompoundPoissonGamma| x.mean().unwrap();
+        // λ E[Y] with E[Y] = α / β
+        test_exact(2.0, 3.0, 1.5, 4.0, mean);
+        test_exact(0.5, 2.0, 4.0, 0.25, mean);
+        test_relative(10.0, 0.5, 0.2, 25.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: CompoundPoissonGamma| x.variance().unwrap();
+        // λ E[Y^2] with E[Y^2] = α(α + 1) / β^2
+        test_relative(2.0, 3.0, 1.5, 2.0 * 12.0 / 2.25, variance);
+        test_exact(0.5, 2.0, 4.0, 0.1875, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: CompoundPoissonGamma| x.skewness().unwrap();
+        // λ E[Y^3] / var^(3 / 2) with E[Y^3] = α(α + 1)(α + 2) / β^3
+        test_relative(2.0, 3.0, 1.5, 2.0 * 60.0 / 1.5f64.powi(3) / (2.0 * 12.0 / 2.25f64).powf(1.5), skewness);
+    }
+
+    #[test]
+    fn test_probability_zero() {
+        let probability_zero = |x: CompoundPoissonGamma| x.probability_zero();
+        test_exact(2.0, 3.0, 1.5, (-2.0f64).exp(), probability_zero);
+        test_exact(0.01, 3.0, 1.5, (-0.01f64).exp(), probability_zero);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: CompoundPoissonGamma| x.min();
+        let max = |x: CompoundPoissonGamma| x.max();
+        test_exact(2.0, 3.0, 1.5, 0.0, min);
+        test_exact(2.0, 3.0, 1.5, f64::INFINITY, max);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample() {
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 100_000;
+        for &(lambda, shape, rate) in &[(2.0, 3.0, 1.5), (0.5, 0.5, 2.0), (40.0, 2.0, 1.0)] {
+            let d = create_ok(lambda, shape, rate);
+            let x: Vec<f64> = d.sample_iter(&mut rng).take(n_samples).collect();
+            let zeros = x.iter().filter(|&&x| x == 0.0).count() as f64 / n_samples as f64;
+
+            assert!(x.iter().all(|&x| x >= 0.0));
+            assert_almost_eq!(zeros, d.probability_zero(), 0.005);
+            assert_almost_eq!(Statistics::mean(&x) / d.mean().unwrap(), 1.0, 0.02);
+            assert_almost_eq!(Statistics::variance(&x) / d.variance().unwrap(), 1.0, 0.03);
+        }
+    }
+}
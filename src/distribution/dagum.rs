@@ -0,0 +1,621 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::{beta, gamma};
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the [Dagum](https://en.wikipedia.org/wiki/Dagum_distribution)
+/// (Burr Type III) distribution, commonly used to model income
+///
+/// It is the distribution of the reciprocal of a Burr Type XII variate, so
+/// unlike the [`Burr`](crate::distribution::Burr) distribution its density
+/// can vanish like a power at zero while keeping a Pareto-like upper tail.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, ContinuousCDF, Dagum};
+/// use statrs::prec;
+///
+/// let n = Dagum::new(1.0, 1.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.pdf(2.0), 1.0 / 9.0, 1e-15));
+/// assert!(prec::almost_eq(n.cdf(2.0), 2.0 / 3.0, 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Dagum {
+    p: f64,
+    a: f64,
+    scale: f64,
+}
+
+/// Represents the errors that can occur when creating a [`Dagum`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum DagumError {
+    /// The shape p is NaN, infinite, zero or less than zero.
+    ShapePInvalid,
+
+    /// The shape a is NaN, infinite, zero or less than zero.
+    ShapeAInvalid,
+
+    /// The scale is NaN, infinite, zero or less than zero.
+    ScaleInvalid,
+}
+
+impl std::fmt::Display for DagumError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DagumError::ShapePInvalid => {
+                write!(f, "Shape p is NaN, infinite, zero or less than zero.")
+            }
+            DagumError::ShapeAInvalid => {
+                write!(f, "Shape a is NaN, infinite, zero or less than zero.")
+            }
+            DagumError::ScaleInvalid => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DagumError {}
+
+impl Dagum {
+    /// Constructs a new Dagum distribution with shapes `p` and `a` and a
+    /// scale (b) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `p`, `a` or `scale` are `NaN`, infinite,
+    /// zero or less than zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dagum;
+    ///
+    /// let mut result = Dagum::new(2.0, 3.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Dagum::new(2.0, 0.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(p: f64, a: f64, scale: f64) -> Result<Dagum, DagumError> {
+        if !p.is_finite() || p <= 0.0 {
+            return Err(DagumError::ShapePInvalid);
+        }
+
+        if !a.is_finite() || a <= 0.0 {
+            return Err(DagumError::ShapeAInvalid);
+        }
+
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(DagumError::ScaleInvalid);
+        }
+
+        Ok(Dagum { p, a, scale })
+    }
+
+    /// Returns the shape p of the Dagum distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dagum;
+    ///
+    /// let n = Dagum::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.p(), 2.0);
+    /// ```
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns the shape a of the Dagum distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dagum;
+    ///
+    /// let n = Dagum::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.a(), 3.0);
+    /// ```
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the scale of the Dagum distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Dagum;
+    ///
+    /// let n = Dagum::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the raw moment `E[X^r]` for `r > 0`, which only exists for
+    /// `r < a`
+    fn raw_moment(&self, r: f64) -> Option<f64> {
+        if r >= self.a {
+            return None;
+        }
+        let ra = r / self.a;
+        Some(self.scale.powf(r) * self.p * beta::beta(self.p + ra, 1.0 - ra))
+    }
+
+    /// Returns `ln(1 + (x / b)^-a)` for `x > 0`
+    fn ln_1p_z_neg_a(&self, x: f64) -> f64 {
+        (x / self.scale).powf(-self.a).ln_1p()
+    }
+}
+
+impl std::fmt::Display for Dagum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Dagum({},{},{})", self.p, self.a, self.scale)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for Dagum {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.gen();
+        self.inverse_cdf(u)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Dagum {
+    /// Calculates the cumulative distribution function for the Dagum
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (1 + (x / b)^(-a))^(-p)
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            (-self.p * self.ln_1p_z_neg_a(x)).exp()
+        }
+    }
+
+    /// Calculates the survival function for the Dagum
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - (1 + (x / b)^(-a))^(-p)
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            -(-self.p * self.ln_1p_z_neg_a(x)).exp_m1()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// Dagum distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in `[0, 1]`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b (u^(-1 / p) - 1)^(-1 / a)
+    /// ```
+    ///
+    /// where `u` is the probability, `p` and `a` are the shapes and `b` is
+    /// the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("x must be in [0, 1]");
+        }
+
+        self.scale * (-p.ln() / self.p).exp_m1().powf(-1.0 / self.a)
+    }
+}
+
+impl Min<f64> for Dagum {
+    /// Returns the minimum value in the domain of the Dagum
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Dagum {
+    /// Returns the maximum value in the domain of the Dagum
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::INFINITY
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Dagum {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for orders below the shape `a`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.a
+    }
+
+    /// Returns the mean of the Dagum distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `a <= 1`, in which case the mean does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b p B(p + 1 / a, 1 - 1 / a)
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes, `b` is the scale and `B` is the
+    /// beta function
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1.0)
+    }
+
+    /// Returns the variance of the Dagum distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `a <= 2`, in which case the variance does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b^2 p B(p + 2 / a, 1 - 2 / a) - μ^2
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes, `b` is the scale, `B` is the
+    /// beta function and `μ` is the mean
+    fn variance(&self) -> Option<f64> {
+        let m2 = self.raw_moment(2.0)?;
+        let mean = self.mean()?;
+        Some(m2 - mean * mean)
+    }
+
+    /// Returns the entropy of the Dagum distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(b / (ap)) + (1 + 1 / a)(ψ(p) + γ) + 1 + 1 / p
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes, `b` is the scale, `ψ` is the
+    /// digamma function and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        let entr = (self.scale / (self.a * self.p)).ln()
+            + (1.0 + 1.0 / self.a) * (gamma::digamma(self.p) + crate::consts::EULER_MASCHERONI)
+            + 1.0
+            + 1.0 / self.p;
+        Some(entr)
+    }
+
+    /// Returns the skewness of the Dagum distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `a <= 3`, in which case the skewness does not exist
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (m3 - 3 m1 m2 + 2 m1^3) / (m2 - m1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `mr = b^r p B(p + r / a, 1 - r / a)` is the `r`-th raw moment
+    fn skewness(&self) -> Option<f64> {
+        let m3 = self.raw_moment(3.0)?;
+        let m2 = self.raw_moment(2.0)?;
+        let m1 = self.raw_moment(1.0)?;
+        Some(skewness_from_moments(m1, m2, m3))
+    }
+}
+
+impl Median<f64> for Dagum {
+    /// Returns the median of the Dagum distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b (2^(1 / p) - 1)^(-1 / a)
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn median(&self) -> f64 {
+        self.scale * (f64::consts::LN_2 / self.p).exp_m1().powf(-1.0 / self.a)
+    }
+}
+
+impl Mode<Option<f64>> for Dagum {
+    /// Returns the mode of the Dagum distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// if ap <= 1 {
+    ///     0
+    /// } else {
+    ///     b ((ap - 1) / (a + 1))^(1 / a)
+    /// }
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn mode(&self) -> Option<f64> {
+        let ap = self.a * self.p;
+        let mode = if ap <= 1.0 {
+            0.0
+        } else {
+            self.scale * ((ap - 1.0) / (self.a + 1.0)).powf(1.0 / self.a)
+        };
+        Some(mode)
+    }
+}
+
+impl Continuous<f64, f64> for Dagum {
+    /// Calculates the probability density function for the Dagum
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (ap / x) (x / b)^(ap) / ((x / b)^a + 1)^(p + 1)
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            0.0
+        } else if x == 0.0 {
+            let ap = self.a * self.p;
+            if ap < 1.0 {
+                f64::INFINITY
+            } else if ap == 1.0 {
+                1.0 / self.scale
+            } else {
+                0.0
+            }
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the Dagum
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(ap / x) - a ln(x / b) - (p + 1) ln(1 + (x / b)^(-a))
+    /// ```
+    ///
+    /// where `p` and `a` are the shapes and `b` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            self.pdf(x).ln()
+        } else {
+            let z = x / self.scale;
+            if z < 1.0 {
+                // the same expression, rearranged so that nothing
+                // overflows for small x
+                (self.a * self.p / x).ln() + self.a * self.p * z.ln()
+                    - (self.p + 1.0) * z.powf(self.a).ln_1p()
+            } else {
+                (self.a * self.p / x).ln()
+                    - self.a * z.ln()
+                    - (self.p + 1.0) * self.ln_1p_z_neg_a(x)
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+    use crate::function::integration;
+
+    crate::testing_boiler!(p: f64, a: f64, scale: f64; Dagum; DagumError);
+
+    #[test]
+    fn test_create() {
+        create_ok(2.0, 3.0, 1.0);
+        create_ok(0.5, 0.5, 0.1);
+        create_ok(10.0, 10.0, 10.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(0.0, 1.0, 1.0, DagumError::ShapePInvalid);
+        test_create_err(1.0, -1.0, 1.0, DagumError::ShapeAInvalid);
+        test_create_err(1.0, 1.0, f64::NAN, DagumError::ScaleInvalid);
+        create_err(f64::NAN, 1.0, 1.0);
+        create_err(f64::INFINITY, 1.0, 1.0);
+        create_err(1.0, f64::INFINITY, 1.0);
+        create_err(1.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Dagum| x.mean().unwrap();
+        test_relative(2.0, 3.0, 1.0, 1.61226610154152697830584734013, mean);
+        test_relative(0.5, 4.0, 2.5, 2.11803271198494771651624780871, mean);
+        test_relative(3.0, 5.0, 2.0, 2.82205263678517109944248674342, mean);
+        test_none(1.0, 1.0, 1.0, |x: Dagum| x.mean());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Dagum| x.variance().unwrap();
+        test_relative(2.0, 3.0, 1.0, 1.43126327167390406467240672895, variance);
+        test_relative(0.5, 4.0, 2.5, 1.76393743096168751361754235209, variance);
+        test_relative(3.0, 5.0, 2.0, 0.915197921047668756337826619071, variance);
+        test_none(1.0, 2.0, 1.0, |x: Dagum| x.variance());
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Dagum| x.skewness().unwrap();
+        test_relative(0.5, 4.0, 2.5, 3.52580536124715724889892543367, skewness);
+        test_relative(3.0, 5.0, 2.0, 3.14873761056204640350473880907, skewness);
+        test_none(2.0, 3.0, 1.0, |x: Dagum| x.skewness());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Dagum| x.entropy().unwrap();
+        test_relative(2.0, 3.0, 1.0, 1.04157386410527833252085597495, entropy);
+        test_relative(0.5, 4.0, 2.5, 1.49027559991434648222321478666, entropy);
+        test_relative(1.0, 1.0, 1.0, 2.0, entropy);
+        test_relative(3.0, 5.0, 2.0, 1.11843031279106857675456088464, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: Dagum| x.median();
+        test_relative(2.0, 3.0, 1.0, 1.34150376263057771967569351449, median);
+        test_relative(0.5, 4.0, 2.5, 1.89958921412898136832796937664, median);
+        test_relative(1.0, 1.0, 1.0, 1.0, median);
+        let mode = |x: Dagum| x.mode().unwrap();
+        test_exact(1.0, 1.0, 1.0, 0.0, mode);
+        test_relative(2.0, 3.0, 1.0, 1.07721734501594186087964678326, mode);
+        test_relative(0.5, 4.0, 2.5, 1.67185076244105506000808268315, mode);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Dagum| x.pdf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.131687242798353909465020576132, pdf(0.5));
+        test_relative(2.0, 3.0, 1.0, 0.263374485596707818930041152263, pdf(2.0));
+        test_relative(0.5, 4.0, 2.5, 0.159616766568975946213912049993, pdf(0.5));
+        test_relative(0.5, 4.0, 2.5, 0.382416072945067587029677732016, pdf(2.0));
+        test_relative(3.0, 5.0, 2.0, 0.0000000278308038082484682887193927583, pdf(0.5));
+        test_relative(3.0, 5.0, 2.0, 0.46875, pdf(2.0));
+        test_relative(1.0, 1.0, 1.0, 4.0 / 9.0, pdf(0.5));
+        test_exact(1.0, 1.0, 1.0, 1.0, pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, pdf(0.0));
+        test_exact(0.5, 1.0, 1.0, f64::INFINITY, pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, pdf(-1.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Dagum| x.ln_pdf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.131687242798353909465020576132f64.ln(), ln_pdf(0.5));
+        test_relative(0.5, 4.0, 2.5, 0.382416072945067587029677732016f64.ln(), ln_pdf(2.0));
+        test_exact(1.0, 1.0, 1.0, 0.0, ln_pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::NEG_INFINITY, ln_pdf(-1.0));
+        // the density underflows far in either tail, its log does not
+        let n = create_ok(2.0, 3.0, 1.0);
+        assert_almost_eq!(n.ln_pdf(1e-200), (6.0f64 / 1e-200).ln() + 6.0 * 1e-200f64.ln(), 1e-9);
+        assert_almost_eq!(n.ln_pdf(1e200), (6.0f64 / 1e200).ln() - 3.0 * 1e200f64.ln(), 1e-9);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Dagum| x.cdf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.0123456790123456790123456790123, cdf(0.5));
+        test_relative(2.0, 3.0, 1.0, 0.79012345679012345679012345679, cdf(2.0));
+        test_relative(2.0, 3.0, 1.0, 9.99998000002999996000004999994e-13, cdf(0.01));
+        test_relative(0.5, 4.0, 2.5, 0.0399680383488715769319635773183, cdf(0.5));
+        test_relative(0.5, 4.0, 2.5, 0.53905369642336727067703373105, cdf(2.0));
+        test_relative(3.0, 5.0, 2.0, 3.05175781247138977050799131393e-35, cdf(0.01));
+        test_relative(1.0, 1.0, 1.0, 1.0 / 3.0, cdf(0.5));
+        test_exact(2.0, 3.0, 1.0, 0.0, cdf(0.0));
+        test_exact(2.0, 3.0, 1.0, 0.0, cdf(-1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Dagum| x.sf(arg);
+        test_relative(2.0, 3.0, 1.0, 0.00199700399500599300799100998901, sf(10.0));
+        test_relative(0.5, 4.0, 2.5, 0.00194742151711144810139425633068, sf(10.0));
+        test_relative(3.0, 5.0, 2.0, 0.000959385927522784034254793487945, sf(10.0));
+        test_relative(1.0, 1.0, 1.0, 1.0 / 11.0, sf(10.0));
+        test_exact(2.0, 3.0, 1.0, 1.0, sf(0.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Dagum| x.inverse_cdf(arg);
+        test_relative(2.0, 3.0, 1.0, 2.64415862883428302609859934443, inverse_cdf(0.9));
+        test_relative(0.5, 4.0, 2.5, 3.59230219082681006738980997037, inverse_cdf(0.9));
+        test_relative(3.0, 5.0, 2.0, 3.8939373116573016797340231818, inverse_cdf(0.9));
+        test_relative(1.0, 1.0, 1.0, 9.0, inverse_cdf(0.9));
+        test_exact(2.0, 3.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_exact(2.0, 3.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        let n = create_ok(0.5, 4.0, 2.5);
+        for &x in &[0.1, 1.0, 2.0, 5.0] {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cdf_is_integrated_pdf() {
+        for &(p, a, scale) in &[(2.0, 3.0, 1.0), (0.5, 4.0, 2.5), (3.0, 5.0, 2.0), (1.0, 1.0, 1.0)] {
+            let n = create_ok(p, a, scale);
+            for &x in &[0.25, 1.0, 3.0, 10.0] {
+                let integral = integration::adaptive(|t| n.pdf(t), 0.0, x, 1e-12);
+                assert_almost_eq!(integral, n.cdf(x), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&create_ok(2.0, 3.0, 1.0), 0.0, 50.0);
+        test::check_continuous_distribution(&create_ok(3.0, 5.0, 2.0), 0.0, 20.0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_quantiles() {
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        // the empirical cdf of exact inverse-transform samples matches the cdf
+        let n = create_ok(2.0, 3.0, 1.0);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 50_000;
+        let samples: Vec<f64> = n.sample_iter(&mut rng).take(n_samples).collect();
+        for &q in &[0.1, 0.25, 0.5, 0.75, 0.9] {
+            let x = n.inverse_cdf(q);
+            let below = samples.iter().filter(|&&s| s <= x).count() as f64 / n_samples as f64;
+            assert_almost_eq!(below, q, 0.01);
+        }
+    }
+}
@@ -131,6 +131,12 @@ impl Max<f64> for Dirac {
 }
 
 impl Distribution<f64> for Dirac {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case for a point mass
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the dirac distribution
     ///
     /// # Remarks
@@ -160,6 +160,12 @@ impl Max<i64> for DiscreteUniform {
 }
 
 impl Distribution<f64> for DiscreteUniform {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its finite support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the discrete uniform distribution
     ///
     /// # Formula
@@ -0,0 +1,239 @@
+use crate::distribution::{Continuous, ContinuousCDF, Normal};
+use crate::function::{erf, integration};
+use crate::statistics::Distribution;
+
+/// Computes the 1-Wasserstein (earth mover's) distance between two
+/// univariate distributions, approximating the integral of the absolute
+/// difference of their cdfs with the trapezoidal rule over `grid`.
+///
+/// The grid should cover the bulk of both distributions; any mass outside
+/// it is ignored.
+///
+/// Returns `None` if `grid` has fewer than two points, or is not strictly
+/// increasing and finite.
+///
+/// # Formula
+///
+/// ```text
+/// ∫ |F_p(x) - F_q(x)| dx
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{wasserstein_1d, Normal};
+///
+/// // shifting a distribution moves all of its mass by the shift
+/// let p = Normal::new(0.0, 1.0).unwrap();
+/// let q = Normal::new(2.0, 1.0).unwrap();
+/// let grid: Vec<f64> = (0..=2000).map(|i| -10.0 + i as f64 * 0.01).collect();
+/// let w = wasserstein_1d(&p, &q, &grid).unwrap();
+/// assert!((w - 2.0).abs() < 1e-4);
+/// ```
+pub fn wasserstein_1d<P, Q>(p: &P, q: &Q, grid: &[f64]) -> Option<f64>
+where
+    P: ContinuousCDF<f64, f64>,
+    Q: ContinuousCDF<f64, f64>,
+{
+    if grid.len() < 2
+        || !grid.iter().all(|x| x.is_finite())
+        || grid.windows(2).any(|w| w[0] >= w[1])
+    {
+        return None;
+    }
+    let diff = |x: f64| (p.cdf(x) - q.cdf(x)).abs();
+    let mut prev = diff(grid[0]);
+    let mut sum = 0.0;
+    for w in grid.windows(2) {
+        let next = diff(w[1]);
+        sum += 0.5 * (prev + next) * (w[1] - w[0]);
+        prev = next;
+    }
+    Some(sum)
+}
+
+/// Computes the overlapping coefficient of two normal distributions, the
+/// area shared by their densities, which lies in `[0, 1]`.
+///
+/// # Formula
+///
+/// ```text
+/// ∫ min(f_a(x), f_b(x)) dx
+/// ```
+///
+/// For equal standard deviations `σ` this is `2Φ(-|μ_a - μ_b| / 2σ)`,
+/// where `Φ` is the standard normal cdf. Otherwise the integral is
+/// evaluated numerically, on panels placed around both means so that
+/// neither density is missed however narrow it is.
+///
+/// # Remarks
+///
+/// Returns `NaN` if the standard deviations differ and either is infinite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{overlapping_coefficient, Normal};
+///
+/// let a = Normal::new(0.0, 1.0).unwrap();
+/// let b = Normal::new(1.0, 1.0).unwrap();
+/// assert!((overlapping_coefficient(&a, &b) - 0.617075077451974).abs() < 1e-12);
+/// ```
+pub fn overlapping_coefficient(a: &Normal, b: &Normal) -> f64 {
+    // always defined for the normal distribution
+    let (mu_a, sigma_a) = (a.mean().unwrap(), a.std_dev().unwrap());
+    let (mu_b, sigma_b) = (b.mean().unwrap(), b.std_dev().unwrap());
+    if sigma_a == sigma_b {
+        return erf::erfc((mu_a - mu_b).abs() / (2.0 * std::f64::consts::SQRT_2 * sigma_a));
+    }
+    if sigma_a.is_infinite() || sigma_b.is_infinite() {
+        return f64::NAN;
+    }
+
+    let mut breaks: Vec<f64> = [(mu_a, sigma_a), (mu_b, sigma_b)]
+        .iter()
+        .flat_map(|&(mu, sigma)| {
+            [-40.0, -12.0, -6.0, -3.0, 0.0, 3.0, 6.0, 12.0, 40.0].map(|k| mu + k * sigma)
+        })
+        .collect();
+    breaks.sort_by(|x, y| x.total_cmp(y));
+    breaks.dedup();
+    let overlap = |x: f64| a.pdf(x).min(b.pdf(x));
+    let area: f64 = breaks
+        .windows(2)
+        .map(|w| integration::adaptive(overlap, w[0], w[1], 1e-14))
+        .sum();
+    area.clamp(0.0, 1.0)
+}
+
+/// Computes the Bhattacharyya distance between two normal distributions,
+/// `-ln ∫ sqrt(f_a(x) f_b(x)) dx`. It is zero for identical distributions,
+/// and the Bayes error of classifying between two equally likely classes is
+/// at most `e^(-D) / 2`.
+///
+/// # Formula
+///
+/// ```text
+/// (μ_a - μ_b)^2 / (4 (σ_a^2 + σ_b^2)) + ln((σ_a^2 + σ_b^2) / (2 σ_a σ_b)) / 2
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{bhattacharyya_distance, Normal};
+///
+/// let a = Normal::new(0.0, 1.0).unwrap();
+/// let b = Normal::new(2.0, 1.0).unwrap();
+/// assert_eq!(bhattacharyya_distance(&a, &b), 0.5);
+/// ```
+pub fn bhattacharyya_distance(a: &Normal, b: &Normal) -> f64 {
+    // always defined for the normal distribution
+    let (mu_a, sigma_a) = (a.mean().unwrap(), a.std_dev().unwrap());
+    let (mu_b, sigma_b) = (b.mean().unwrap(), b.std_dev().unwrap());
+    let var_sum = sigma_a * sigma_a + sigma_b * sigma_b;
+    let d = mu_a - mu_b;
+    0.25 * d * d / var_sum + 0.5 * (var_sum / (2.0 * sigma_a * sigma_b)).ln()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Normal};
+
+    fn grid(lo: f64, hi: f64, n: usize) -> Vec<f64> {
+        (0..=n).map(|i| lo + (hi - lo) * i as f64 / n as f64).collect()
+    }
+
+    #[test]
+    fn test_wasserstein_normals() {
+        // for normals W1 = E|Δμ + Δσ Z| with Z standard normal, bounded
+        // above by the 2-Wasserstein distance sqrt(Δμ^2 + Δσ^2)
+        let std_normal = Normal::standard();
+        let exact = |a: f64, b: f64| {
+            let b = b.abs();
+            b * (2.0 / std::f64::consts::PI).sqrt() * (-a * a / (2.0 * b * b)).exp()
+                + a * (1.0 - 2.0 * std_normal.cdf(-a / b))
+        };
+        let x = grid(-30.0, 30.0, 60_000);
+        for &(mp, sp, mq, sq) in &[(0.0, 1.0, 1.0, 2.0), (-1.0, 0.5, 2.0, 3.0), (0.0, 1.0, 0.0, 4.0), (3.0, 2.0, 1.0, 1.5)] {
+            let p = Normal::new(mp, sp).unwrap();
+            let q = Normal::new(mq, sq).unwrap();
+            let w = wasserstein_1d(&p, &q, &x).unwrap();
+            assert_almost_eq!(w, exact(mq - mp, sq - sp), 1e-6);
+            assert!(w <= ((mp - mq) * (mp - mq) + (sp - sq) * (sp - sq)).sqrt() + 1e-9);
+            assert_almost_eq!(wasserstein_1d(&q, &p, &x).unwrap(), w, 1e-15);
+        }
+
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Normal::new(1.5, 1.0).unwrap();
+        assert_almost_eq!(wasserstein_1d(&p, &q, &x).unwrap(), 1.5, 1e-6);
+        assert_eq!(wasserstein_1d(&p, &p, &x).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_wasserstein_exponentials() {
+        // W1 between exponentials is |1 / λp - 1 / λq|, the cdfs never cross
+        let p = Exp::new(1.0).unwrap();
+        let q = Exp::new(0.25).unwrap();
+        let w = wasserstein_1d(&p, &q, &grid(0.0, 200.0, 200_000)).unwrap();
+        assert_almost_eq!(w, 3.0, 1e-6);
+    }
+
+    #[test]
+    fn test_wasserstein_bad_grid() {
+        let p = Normal::standard();
+        assert!(wasserstein_1d(&p, &p, &[]).is_none());
+        assert!(wasserstein_1d(&p, &p, &[0.0]).is_none());
+        assert!(wasserstein_1d(&p, &p, &[0.0, 1.0, 1.0]).is_none());
+        assert!(wasserstein_1d(&p, &p, &[1.0, 0.0]).is_none());
+        assert!(wasserstein_1d(&p, &p, &[0.0, f64::INFINITY]).is_none());
+    }
+
+    #[test]
+    fn test_overlapping_coefficient() {
+        let n = |mean: f64, std_dev: f64| Normal::new(mean, std_dev).unwrap();
+        assert_eq!(overlapping_coefficient(&n(1.0, 2.0), &n(1.0, 2.0)), 1.0);
+        assert_almost_eq!(overlapping_coefficient(&n(0.0, 1.0), &n(1.0, 1.0)), 0.617075077451974, 1e-14);
+        assert!(overlapping_coefficient(&n(0.0, 1.0), &n(50.0, 1.0)) < 1e-100);
+        assert!(overlapping_coefficient(&n(0.0, 1.0), &n(50.0, 1.5)) < 1e-50);
+
+        // closed form through the crossing points of the densities
+        for &(ma, sa, mb, sb, expected) in &[
+            (0.0, 1.0, 0.0, 2.0, 0.677325431165231335),
+            (0.0, 1.0, 1.0, 3.0, 0.493379728193159042),
+            (-1.0, 0.5, 2.0, 1.5, 0.113421228339190063),
+        ] {
+            assert_almost_eq!(overlapping_coefficient(&n(ma, sa), &n(mb, sb)), expected, 1e-10);
+            assert_almost_eq!(overlapping_coefficient(&n(mb, sb), &n(ma, sa)), expected, 1e-10);
+        }
+
+        // the numeric path agrees with the closed form for near-equal scales
+        let closed = overlapping_coefficient(&n(0.0, 1.0), &n(1.5, 1.0));
+        assert_almost_eq!(overlapping_coefficient(&n(0.0, 1.0), &n(1.5, 1.0 + 1e-12)), closed, 1e-10);
+
+        // a very narrow density is still found
+        assert_almost_eq!(overlapping_coefficient(&n(0.0, 1e-6), &n(0.0, 1e3)), 5.257838776282622e-9, 1e-12);
+    }
+
+    #[test]
+    fn test_bhattacharyya_distance() {
+        let n = |mean: f64, std_dev: f64| Normal::new(mean, std_dev).unwrap();
+        assert_eq!(bhattacharyya_distance(&n(1.0, 2.0), &n(1.0, 2.0)), 0.0);
+
+        let mut prev = 0.0;
+        for &shift in &[0.5, 1.0, 2.0, 5.0] {
+            let d = bhattacharyya_distance(&n(0.0, 1.0), &n(shift, 2.0));
+            assert!(d > prev);
+            assert_eq!(d, bhattacharyya_distance(&n(shift, 2.0), &n(0.0, 1.0)));
+            prev = d;
+        }
+
+        // -ln of the Bhattacharyya coefficient evaluated numerically
+        for &(ma, sa, mb, sb) in &[(0.0, 1.0, 1.0, 1.0), (0.0, 1.0, 0.0, 3.0), (-1.0, 0.5, 2.0, 1.5)] {
+            let (a, b) = (n(ma, sa), n(mb, sb));
+            let bc = integration::adaptive(|x| (a.pdf(x) * b.pdf(x)).sqrt(), -20.0, 20.0, 1e-14);
+            assert_almost_eq!(bhattacharyya_distance(&a, &b), -bc.ln(), 1e-10);
+        }
+    }
+}
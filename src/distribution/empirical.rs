@@ -238,6 +238,12 @@ impl Min<f64> for Empirical {
 }
 
 impl Distribution<f64> for Empirical {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for every order once it holds any data
+    fn moment_exists(&self, order: u32) -> bool {
+        order == 0 || !self.data.is_empty()
+    }
+
     fn mean(&self) -> Option<f64> {
         if self.data.is_empty() {
             None
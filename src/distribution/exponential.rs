@@ -149,6 +149,17 @@ impl ContinuousCDF<f64, f64> for Exp {
     fn inverse_cdf(&self, p: f64) -> f64 {
         -(-p).ln_1p() / self.rate
     }
+
+    /// Returns the Gini coefficient of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 / 2
+    /// ```
+    fn gini_coefficient(&self) -> Option<f64> {
+        Some(0.5)
+    }
 }
 
 impl Min<f64> for Exp {
@@ -180,6 +191,12 @@ impl Max<f64> for Exp {
 }
 
 impl Distribution<f64> for Exp {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the exponential distribution
     ///
     /// # Formula
@@ -229,6 +246,17 @@ impl Distribution<f64> for Exp {
     fn skewness(&self) -> Option<f64> {
         Some(2.0)
     }
+
+    /// Returns the excess kurtosis of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 6
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0)
+    }
 }
 
 impl Median<f64> for Exp {
@@ -337,6 +365,14 @@ mod tests {
         test_exact(10.0, 0.01, variance);
     }
 
+    #[test]
+    fn test_moment_exists() {
+        let n = create_ok(0.5);
+        for order in 0..=6 {
+            assert!(n.moment_exists(order));
+        }
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Exp| x.entropy().unwrap();
@@ -353,6 +389,13 @@ mod tests {
         test_exact(10.0, 2.0, skewness);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let kurtosis = |x: Exp| x.excess_kurtosis().unwrap();
+        test_exact(0.1, 6.0, kurtosis);
+        test_exact(10.0, 6.0, kurtosis);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Exp| x.median();
@@ -369,6 +412,61 @@ mod tests {
         test_exact(10.0, 0.0, mode);
     }
 
+    #[test]
+    fn test_time_to_reliability() {
+        for &rate in &[0.1, 1.0, 4.0] {
+            let n = Exp::new(rate).unwrap();
+            for &r in &[0.5, 0.9, 0.99] {
+                assert_almost_eq!(n.time_to_reliability(r).unwrap(), -f64::ln(r) / rate, 1e-13);
+            }
+            assert!(n.time_to_reliability(0.99).unwrap() < n.time_to_reliability(0.9).unwrap());
+            assert_eq!(n.time_to_reliability(1.0), Some(0.0));
+            assert_eq!(n.time_to_reliability(0.0), Some(f64::INFINITY));
+            assert_eq!(n.time_to_reliability(1.5), None);
+            assert_eq!(n.time_to_reliability(f64::NAN), None);
+        }
+    }
+
+    #[test]
+    fn test_gini_coefficient() {
+        let gini = |x: Exp| x.gini_coefficient().unwrap();
+        test_exact(0.1, 0.5, gini);
+        test_exact(3.0, 0.5, gini);
+
+        // the default numeric implementation agrees for the equivalent gamma
+        let g = crate::distribution::Gamma::new(1.0, 3.0).unwrap();
+        assert_almost_eq!(g.gini_coefficient().unwrap(), 0.5, 1e-10);
+    }
+
+    #[test]
+    fn test_highest_density_interval() {
+        // the mode lies on the boundary, so the interval starts at zero
+        let n = Exp::new(2.0).unwrap();
+        let (a, b) = n.highest_density_interval(0.9).unwrap();
+        assert_almost_eq!(a, 0.0, 1e-12);
+        assert_almost_eq!(b, n.inverse_cdf(0.9), 1e-12);
+    }
+
+    #[test]
+    fn test_partial_moments() {
+        // the default numeric integration, against E[(t - X)+] = t - (1 - e^(-λt)) / λ
+        let n = create_ok(0.5);
+        for t in [0.1, 1.0, 2.0, 7.5] {
+            let lower: f64 = n.lower_partial_moment(1, t);
+            let upper: f64 = n.upper_partial_moment(1, t);
+            assert_almost_eq!(lower, t - 2.0 * (1.0 - (-0.5 * t).exp()), 1e-10);
+            assert_almost_eq!(upper, 2.0 * (-0.5 * t).exp(), 1e-10);
+            assert_almost_eq!(upper - lower, 2.0 - t, 1e-10);
+        }
+        // nothing lies below the support
+        assert_eq!(n.lower_partial_moment(2, -1.0), 0.0);
+        assert_almost_eq!(n.upper_partial_moment(2, 0.0), 8.0, 1e-9);
+        let nan: f64 = n.lower_partial_moment(1, f64::NAN);
+        assert!(nan.is_nan());
+        let nan: f64 = n.upper_partial_moment(1, f64::NAN);
+        assert!(nan.is_nan());
+    }
+
     #[test]
     fn test_min_max() {
         let min = |x: Exp| x.min();
@@ -411,8 +509,10 @@ mod tests {
     #[test]
     fn test_ln_pdf() {
         let ln_pdf = |arg: f64| move |x: Exp| x.ln_pdf(arg);
+        #[allow(clippy::approx_constant)]
         test_absolute(0.1, -2.302585092994045684018, 1e-15, ln_pdf(0.0));
         test_exact(1.0, 0.0, ln_pdf(0.0));
+        #[allow(clippy::approx_constant)]
         test_exact(10.0, 2.302585092994045684018, ln_pdf(0.0));
         test_is_nan(f64::INFINITY, ln_pdf(0.0));
         test_absolute(0.1, -2.312585092994045684018, 1e-15, ln_pdf(0.1));
@@ -508,4 +608,62 @@ mod tests {
         test::check_continuous_distribution(&create_ok(1.5), 0.0, 20.0);
         test::check_continuous_distribution(&create_ok(2.5), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_clt_approximation() {
+        use crate::distribution::{Cauchy, Dirac, Gamma};
+
+        // the exact distribution of the sum is Gamma(n, λ)
+        for &(rate, n) in &[(0.5, 1), (2.0, 30), (10.0, 1000)] {
+            let d = create_ok(rate);
+            let approx = d.clt_approximation(n).unwrap();
+            let exact = Gamma::new(n as f64, rate).unwrap();
+            assert_almost_eq!(approx.mean().unwrap(), n as f64 * d.mean().unwrap(), 1e-12);
+            assert_almost_eq!(approx.variance().unwrap(), n as f64 * d.variance().unwrap(), 1e-12);
+            assert_almost_eq!(approx.mean().unwrap(), exact.mean().unwrap(), 1e-12);
+            assert_almost_eq!(approx.variance().unwrap(), exact.variance().unwrap(), 1e-10);
+        }
+        assert!(create_ok(1.0).clt_approximation(0).is_none());
+        assert!(Cauchy::new(0.0, 1.0).unwrap().clt_approximation(10).is_none());
+        assert!(Dirac::new(1.0).unwrap().clt_approximation(10).is_none());
+    }
+
+    #[test]
+    fn test_nth_order_statistic_cdf() {
+        let d = create_ok(1.5);
+        for &n in &[1, 2, 7, 50] {
+            for &x in &[0.01, 0.3, 1.0, 4.0] {
+                // the maximum is below x only if every draw is
+                let max = d.nth_order_statistic_cdf(n, n, x).unwrap();
+                assert_almost_eq!(max, d.cdf(x).powi(n as i32), 1e-13);
+                // the minimum of n draws is exponential with rate n λ
+                let min = d.nth_order_statistic_cdf(1, n, x).unwrap();
+                assert_almost_eq!(min, -(-1.5 * n as f64 * x).exp_m1(), 1e-13);
+            }
+        }
+        // the median of three draws
+        let p = d.cdf(0.5);
+        let median = d.nth_order_statistic_cdf(2, 3, 0.5).unwrap();
+        assert_almost_eq!(median, 3.0 * p * p * (1.0 - p) + p * p * p, 1e-14);
+        assert_eq!(d.nth_order_statistic_cdf(1, 3, -1.0), Some(0.0));
+        assert_eq!(d.nth_order_statistic_cdf(0, 3, 1.0), None);
+        assert_eq!(d.nth_order_statistic_cdf(4, 3, 1.0), None);
+    }
+
+    #[test]
+    fn test_expected_shortfall() {
+        // ((1 - α) ln(1 - α) + α) / (α λ)
+        let exact = |alpha: f64, rate: f64| ((1.0 - alpha) * (-alpha).ln_1p() + alpha) / (alpha * rate);
+        for &rate in &[0.5, 3.0] {
+            let n = create_ok(rate);
+            for &alpha in &[1e-3, 0.1, 0.5, 0.99, 1.0 - 1e-9] {
+                let es = n.expected_shortfall(alpha).unwrap();
+                assert_almost_eq!(es, exact(alpha, rate), 1e-11 * exact(alpha, rate));
+                assert!(es <= n.value_at_risk(alpha));
+            }
+            // the whole distribution
+            assert_almost_eq!(n.expected_shortfall(1.0).unwrap(), 1.0 / rate, 1e-11 / rate);
+            assert_eq!(n.expected_shortfall(1.5), None);
+        }
+    }
 }
@@ -240,6 +240,12 @@ impl Max<f64> for FisherSnedecor {
 }
 
 impl Distribution<f64> for FisherSnedecor {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for orders below half the second degrees of freedom `d2`
+    fn moment_exists(&self, order: u32) -> bool {
+        2.0 * (order as f64) < self.freedom_2
+    }
+
     /// Returns the mean of the fisher-snedecor distribution
     ///
     /// # Panics
@@ -257,6 +257,12 @@ impl Max<f64> for Gamma {
 }
 
 impl Distribution<f64> for Gamma {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the gamma distribution
     ///
     /// # Formula
@@ -312,6 +318,19 @@ impl Distribution<f64> for Gamma {
     fn skewness(&self) -> Option<f64> {
         Some(2.0 / self.shape.sqrt())
     }
+
+    /// Returns the excess kurtosis of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 6 / α
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0 / self.shape)
+    }
 }
 
 impl Mode<Option<f64>> for Gamma {
@@ -506,6 +525,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_moment_exists() {
+        for (shape, rate) in [(0.5, 2.0), (3.0, 1.0)] {
+            let n = create_ok(shape, rate);
+            assert!(n.moment_exists(4));
+            assert!(n.moment_exists(5));
+        }
+    }
+
     #[test]
     fn test_entropy() {
         let f = |x: Gamma| x.entropy().unwrap();
@@ -521,6 +549,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let kurtosis = |x: Gamma| x.excess_kurtosis().unwrap();
+        test_exact(1.0, 0.1, 6.0, kurtosis);
+        test_exact(10.0, 1.0, 0.6, kurtosis);
+        test_exact(4.0, f64::INFINITY, 1.5, kurtosis);
+    }
+
     #[test]
     fn test_skewness() {
         let f = |x: Gamma| x.skewness().unwrap();
@@ -553,6 +589,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mode_numeric() {
+        let f = |x: Gamma| x.mode_numeric();
+        test_absolute(10.0, 10.0, 0.9, 1e-7, f);
+        test_absolute(10.0, 1.0, 9.0, 1e-6, f);
+        test_absolute(1.0, 1.0, 0.0, 1e-7, f);
+    }
+
     #[test]
     fn test_min_max() {
         let f = |x: Gamma| x.min();
@@ -0,0 +1,600 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the [Generalized
+/// Pareto](https://en.wikipedia.org/wiki/Generalized_Pareto_distribution)
+/// distribution, the limiting distribution of exceedances over a high
+/// threshold.
+///
+/// The shape `ξ` selects the tail: `ξ = 0` is the shifted exponential
+/// distribution, `ξ > 0` a heavy Pareto-type tail and `ξ < 0` a bounded
+/// support ending at `μ - σ / ξ`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedPareto, Continuous};
+/// use statrs::statistics::Distribution;
+///
+/// let n = GeneralizedPareto::new(0.0, 2.0, 0.5).unwrap();
+/// assert_eq!(n.mean().unwrap(), 4.0);
+/// assert_eq!(n.pdf(0.0), 0.5);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+/// Represents the errors that can occur when creating a [`GeneralizedPareto`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum GeneralizedParetoError {
+    /// The location is NaN or infinite.
+    LocationInvalid,
+
+    /// The scale is NaN, infinite, zero or less than zero.
+    ScaleInvalid,
+
+    /// The shape is NaN or infinite.
+    ShapeInvalid,
+}
+
+impl std::fmt::Display for GeneralizedParetoError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeneralizedParetoError::LocationInvalid => write!(f, "Location is NaN or infinite"),
+            GeneralizedParetoError::ScaleInvalid => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+            GeneralizedParetoError::ShapeInvalid => write!(f, "Shape is NaN or infinite"),
+        }
+    }
+}
+
+impl std::error::Error for GeneralizedParetoError {}
+
+impl GeneralizedPareto {
+    /// Constructs a new generalized Pareto distribution with location `μ`,
+    /// scale `σ` and shape `ξ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let mut result = GeneralizedPareto::new(0.0, 1.0, -0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedPareto::new(0.0, 0.0, 0.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        location: f64,
+        scale: f64,
+        shape: f64,
+    ) -> Result<GeneralizedPareto, GeneralizedParetoError> {
+        if !location.is_finite() {
+            return Err(GeneralizedParetoError::LocationInvalid);
+        }
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(GeneralizedParetoError::ScaleInvalid);
+        }
+        if !shape.is_finite() {
+            return Err(GeneralizedParetoError::ShapeInvalid);
+        }
+        Ok(GeneralizedPareto {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Returns the location `μ` of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale `σ` of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape `ξ` of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.shape(), 0.5);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `ln(1 + ξz) / ξ` for the standardized value `z`, which tends
+    /// to `z` as `ξ` tends to zero
+    fn log_term(&self, z: f64) -> f64 {
+        if self.shape == 0.0 {
+            z
+        } else {
+            (self.shape * z).ln_1p() / self.shape
+        }
+    }
+}
+
+impl std::fmt::Display for GeneralizedPareto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GPD(μ = {}, σ = {}, ξ = {})",
+            self.location, self.scale, self.shape
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+impl ::rand::distributions::Distribution<f64> for GeneralizedPareto {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inverse_cdf(rng.gen())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedPareto {
+    /// Calculates the cumulative distribution function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - (1 + ξ(x - μ) / σ)^(-1 / ξ)   for ξ != 0
+    /// 1 - e^(-(x - μ) / σ)              for ξ = 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` the scale and `ξ` the shape
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            -(-self.log_term((x - self.location) / self.scale)).exp_m1()
+        }
+    }
+
+    /// Calculates the survival function for the generalized Pareto
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (1 + ξ(x - μ) / σ)^(-1 / ξ)   for ξ != 0
+    /// e^(-(x - μ) / σ)              for ξ = 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` the scale and `ξ` the shape
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            (-self.log_term((x - self.location) / self.scale)).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized Pareto distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ ((1 - p)^(-ξ) - 1) / ξ   for ξ != 0
+    /// μ - σ ln(1 - p)                for ξ = 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` the scale and `ξ` the shape
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        let t = -(-p).ln_1p();
+        if p == 1.0 {
+            self.max()
+        } else if self.shape == 0.0 {
+            self.location + self.scale * t
+        } else {
+            self.location + self.scale * (self.shape * t).exp_m1() / self.shape
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    /// Returns the minimum value in the domain of the generalized Pareto
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ
+    /// ```
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    /// Returns the maximum value in the domain of the generalized Pareto
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ - σ / ξ   for ξ < 0
+    /// f64::INFINITY   otherwise
+    /// ```
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1`, where the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ / (1 - ξ)
+    /// ```
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale / (1.0 - self.shape))
+        }
+    }
+
+    /// Returns the variance of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 2`, where the variance is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// σ^2 / ((1 - ξ)^2 (1 - 2ξ))
+    /// ```
+    fn variance(&self) -> Option<f64> {
+        if self.shape >= 0.5 {
+            None
+        } else {
+            let a = self.scale / (1.0 - self.shape);
+            Some(a * a / (1.0 - 2.0 * self.shape))
+        }
+    }
+
+    /// Returns the skewness of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 3`, where the third moment is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2 (1 + ξ) sqrt(1 - 2ξ) / (1 - 3ξ)
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        if self.shape >= 1.0 / 3.0 {
+            None
+        } else {
+            Some(
+                2.0 * (1.0 + self.shape) * (1.0 - 2.0 * self.shape).sqrt()
+                    / (1.0 - 3.0 * self.shape),
+            )
+        }
+    }
+
+    /// Returns the entropy of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(σ) + ξ + 1
+    /// ```
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + self.shape + 1.0)
+    }
+
+    /// Returns whether the moment of the given order exists, which is the
+    /// case when `order * ξ < 1`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) * self.shape < 1.0
+    }
+}
+
+impl Median<f64> for GeneralizedPareto {
+    /// Returns the median of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ (2^ξ - 1) / ξ   for ξ != 0
+    /// μ + σ ln(2)           for ξ = 0
+    /// ```
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedPareto {
+    /// Returns the mode of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ           for ξ >= -1
+    /// μ - σ / ξ   otherwise
+    /// ```
+    ///
+    /// For `ξ = -1` the distribution is uniform and the lower end of the
+    /// support is returned
+    fn mode(&self) -> Option<f64> {
+        if self.shape >= -1.0 {
+            Some(self.location)
+        } else {
+            Some(self.max())
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    /// Calculates the probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (1 / σ) (1 + ξ(x - μ) / σ)^(-1 / ξ - 1)   for ξ != 0
+    /// (1 / σ) e^(-(x - μ) / σ)                  for ξ = 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` the scale and `ξ` the shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -ln(σ) - (1 / ξ + 1) ln(1 + ξ(x - μ) / σ)   for ξ != 0
+    /// -ln(σ) - (x - μ) / σ                        for ξ = 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` the scale and `ξ` the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.location || x > self.max() {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        if self.shape == 0.0 {
+            return -self.scale.ln() - z;
+        }
+        let power = 1.0 / self.shape + 1.0;
+        if power == 0.0 {
+            // uniform, including at the upper end of the support
+            -self.scale.ln()
+        } else {
+            -self.scale.ln() - power * (self.shape * z).ln_1p()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Exp;
+
+    crate::testing_boiler!(location: f64, scale: f64, shape: f64; GeneralizedPareto; GeneralizedParetoError);
+
+    #[test]
+    fn test_create() {
+        create_ok(0.0, 1.0, 0.0);
+        create_ok(-5.0, 0.1, 2.0);
+        create_ok(3.0, 10.0, -0.5);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert_eq!(create_err(f64::NAN, 1.0, 0.0), GeneralizedParetoError::LocationInvalid);
+        assert_eq!(create_err(f64::INFINITY, 1.0, 0.0), GeneralizedParetoError::LocationInvalid);
+        assert_eq!(create_err(0.0, 0.0, 0.0), GeneralizedParetoError::ScaleInvalid);
+        assert_eq!(create_err(0.0, -1.0, 0.0), GeneralizedParetoError::ScaleInvalid);
+        assert_eq!(create_err(0.0, f64::NAN, 0.0), GeneralizedParetoError::ScaleInvalid);
+        assert_eq!(create_err(0.0, f64::INFINITY, 0.0), GeneralizedParetoError::ScaleInvalid);
+        assert_eq!(create_err(0.0, 1.0, f64::NAN), GeneralizedParetoError::ShapeInvalid);
+        assert_eq!(create_err(0.0, 1.0, f64::NEG_INFINITY), GeneralizedParetoError::ShapeInvalid);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedPareto| x.cdf(arg);
+        // reference values computed with mpmath
+        test_absolute(1.0, 2.0, 0.5, 0.4710743801652893, 1e-15, cdf(2.5));
+        test_absolute(0.0, 1.0, -0.5, 0.2775, 1e-15, cdf(0.3));
+        test_absolute(1.0, 3.0, 0.2, 0.8140655679181293, 1e-15, cdf(7.0));
+        test_absolute(1.0, 0.5, -1.5, 0.4571164766810186, 1e-15, cdf(1.2));
+        test_exact(1.0, 2.0, 0.5, 0.0, cdf(0.5));
+        test_exact(0.0, 1.0, -0.5, 1.0, cdf(2.0));
+        test_exact(0.0, 1.0, -0.5, 1.0, cdf(3.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        for &(location, scale, shape, x) in &[(1.0, 2.0, 0.5, 2.5), (0.0, 1.0, -0.5, 0.3), (0.0, 1.0, 0.0, 4.0)] {
+            let n = create_ok(location, scale, shape);
+            assert_almost_eq!(n.sf(x), 1.0 - n.cdf(x), 1e-15);
+        }
+        let n = create_ok(0.0, 1.0, 0.0);
+        assert_eq!(n.sf(800.0), (-800f64).exp());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedPareto| x.pdf(arg);
+        test_absolute(1.0, 2.0, 0.5, 0.1923365890308039, 1e-15, pdf(2.5));
+        test_absolute(0.0, 1.0, -0.5, 0.85, 1e-15, pdf(0.3));
+        test_absolute(1.0, 3.0, 0.2, 0.04427010287663586, 1e-16, pdf(7.0));
+        test_absolute(1.0, 0.5, -1.5, 2.714417616594906, 1e-14, pdf(1.2));
+        test_exact(1.0, 2.0, 0.5, 0.0, pdf(0.5));
+        test_exact(0.0, 1.0, -0.5, 0.0, pdf(2.5));
+        // uniform on [0, 2]
+        test_absolute(0.0, 2.0, -1.0, 0.5, 1e-15, pdf(1.3));
+        test_absolute(0.0, 2.0, -1.0, 0.5, 1e-15, pdf(2.0));
+        // at the upper end of a bounded support
+        test_exact(0.0, 1.0, -0.5, 0.0, pdf(2.0));
+        test_exact(0.0, 1.0, -2.0, f64::INFINITY, pdf(0.5));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        for &(location, scale, shape) in &[(1.0, 2.0, 0.5), (0.0, 1.0, -0.5), (0.0, 1.0, 0.0), (-3.0, 0.5, 1.5)] {
+            let n = create_ok(location, scale, shape);
+            for &p in &[0.01, 0.3, 0.5, 0.9, 0.999] {
+                assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+            }
+            assert_eq!(n.inverse_cdf(0.0), location);
+            assert_eq!(n.inverse_cdf(1.0), n.max());
+        }
+    }
+
+    #[test]
+    fn test_exponential_limit() {
+        let exp = Exp::new(0.5).unwrap();
+        let zero = create_ok(0.0, 2.0, 0.0);
+        for &x in &[0.0, 0.5, 2.0, 10.0] {
+            assert_almost_eq!(zero.cdf(x), exp.cdf(x), 1e-15);
+            assert_almost_eq!(zero.pdf(x), exp.pdf(x), 1e-15);
+        }
+        assert_almost_eq!(zero.inverse_cdf(0.7), exp.inverse_cdf(0.7), 1e-15);
+        assert_eq!(zero.mean(), exp.mean());
+        assert_almost_eq!(zero.variance().unwrap(), exp.variance().unwrap(), 1e-15);
+        assert_almost_eq!(zero.entropy().unwrap(), exp.entropy().unwrap(), 1e-15);
+        assert_almost_eq!(zero.skewness().unwrap(), 2.0, 1e-15);
+    }
+
+    #[test]
+    fn test_continuous_across_zero_shape() {
+        let zero = create_ok(1.0, 2.0, 0.0);
+        for &eps in &[1e-9, -1e-9, 1e-12, -1e-12] {
+            let near = create_ok(1.0, 2.0, eps);
+            for &x in &[1.0, 1.5, 3.0, 10.0] {
+                assert_almost_eq!(near.cdf(x), zero.cdf(x), 1e-8);
+                assert_almost_eq!(near.pdf(x), zero.pdf(x), 1e-8);
+            }
+            assert_almost_eq!(near.inverse_cdf(0.9), zero.inverse_cdf(0.9), 1e-7);
+            assert_almost_eq!(near.median(), zero.median(), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = create_ok(0.0, 2.0, 0.25);
+        assert_almost_eq!(n.mean().unwrap(), 8.0 / 3.0, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 128.0 / 9.0, 1e-13);
+        assert_almost_eq!(n.skewness().unwrap(), 5f64.sqrt() * 10f64.sqrt(), 1e-13);
+
+        let mean = |x: GeneralizedPareto| x.mean();
+        let variance = |x: GeneralizedPareto| x.variance();
+        let skewness = |x: GeneralizedPareto| x.skewness();
+        test_exact(0.0, 1.0, 1.0, None, mean);
+        test_exact(0.0, 1.0, 0.5, None, variance);
+        test_exact(0.0, 1.0, 0.4, None, skewness);
+        test_exact(0.0, 1.0, 0.3, true, |x| x.moment_exists(3));
+        test_exact(0.0, 1.0, 0.5, false, |x| x.moment_exists(2));
+        test_exact(0.0, 1.0, -2.0, true, |x| x.moment_exists(10));
+    }
+
+    #[test]
+    fn test_min_max_mode() {
+        let n = create_ok(1.0, 2.0, -0.5);
+        assert_eq!(n.min(), 1.0);
+        assert_eq!(n.max(), 5.0);
+        assert_eq!(n.mode(), Some(1.0));
+        assert_eq!(create_ok(1.0, 2.0, 0.5).max(), f64::INFINITY);
+        assert_eq!(create_ok(1.0, 2.0, -2.0).mode(), Some(2.0));
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedPareto| x.median();
+        test_absolute(0.0, 1.0, 0.0, f64::consts::LN_2, 1e-15, median);
+        test_absolute(1.0, 2.0, 0.5, 1.0 + 4.0 * (2f64.sqrt() - 1.0), 1e-15, median);
+    }
+
+    #[test]
+    fn test_density_integrates_to_cdf() {
+        use crate::function::integration;
+
+        let n = create_ok(0.5, 1.5, -0.3);
+        let mass = integration::adaptive(|x| n.pdf(x), n.min(), 3.0, 1e-13);
+        assert_almost_eq!(mass, n.cdf(3.0), 1e-10);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_mean() {
+        use ::rand::distributions::Distribution;
+        use ::rand::{rngs::StdRng, SeedableRng};
+
+        let n = create_ok(1.0, 2.0, 0.2);
+        let mut rng = StdRng::seed_from_u64(11);
+        let x: Vec<f64> = (0..100_000).map(|_| n.sample(&mut rng)).collect();
+        assert_almost_eq!(Statistics::mean(&x), n.mean().unwrap(), 0.05);
+    }
+}
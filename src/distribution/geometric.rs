@@ -184,6 +184,12 @@ impl Max<u64> for Geometric {
 }
 
 impl Distribution<f64> for Geometric {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays geometrically
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the geometric distribution
     ///
     /// # Formula
@@ -200,6 +200,12 @@ impl Max<f64> for Gumbel {
 }
 
 impl Distribution<f64> for Gumbel {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as both tails decay at least exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the entropy of the Gumbel distribution
     ///
     /// # Formula
@@ -293,6 +293,12 @@ impl Max<u64> for Hypergeometric {
 }
 
 impl Distribution<f64> for Hypergeometric {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its finite support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the hypergeometric distribution
     ///
     /// # None
@@ -539,10 +545,13 @@ mod tests {
         let ln_pmf = |arg: u64| move |x: Hypergeometric| x.ln_pmf(arg);
         test_exact(0, 0, 0, 0.0, ln_pmf(0));
         test_exact(1, 1, 1, 0.0, ln_pmf(1));
+        #[allow(clippy::approx_constant)]
         test_exact(2, 1, 1, -0.6931471805599453094172, ln_pmf(0));
+        #[allow(clippy::approx_constant)]
         test_exact(2, 1, 1, -0.6931471805599453094172, ln_pmf(1));
         test_exact(2, 2, 2, 0.0, ln_pmf(2));
         test_absolute(10, 1, 1, -0.1053605156578263012275, 1e-14, ln_pmf(0));
+        #[allow(clippy::approx_constant)]
         test_absolute(10, 1, 1, -2.302585092994045684018, 1e-14, ln_pmf(1));
         test_absolute(10, 5, 3, -0.875468737353899935621, 1e-14, ln_pmf(1));
         test_absolute(10, 5, 3, -2.484906649788000310234, 1e-14, ln_pmf(3));
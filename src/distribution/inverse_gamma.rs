@@ -203,6 +203,12 @@ impl Max<f64> for InverseGamma {
 }
 
 impl Distribution<f64> for InverseGamma {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for orders below the shape `α`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.shape
+    }
+
     /// Returns the mean of the inverse distribution
     ///
     /// # None
@@ -342,7 +348,6 @@ impl Continuous<f64, f64> for InverseGamma {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(shape: f64, rate: f64; InverseGamma; InverseGammaError);
 
@@ -393,6 +398,13 @@ mod tests {
         test_none(0.1, 0.1, |dist| dist.variance());
     }
 
+    #[test]
+    fn test_moment_exists() {
+        let n = create_ok(4.5, 1.0);
+        assert!(n.moment_exists(4));
+        assert!(!n.moment_exists(5));
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: InverseGamma| x.entropy().unwrap();
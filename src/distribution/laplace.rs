@@ -214,6 +214,12 @@ impl Max<f64> for Laplace {
 }
 
 impl Distribution<f64> for Laplace {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as both tails decay exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mode of the laplace distribution
     ///
     /// # Formula
@@ -263,6 +269,17 @@ impl Distribution<f64> for Laplace {
     fn skewness(&self) -> Option<f64> {
         Some(0.)
     }
+
+    /// Returns the excess kurtosis of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 3
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(3.)
+    }
 }
 
 impl Median<f64> for Laplace {
@@ -328,8 +345,6 @@ impl Continuous<f64, f64> for Laplace {
 mod tests {
     use super::*;
 
-    use crate::testing_boiler;
-
     testing_boiler!(location: f64, scale: f64; Laplace; LaplaceError);
 
     // A wrapper for the `assert_relative_eq!` macro from the approx crate.
@@ -409,6 +424,15 @@ mod tests {
         test_exact(f64::INFINITY, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_tail_classification() {
+        use crate::statistics::TailType;
+
+        let tail = |x: Laplace| x.tail_classification();
+        test_exact(-6.0, 1.0, Some(TailType::Leptokurtic), tail);
+        test_exact(5.0, 10.0, Some(TailType::Leptokurtic), tail);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Laplace| x.mode().unwrap();
@@ -547,6 +571,7 @@ mod tests {
         test_rel_close(loc, scale, expected, reltol, inverse_cdf(0.001));
 
         // Wolfram Alpha: Inverse CDF[LaplaceDistribution[0, 1], 95/100]
+        #[allow(clippy::approx_constant)]
         let expected = 2.3025850929940456840179914546843642f64;
         test_rel_close(loc, scale, expected, reltol, inverse_cdf(0.95));
     }
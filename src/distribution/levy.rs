@@ -225,6 +225,12 @@ impl Distribution<f64> for Levy {
         Some(f64::INFINITY)
     }
 
+    /// Returns whether the moment of the given order exists, which is
+    /// only the case for order `0`
+    fn moment_exists(&self, order: u32) -> bool {
+        order == 0
+    }
+
     /// Returns the variance of the Levy distribution
     ///
     /// # Formula
@@ -339,7 +345,6 @@ mod tests {
 
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(mu: f64, c: f64; Levy; LevyError);
 
@@ -385,6 +390,12 @@ mod tests {
         test_exact(1.0, 3.0, f64::INFINITY, variance);
     }
 
+    #[test]
+    fn test_moment_exists() {
+        test_exact(0.0, 1.0, true, |x| x.moment_exists(0));
+        test_exact(0.0, 1.0, false, |x| x.moment_exists(1));
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Levy| x.entropy().unwrap();
@@ -79,6 +79,74 @@ impl LogNormal {
 
         Ok(LogNormal { location, scale })
     }
+
+    /// Returns the natural logarithm of the mean of the log-normal
+    /// distribution
+    ///
+    /// # Remarks
+    ///
+    /// Unlike `mean`, which overflows to `f64::INFINITY` once
+    /// `μ + σ^2 / 2` exceeds roughly `709`, this stays finite for any
+    /// finite parameters.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ^2 / 2
+    /// ```
+    ///
+    /// where `μ` is the location and `σ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogNormal;
+    /// use statrs::statistics::Distribution;
+    ///
+    /// let n = LogNormal::new(0.0, 30.0).unwrap();
+    /// assert_eq!(n.ln_mean(), 450.0);
+    /// assert!(LogNormal::new(0.0, 40.0).unwrap().mean().unwrap().is_infinite());
+    /// ```
+    pub fn ln_mean(&self) -> f64 {
+        self.location + self.scale * self.scale / 2.0
+    }
+
+    /// Returns the natural logarithm of the variance of the log-normal
+    /// distribution
+    ///
+    /// # Remarks
+    ///
+    /// Unlike `variance`, which overflows to `f64::INFINITY` once
+    /// `2μ + 2σ^2` exceeds roughly `709`, this stays finite for any
+    /// finite parameters.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(e^(σ^2) - 1) + 2μ + σ^2
+    /// ```
+    ///
+    /// where `μ` is the location and `σ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogNormal;
+    ///
+    /// let n = LogNormal::new(0.0, 30.0).unwrap();
+    /// assert_eq!(n.ln_variance(), 1800.0);
+    /// ```
+    pub fn ln_variance(&self) -> f64 {
+        let sigma2 = self.scale * self.scale;
+        // ln(e^s - 1), rewritten as s + ln(1 - e^-s) for large s to avoid
+        // overflowing the exponential
+        let ln_expm1 = if sigma2 < 1.0 {
+            sigma2.exp_m1().ln()
+        } else {
+            sigma2 + (-(-sigma2).exp()).ln_1p()
+        };
+        ln_expm1 + 2.0 * self.location + sigma2
+    }
 }
 
 impl std::fmt::Display for LogNormal {
@@ -148,6 +216,19 @@ impl ContinuousCDF<f64, f64> for LogNormal {
         }
     }
 
+    /// Calculates the log of the cumulative distribution function for the
+    /// log-normal distribution at `x`, which is the normal log cdf of
+    /// `ln(x)` and stays finite far in the lower tail
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            super::normal::ln_cdf_unchecked(x.ln(), self.location, self.scale)
+        }
+    }
+
     /// Calculates the inverse cumulative distribution function for the
     /// log-normal distribution at `p`
     ///
@@ -174,6 +255,19 @@ impl ContinuousCDF<f64, f64> for LogNormal {
             panic!("p must be within [0.0, 1.0]");
         }
     }
+
+    /// Returns the Gini coefficient of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2Φ(σ / sqrt(2)) - 1
+    /// ```
+    ///
+    /// where `σ` is the scale and `Φ` is the standard normal cdf
+    fn gini_coefficient(&self) -> Option<f64> {
+        Some(2.0 * super::normal::cdf_unchecked(self.scale / f64::consts::SQRT_2, 0.0, 1.0) - 1.0)
+    }
 }
 
 impl Min<f64> for LogNormal {
@@ -205,8 +299,20 @@ impl Max<f64> for LogNormal {
 }
 
 impl Distribution<f64> for LogNormal {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays faster than any power, even though the
+    /// moment generating function does not exist
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the log-normal distribution
     ///
+    /// # Remarks
+    ///
+    /// Overflows to `f64::INFINITY` for large `μ + σ^2 / 2`, see
+    /// [`LogNormal::ln_mean`] for a log-scale alternative
+    ///
     /// # Formula
     ///
     /// ```text
@@ -220,6 +326,11 @@ impl Distribution<f64> for LogNormal {
 
     /// Returns the variance of the log-normal distribution
     ///
+    /// # Remarks
+    ///
+    /// Overflows to `f64::INFINITY` for large `μ + σ^2`, see
+    /// [`LogNormal::ln_variance`] for a log-scale alternative
+    ///
     /// # Formula
     ///
     /// ```text
@@ -253,10 +364,28 @@ impl Distribution<f64> for LogNormal {
     /// (e^(σ^2) + 2) * sqrt(e^(σ^2) - 1)
     /// ```
     ///
-    /// where `μ` is the location and `σ` is the scale
+    /// where `μ` is the location and `σ` is the scale. `e^(σ^2) - 1` is
+    /// evaluated with `exp_m1` so that the skewness stays accurate as it
+    /// approaches `3σ` for small `σ`.
     fn skewness(&self) -> Option<f64> {
-        let expsigma2 = (self.scale * self.scale).exp();
-        Some((expsigma2 + 2.0) * (expsigma2 - 1.0).sqrt())
+        let expm1_sigma2 = (self.scale * self.scale).exp_m1();
+        Some((expm1_sigma2 + 3.0) * expm1_sigma2.sqrt())
+    }
+
+    /// Returns the excess kurtosis of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// e^(4σ^2) + 2e^(3σ^2) + 3e^(2σ^2) - 6
+    /// ```
+    ///
+    /// where `σ` is the scale. This is evaluated as the polynomial
+    /// `16m + 15m^2 + 6m^3 + m^4` in `m = e^(σ^2) - 1`, which avoids the
+    /// cancellation as it approaches `16σ^2` for small `σ`.
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let m = (self.scale * self.scale).exp_m1();
+        Some(m * (16.0 + m * (15.0 + m * (6.0 + m))))
     }
 }
 
@@ -285,8 +414,18 @@ impl Mode<Option<f64>> for LogNormal {
     /// ```
     ///
     /// where `μ` is the location and `σ` is the scale
+    ///
+    /// # Remarks
+    ///
+    /// As `σ` grows the mode underflows towards zero; results that would
+    /// fall in the subnormal range are flushed to `0.0`.
     fn mode(&self) -> Option<f64> {
-        Some((self.location - self.scale * self.scale).exp())
+        let mode = (self.location - self.scale * self.scale).exp();
+        if mode < f64::MIN_POSITIVE {
+            Some(0.0)
+        } else {
+            Some(mode)
+        }
     }
 }
 
@@ -335,7 +474,6 @@ impl Continuous<f64, f64> for LogNormal {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(location: f64, scale: f64; LogNormal; LogNormalError);
 
@@ -415,6 +553,27 @@ mod tests {
         test_exact(5.5, 5.5, 1.127341399856331737823E+31, variance);
     }
 
+    #[test]
+    fn test_ln_mean_ln_variance() {
+        let ln_mean = |x: LogNormal| x.ln_mean();
+        let ln_variance = |x: LogNormal| x.ln_variance();
+        for &(mu, sigma) in &[(-1.0, 0.1), (0.1, 1.5), (2.5, 2.5), (5.5, 5.5)] {
+            let n = LogNormal::new(mu, sigma).unwrap();
+            assert_almost_eq!(n.ln_mean(), n.mean().unwrap().ln(), 1e-12);
+            assert_almost_eq!(n.ln_variance(), n.variance().unwrap().ln(), 1e-12);
+        }
+        test_exact(0.0, 40.0, 800.0, ln_mean);
+        test_exact(0.0, 40.0, 3200.0, ln_variance);
+        test_exact(-1.0, 1e-10, -1.0, ln_mean);
+        test_absolute(-1.0, 1e-10, -2.0 + 2.0 * 1e-10f64.ln(), 1e-9, ln_variance);
+
+        let n = create_ok(0.0, 40.0);
+        assert!(n.mean().unwrap().is_infinite());
+        assert!(n.variance().unwrap().is_infinite());
+        assert!(n.ln_mean().is_finite());
+        assert!(n.ln_variance().is_finite());
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: LogNormal| x.entropy().unwrap();
@@ -444,30 +603,45 @@ mod tests {
         test_exact(5.5, 5.5, 8.6236866254430979764250411929125703716076041932149, entropy);
     }
 
+    #[test]
+    fn test_excess_kurtosis() {
+        let kurtosis = |x: LogNormal| x.excess_kurtosis().unwrap();
+        // 16σ^2 + O(σ^4), where the direct formula cancels badly
+        test_relative(0.0, 1e-3, 0.00001600002300002366668608334671667, kurtosis);
+        test_relative(0.0, 1e-8, 1.60000000000000023e-15, kurtosis);
+        test_relative(-1.0, 0.1, 0.1623238621796893684623564270287053158035, kurtosis);
+        test_relative(0.1, 1.5, 10075.25284652925255392141818571910422784, kurtosis);
+        test_relative(5.0, 2.5, 72283708654.75446794374424568210187069633, kurtosis);
+    }
+
     #[test]
     fn test_skewness() {
         let skewness = |x: LogNormal| x.skewness().unwrap();
-        test_absolute(-1.0, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        // 3σ + 7σ^3 / 4 + O(σ^5), where the direct formula cancels badly
+        test_relative(0.0, 1e-3, 3e-3 + 1.75e-9, skewness);
+        test_relative(0.0, 1e-8, 3e-8, skewness);
+        test_relative(5.0, 1e-3, 0.003000001750000906250367188, skewness);
+        test_absolute(-1.0, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(-1.0, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(-1.0, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(-1.0, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
-        test_absolute(-0.1, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        test_absolute(-0.1, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(-0.1, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(-0.1, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(-0.1, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
-        test_absolute(0.1, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        test_absolute(0.1, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(0.1, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(0.1, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(0.1, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
-        test_absolute(1.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        test_absolute(1.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(1.5, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(1.5, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(1.5, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
-        test_absolute(2.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        test_absolute(2.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(2.5, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(2.5, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(2.5, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
-        test_absolute(5.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-14, skewness);
+        test_absolute(5.5, 0.1, 0.30175909933883402945387113824982918009810212213629, 1e-16, skewness);
         test_exact(5.5, 1.5, 33.46804679732172529147579024311650645764144530123, skewness);
         test_absolute(5.5, 2.5, 11824.007933610287521341659465200553739278936344799, 1e-11, skewness);
         test_absolute(5.5, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
@@ -502,6 +676,45 @@ mod tests {
         test_exact(5.5, 5.5, 0.000000000017832472908146389493511850431527026413424899198327, mode);
     }
 
+    #[test]
+    fn test_mode_large_scale_underflow() {
+        let mode = |x: LogNormal| x.mode();
+        // e^(-729) would be subnormal
+        test_exact(0.0, 27.0, Some(0.0), mode);
+        test_exact(0.0, 1e10, Some(0.0), mode);
+        test_exact(1.0, f64::INFINITY, Some(0.0), mode);
+        // e^(-700) is still a normal float
+        let m = LogNormal::new(0.0, 700f64.sqrt()).unwrap().mode().unwrap();
+        assert!(m.is_normal());
+        assert_almost_eq!(m.ln(), -700.0, 1e-10);
+    }
+
+    #[test]
+    fn test_gini_coefficient() {
+        let std_normal = crate::distribution::Normal::standard();
+        for &(location, scale) in &[(0.0, 0.5), (2.0, 1.0), (-1.0, 2.5)] {
+            let n = LogNormal::new(location, scale).unwrap();
+            let gini = n.gini_coefficient().unwrap();
+            assert_almost_eq!(gini, 2.0 * std_normal.cdf(scale / f64::consts::SQRT_2) - 1.0, 1e-15);
+
+            // 1 - ∫ S^2 / ∫ S evaluated numerically
+            let squared = crate::function::integration::adaptive_semi_infinite(|x| n.sf(x).powi(2), 0.0, 1e-13);
+            assert_almost_eq!(gini, 1.0 - squared / n.mean().unwrap(), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_highest_density_interval() {
+        for &(location, scale, prob) in &[(0.0, 1.0, 0.9), (1.0, 0.5, 0.95), (-1.0, 1.5, 0.5)] {
+            let n = LogNormal::new(location, scale).unwrap();
+            let (a, b) = n.highest_density_interval(prob).unwrap();
+            assert_almost_eq!(n.cdf(b) - n.cdf(a), prob, 1e-9);
+            assert_almost_eq!(n.pdf(a) / n.pdf(b), 1.0, 1e-6);
+            let tail = (1.0 - prob) / 2.0;
+            assert!(b - a < n.inverse_cdf(1.0 - tail) - n.inverse_cdf(tail));
+        }
+    }
+
     #[test]
     fn test_median() {
         let median = |x: LogNormal| x.median();
@@ -583,6 +796,19 @@ mod tests {
         test_exact(0.0, 1.0, 0.0, pdf(0.0));
     }
 
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_moments() {
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        for &(location, scale) in &[(0.0, 0.5), (1.0, 0.25)] {
+            let d = LogNormal::new(location, scale).unwrap();
+            let x: Vec<f64> = d.sample_iter(StdRng::seed_from_u64(1600)).take(100_000).collect();
+            assert_almost_eq!(Statistics::mean(&x) / d.mean().unwrap(), 1.0, 0.01);
+            assert_almost_eq!(Statistics::variance(&x) / d.variance().unwrap(), 1.0, 0.05);
+        }
+    }
+
     #[test]
     fn test_ln_pdf() {
         let ln_pdf = |arg: f64| move |x: LogNormal| x.ln_pdf(arg);
@@ -631,6 +857,17 @@ mod tests {
         cdf_tests(true)
     }
 
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let d = create_ok(1.0, 0.5);
+        assert_eq!(d.inverse_cdf(0.0), d.min());
+        assert_eq!(d.inverse_cdf(1.0), d.max());
+        for i in 1..=100 {
+            let x = i as f64 / 5.0;
+            assert_almost_eq!(d.inverse_cdf(d.cdf(x)), x, 1e-8 * x);
+        }
+    }
+
     // we can reuse the (input, output) pairs from the CDF unit test
     // and verify that passing an 'output' to .inverse_cdf gives 'input',
     // except in cases where output would be 0.0 (the inverse_cdf is defined to
@@ -713,6 +950,22 @@ mod tests {
         almost(2.5, 2.5, 0.8, 0.13802019192453118732001307556787218421918336849121, 1e-11);
     }
 
+    #[test]
+    fn test_tails() {
+        let d = create_ok(1.0, 0.5);
+        // 20 scale units above the location, mpmath: ncdf(-20)
+        let x = 11f64.exp();
+        assert_eq!(1.0 - d.cdf(x), 0.0);
+        assert_almost_eq!(d.sf(x), 2.753624118606233695e-89, 1e-98);
+        // 40 scale units below, mpmath: log(ncdf(-40))
+        assert_almost_eq!(d.ln_cdf((-19f64).exp()), -804.6084420137537882, 1e-10);
+        assert_almost_eq!(d.ln_cdf(1f64.exp()), 0.5f64.ln(), 1e-15);
+        assert_eq!(d.ln_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.ln_cdf(-1.0), f64::NEG_INFINITY);
+        assert_eq!(d.sf(-1.0), 1.0);
+        assert_eq!(d.ln_cdf(f64::INFINITY), 0.0);
+    }
+
     #[test]
     fn test_sf() {
         let sf = |arg: f64| move |x: LogNormal| x.sf(arg);
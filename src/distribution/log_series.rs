@@ -0,0 +1,448 @@
+use crate::distribution::{Discrete, DiscreteCDF};
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the
+/// [Logarithmic](https://en.wikipedia.org/wiki/Logarithmic_distribution)
+/// (log-series) distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{LogSeries, Discrete};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = LogSeries::new(0.5).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.0 / 2f64.ln(), 1e-15));
+/// assert!(prec::almost_eq(n.pmf(1), 0.5 / 2f64.ln(), 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LogSeries {
+    p: f64,
+}
+
+/// Represents the errors that can occur when creating a [`LogSeries`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum LogSeriesError {
+    /// The probability is NaN or not in `(0, 1)`.
+    ProbabilityInvalid,
+}
+
+impl std::fmt::Display for LogSeriesError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LogSeriesError::ProbabilityInvalid => write!(f, "Probability is NaN or not in (0, 1)"),
+        }
+    }
+}
+
+impl std::error::Error for LogSeriesError {}
+
+impl LogSeries {
+    /// Constructs a new log-series distribution with a probability
+    /// of `p`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` is not in `(0, 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogSeries;
+    ///
+    /// let mut result = LogSeries::new(0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = LogSeries::new(1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(p: f64) -> Result<LogSeries, LogSeriesError> {
+        if p <= 0.0 || p >= 1.0 || p.is_nan() {
+            Err(LogSeriesError::ProbabilityInvalid)
+        } else {
+            Ok(LogSeries { p })
+        }
+    }
+
+    /// Returns the probability `p` of the log-series
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LogSeries;
+    ///
+    /// let n = LogSeries::new(0.5).unwrap();
+    /// assert_eq!(n.p(), 0.5);
+    /// ```
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns `ln(1 - p)`
+    fn ln_q(&self) -> f64 {
+        (-self.p).ln_1p()
+    }
+
+    /// Sums `pmf(j)` for `j` in `from..=to`, stopping early once the
+    /// remaining terms, bounded by a geometric series, can no longer
+    /// change the sum, or once they underflow to zero.
+    fn sum_pmf(&self, from: u64, to: u64) -> f64 {
+        let mut term = self.pmf(from);
+        let mut sum = 0.0;
+        let mut j = from;
+        loop {
+            sum += term;
+            if j == to || term == 0.0 || term * self.p / (1.0 - self.p) < f64::EPSILON * sum {
+                return sum;
+            }
+            term *= self.p * j as f64 / (j + 1) as f64;
+            j += 1;
+        }
+    }
+}
+
+impl std::fmt::Display for LogSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Log({})", self.p)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<u64> for LogSeries {
+    /// Generates a sample by sequential search of the inverse cdf
+    fn sample<R: ::rand::Rng + ?Sized>(&self, r: &mut R) -> u64 {
+        let u: f64 = r.gen();
+        let mut k = 1;
+        let mut term = self.pmf(1);
+        let mut cdf = term;
+        while u > cdf && term > 0.0 {
+            term *= self.p * k as f64 / (k + 1) as f64;
+            cdf += term;
+            k += 1;
+        }
+        k
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for LogSeries {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        r.sample::<u64, _>(self) as f64
+    }
+}
+
+impl DiscreteCDF<u64, f64> for LogSeries {
+    /// Calculates the cumulative distribution function for the log-series
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// sum_(k = 1..=x) -p^k / (k * ln(1 - p))
+    /// ```
+    fn cdf(&self, x: u64) -> f64 {
+        if x == 0 {
+            0.0
+        } else if x == u64::MAX {
+            1.0
+        } else {
+            self.sum_pmf(1, x).min(1.0)
+        }
+    }
+
+    /// Calculates the survival function for the log-series
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// sum_(k > x) -p^k / (k * ln(1 - p))
+    /// ```
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            1.0
+        } else if x == u64::MAX {
+            0.0
+        } else {
+            self.sum_pmf(x + 1, u64::MAX).min(1.0)
+        }
+    }
+}
+
+impl Min<u64> for LogSeries {
+    /// Returns the minimum value in the domain of the
+    /// log-series distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1
+    /// ```
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for LogSeries {
+    /// Returns the maximum value in the domain of the
+    /// log-series distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2^64 - 1
+    /// ```
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Distribution<f64> for LogSeries {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays geometrically
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
+    /// Returns the mean of the log-series distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -p / ((1 - p) * ln(1 - p))
+    /// ```
+    fn mean(&self) -> Option<f64> {
+        Some(-self.p / ((1.0 - self.p) * self.ln_q()))
+    }
+
+    /// Returns the variance of the log-series distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -p * (p + ln(1 - p)) / ((1 - p)^2 * ln(1 - p)^2)
+    /// ```
+    fn variance(&self) -> Option<f64> {
+        let ln_q = self.ln_q();
+        let q = 1.0 - self.p;
+        Some(-self.p * (self.p + ln_q) / (q * q * ln_q * ln_q))
+    }
+
+    /// Returns the skewness of the log-series distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (m3 - 3 * m1 * m2 + 2 * m1^3) / σ^3
+    /// ```
+    ///
+    /// where `m1 = ap / (1 - p)`, `m2 = ap / (1 - p)^2` and
+    /// `m3 = ap(1 + p) / (1 - p)^3` are the raw moments, `a = -1 / ln(1 - p)`
+    /// and `σ` is the standard deviation
+    fn skewness(&self) -> Option<f64> {
+        let a = -1.0 / self.ln_q();
+        let q = 1.0 - self.p;
+        let m1 = a * self.p / q;
+        let m2 = m1 / q;
+        let m3 = m2 * (1.0 + self.p) / q;
+        Some(skewness_from_moments(m1, m2, m3))
+    }
+}
+
+impl Mode<Option<u64>> for LogSeries {
+    /// Returns the mode of the log-series distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1
+    /// ```
+    fn mode(&self) -> Option<u64> {
+        Some(1)
+    }
+}
+
+impl Discrete<u64, f64> for LogSeries {
+    /// Calculates the probability mass function for the log-series
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -p^x / (x * ln(1 - p))
+    /// ```
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.ln_pmf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability mass function for the log-series
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(-p^x / (x * ln(1 - p)))
+    /// ```
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            f64::NEG_INFINITY
+        } else {
+            let x = x as f64;
+            x * self.p.ln() - x.ln() - (-self.ln_q()).ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+
+    testing_boiler!(p: f64; LogSeries; LogSeriesError);
+
+    #[test]
+    fn test_create() {
+        create_ok(0.1);
+        create_ok(0.5);
+        create_ok(0.999);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(f64::NAN, LogSeriesError::ProbabilityInvalid);
+        test_create_err(0.0, LogSeriesError::ProbabilityInvalid);
+        test_create_err(1.0, LogSeriesError::ProbabilityInvalid);
+        test_create_err(-0.5, LogSeriesError::ProbabilityInvalid);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: LogSeries| x.mean().unwrap();
+        test_relative(0.1, 1.05458017566998922511221474294, mean);
+        #[allow(clippy::approx_constant)]
+        test_relative(0.5, 1.442695040888963407359924681, mean);
+        test_relative(0.9, 3.90865033712926644886016027025, mean);
+        test_relative(0.99, 21.4975768542109654687308814864, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: LogSeries| x.variance().unwrap();
+        test_relative(0.1, 0.0596164038282871383641120716698, variance);
+        test_relative(0.5, 0.804021100772319016850267758269, variance);
+        test_relative(0.9, 23.8089559133519362217847754753, variance);
+        test_relative(0.99, 1687.61187481838951680187912502, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: LogSeries| x.skewness().unwrap();
+        test_absolute(0.1, 4.85668452702377663347308535209, 1e-9, skewness);
+        test_absolute(0.5, 3.01482443189053829291596309242, 1e-12, skewness);
+        test_absolute(0.9, 3.47534603552553512934442285837, 1e-12, skewness);
+        test_absolute(0.99, 4.45747653401519274627516781303, 1e-12, skewness);
+    }
+
+    #[test]
+    fn test_mode() {
+        test_exact(0.5, Some(1), |x| x.mode());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_exact(0.5, 1, |x| x.min());
+        test_exact(0.5, u64::MAX, |x| x.max());
+    }
+
+    #[test]
+    fn test_pmf() {
+        let pmf = |arg: u64| move |x: LogSeries| x.pmf(arg);
+        test_exact(0.5, 0.0, pmf(0));
+        test_relative(0.1, 0.949122158102990302600993268648, pmf(1));
+        test_relative(0.1, 9.49122158102990302600993268648e-11, pmf(10));
+        test_relative(0.5, 0.0601122933703734753066635283751, pmf(3));
+        test_relative(0.9, 0.0151429122494063526382869678452, pmf(10));
+        test_relative(0.99, 0.070232583582707224186343789816, pmf(3));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let ln_pmf = |arg: u64| move |x: LogSeries| x.ln_pmf(arg);
+        test_exact(0.5, f64::NEG_INFINITY, ln_pmf(0));
+        test_relative(0.5, 0.0601122933703734753066635283751f64.ln(), ln_pmf(3));
+        test_relative(0.9, 0.0151429122494063526382869678452f64.ln(), ln_pmf(10));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: u64| move |x: LogSeries| x.cdf(arg);
+        test_exact(0.5, 0.0, cdf(0));
+        test_relative(0.1, 0.99974200653514978540637957631, cdf(3));
+        test_relative(0.5, 0.961796693925975604906616454001, cdf(3));
+        test_relative(0.9, 0.672287857986233829203947566483, cdf(3));
+        test_relative(0.99, 0.391621357553161157943870468037, cdf(3));
+        test_exact(0.5, 1.0, cdf(u64::MAX));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: LogSeries| x.sf(arg);
+        test_exact(0.5, 1.0, sf(0));
+        test_relative(0.1, 9.49980546179879201588460593898e-12, sf(10));
+        test_relative(0.5, 0.000118769016827211891282359680748, sf(10));
+        test_relative(0.9, 0.0798396110189238087199356978145, sf(10));
+        test_relative(0.99, 0.385217340593109554735315305597, sf(10));
+        test_exact(0.5, 0.0, sf(u64::MAX));
+        // the tail underflows long before u64::MAX
+        test_exact(0.5, 0.0, sf(2000));
+        test_exact(0.1, 0.0, sf(1_000_000));
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        for &p in &[0.1, 0.5, 0.9, 0.99] {
+            let n = create_ok(p);
+            let total: f64 = (1..100_000).map(|k| n.pmf(k)).sum();
+            assert_almost_eq!(total, 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_discrete() {
+        test::check_discrete_distribution(&create_ok(0.1), 10);
+        test::check_discrete_distribution(&create_ok(0.5), 20);
+        test::check_discrete_distribution(&create_ok(0.9), 100);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let dist = create_ok(0.7);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 100_000;
+
+        let samples: Vec<u64> = dist.sample_iter(&mut rng).take(n_samples).collect();
+        assert!(samples.iter().all(|&x| x >= 1));
+        let mean = samples.iter().sum::<u64>() as f64 / n_samples as f64;
+        let ones = samples.iter().filter(|&&x| x == 1).count() as f64 / n_samples as f64;
+
+        assert_almost_eq!(mean, dist.mean().unwrap(), 0.05);
+        assert_almost_eq!(ones, dist.pmf(1), 0.01);
+    }
+}
@@ -0,0 +1,83 @@
+/// A distribution whose samples are the samples of an underlying
+/// distribution passed through a transformation `f`.
+///
+/// Constructed with [`Distribution::map_samples`](crate::statistics::Distribution::map_samples).
+///
+/// # Examples
+///
+/// ```
+/// use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::Distribution;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n = Normal::standard().map_samples(f64::exp);
+/// assert!(n.sample(&mut rng) > 0.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MappedDistribution<D, F> {
+    dist: D,
+    f: F,
+}
+
+impl<D, F> MappedDistribution<D, F> {
+    /// Constructs a new mapped distribution that applies `f` to each
+    /// sample drawn from `dist`.
+    pub fn new(dist: D, f: F) -> Self {
+        MappedDistribution { dist, f }
+    }
+
+    /// Returns a reference to the underlying distribution
+    pub fn inner(&self) -> &D {
+        &self.dist
+    }
+}
+
+impl<D, F> ::rand::distributions::Distribution<f64> for MappedDistribution<D, F>
+where
+    D: ::rand::distributions::Distribution<f64>,
+    F: Fn(f64) -> f64,
+{
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        (self.f)(self.dist.sample(rng))
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{LogNormal, Normal};
+    use crate::statistics::{Distribution, Statistics};
+    use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_map_identity() {
+        let n = Normal::new(2.0, 3.0).unwrap();
+        let mut r1 = StdRng::seed_from_u64(7);
+        let mut r2 = StdRng::seed_from_u64(7);
+        let mapped = n.map_samples(|x| x);
+        for _ in 0..100 {
+            assert_eq!(mapped.sample(&mut r1), n.sample(&mut r2));
+        }
+    }
+
+    #[test]
+    fn test_map_exp_matches_log_normal() {
+        let n_samples = 200_000;
+        let mapped = Normal::standard().map_samples(f64::exp);
+        let log_normal = LogNormal::new(0.0, 1.0).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        let mapped_samples: Vec<f64> = mapped.sample_iter(&mut rng).take(n_samples).collect();
+        let log_normal_samples: Vec<f64> = log_normal.sample_iter(&mut rng).take(n_samples).collect();
+
+        let (mapped_mean, mapped_var) = (Statistics::mean(&mapped_samples), Statistics::variance(&mapped_samples));
+        let (log_normal_mean, log_normal_var) = (Statistics::mean(&log_normal_samples), Statistics::variance(&log_normal_samples));
+
+        assert_almost_eq!(mapped_mean, log_normal.mean().unwrap(), 0.05);
+        assert_almost_eq!(mapped_mean, log_normal_mean, 0.05);
+        // the lognormal variance converges slowly, so only loosely compare
+        assert_almost_eq!(mapped_var / log_normal.variance().unwrap(), 1.0, 0.2);
+        assert_almost_eq!(mapped_var / log_normal_var, 1.0, 0.2);
+    }
+}
@@ -8,19 +8,25 @@ use num_traits::NumAssignOps;
 pub use self::bernoulli::Bernoulli;
 pub use self::beta::{Beta, BetaError};
 pub use self::binomial::{Binomial, BinomialError};
+pub use self::burr::{Burr, BurrError};
 pub use self::categorical::{Categorical, CategoricalError};
 pub use self::cauchy::{Cauchy, CauchyError};
 pub use self::chi::{Chi, ChiError};
 pub use self::chi_squared::ChiSquared;
+pub use self::compound_poisson_gamma::{CompoundPoissonGamma, CompoundPoissonGammaError};
+pub use self::custom::{Custom, CustomError};
+pub use self::dagum::{Dagum, DagumError};
 pub use self::dirac::{Dirac, DiracError};
 #[cfg(feature = "nalgebra")]
 pub use self::dirichlet::{Dirichlet, DirichletError};
 pub use self::discrete_uniform::{DiscreteUniform, DiscreteUniformError};
+pub use self::distance::{bhattacharyya_distance, overlapping_coefficient, wasserstein_1d};
 pub use self::empirical::Empirical;
 pub use self::erlang::Erlang;
 pub use self::exponential::{Exp, ExpError};
 pub use self::fisher_snedecor::{FisherSnedecor, FisherSnedecorError};
 pub use self::gamma::{Gamma, GammaError};
+pub use self::generalized_pareto::{GeneralizedPareto, GeneralizedParetoError};
 pub use self::geometric::{Geometric, GeometricError};
 pub use self::gumbel::{Gumbel, GumbelError};
 pub use self::hypergeometric::{Hypergeometric, HypergeometricError};
@@ -28,6 +34,10 @@ pub use self::inverse_gamma::{InverseGamma, InverseGammaError};
 pub use self::laplace::{Laplace, LaplaceError};
 pub use self::levy::{Levy, LevyError};
 pub use self::log_normal::{LogNormal, LogNormalError};
+pub use self::log_series::{LogSeries, LogSeriesError};
+#[cfg(feature = "rand")]
+pub use self::mapped::MappedDistribution;
+pub use self::morph::{cdf_morph, quantile_morph};
 #[cfg(feature = "nalgebra")]
 pub use self::multinomial::{Multinomial, MultinomialError};
 #[cfg(feature = "nalgebra")]
@@ -35,31 +45,50 @@ pub use self::multivariate_normal::{MultivariateNormal, MultivariateNormalError}
 #[cfg(feature = "nalgebra")]
 pub use self::multivariate_students_t::{MultivariateStudent, MultivariateStudentError};
 pub use self::negative_binomial::{NegativeBinomial, NegativeBinomialError};
-pub use self::normal::{Normal, NormalError};
+pub use self::noncentral_chi_squared::{NoncentralChiSquared, NoncentralChiSquaredError};
+#[cfg(feature = "rand")]
+pub use self::normal::sample_bivariate_normal;
+pub use self::normal::{Normal, NormalBuilder, NormalError};
+pub use self::normal_ratio::{ratio_distribution, NormalRatio};
 pub use self::pareto::{Pareto, ParetoError};
 pub use self::poisson::{Poisson, PoissonError};
+#[cfg(feature = "rand")]
+pub use self::random_walk::random_walk;
+#[cfg(feature = "rand")]
+pub use self::rejection::rejection_sample;
 pub use self::students_t::{StudentsT, StudentsTError};
+#[cfg(feature = "rand")]
+pub use self::transformed::Transformed;
 pub use self::triangular::{Triangular, TriangularError};
+pub use self::truncated_normal::{TruncatedNormal, TruncatedNormalError};
 pub use self::uniform::{Uniform, UniformError};
 pub use self::weibull::{Weibull, WeibullError};
+pub use self::wrapped_normal::{WrappedNormal, WrappedNormalError};
+pub use self::yule_simon::{YuleSimon, YuleSimonError};
 
 mod bernoulli;
 mod beta;
 mod binomial;
+mod burr;
 mod categorical;
 mod cauchy;
 mod chi;
 mod chi_squared;
+mod compound_poisson_gamma;
+mod custom;
+mod dagum;
 mod dirac;
 #[cfg(feature = "nalgebra")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
 mod dirichlet;
 mod discrete_uniform;
+mod distance;
 mod empirical;
 mod erlang;
 mod exponential;
 mod fisher_snedecor;
 mod gamma;
+mod generalized_pareto;
 mod geometric;
 mod gumbel;
 mod hypergeometric;
@@ -69,6 +98,11 @@ mod inverse_gamma;
 mod laplace;
 mod levy;
 mod log_normal;
+mod log_series;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod mapped;
+mod morph;
 #[cfg(feature = "nalgebra")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
 mod multinomial;
@@ -79,13 +113,27 @@ mod multivariate_normal;
 #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
 mod multivariate_students_t;
 mod negative_binomial;
+mod noncentral_chi_squared;
 mod normal;
+mod normal_ratio;
 mod pareto;
 mod poisson;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod random_walk;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod rejection;
 mod students_t;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod transformed;
 mod triangular;
+mod truncated_normal;
 mod uniform;
 mod weibull;
+mod wrapped_normal;
+mod yule_simon;
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 mod ziggurat;
@@ -126,20 +174,79 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
         T::one() - self.cdf(x)
     }
 
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x` for a given distribution. May panic depending
+    /// on the implementor.
+    ///
+    /// The default implementation takes the logarithm of `cdf`, which
+    /// becomes `-∞` once the cdf underflows far in the lower tail; it should
+    /// be overridden where a more accurate form is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5f64.ln(), n.ln_cdf(0.5));
+    /// ```
+    fn ln_cdf(&self, x: K) -> T {
+        self.cdf(x).ln()
+    }
+
     /// Due to issues with rounding and floating-point accuracy the default
     /// implementation may be ill-behaved.
     /// Specialized inverse cdfs should be used whenever possible.
     /// Performs a binary search on the domain of `cdf` to obtain an approximation
-    /// of `F^-1(p) := inf { x | F(x) >= p }`. Needless to say, performance may
-    /// may be lacking.
+    /// of `F^-1(p) := inf { x | F(x) >= p }`, see
+    /// [`inverse_cdf_with`](Self::inverse_cdf_with). Needless to say,
+    /// performance may be lacking.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in `[0, 1]`
     #[doc(alias = "quantile function")]
     #[doc(alias = "quantile")]
     fn inverse_cdf(&self, p: T) -> K {
+        if !(T::zero()..=T::one()).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        // enough halvings to shrink the largest finite bracket to a single
+        // representable value, so this always converges
+        self.inverse_cdf_with(p, K::epsilon(), 2100).unwrap()
+    }
+
+    /// Performs a binary search on the domain of `cdf` to obtain an
+    /// approximation of `F^-1(p) := inf { x | F(x) >= p }`, stopping once the
+    /// bracket around the root is no wider than `tol` or cannot be narrowed
+    /// any further in floating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` is not in `[0, 1]`, or if the search has not
+    /// converged after `max_iter` bisection steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Chi, ContinuousCDF, InverseCdfError};
+    ///
+    /// let n = Chi::new(2).unwrap();
+    /// let exact = (-2.0 * 0.5f64.ln()).sqrt();
+    /// let coarse = n.inverse_cdf_with(0.5, 1e-3, 100).unwrap();
+    /// let fine = n.inverse_cdf_with(0.5, 1e-12, 100).unwrap();
+    /// assert!((fine - exact).abs() < (coarse - exact).abs());
+    /// assert_eq!(n.inverse_cdf_with(0.5, 1e-12, 5), Err(InverseCdfError::NotConverged));
+    /// ```
+    fn inverse_cdf_with(&self, p: T, tol: K, max_iter: usize) -> Result<K, InverseCdfError> {
+        if !(T::zero()..=T::one()).contains(&p) {
+            return Err(InverseCdfError::ProbabilityInvalid);
+        }
         if p == T::zero() {
-            return self.min();
+            return Ok(self.min());
         };
         if p == T::one() {
-            return self.max();
+            return Ok(self.max());
         };
         let two = K::one() + K::one();
         let mut high = two;
@@ -150,20 +257,715 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
         while self.cdf(high) < p {
             high = high + high;
         }
-        let mut i = 16;
-        while i != 0 {
+        for _ in 0..max_iter {
             let mid = (high + low) / two;
+            if high - low <= tol || mid <= low || mid >= high {
+                return Ok(mid);
+            }
             if self.cdf(mid) >= p {
                 high = mid;
             } else {
                 low = mid;
             }
-            i -= 1;
         }
-        (high + low) / two
+        Err(InverseCdfError::NotConverged)
+    }
+
+    /// Evaluates `inverse_cdf` at each of the probabilities in `ps`, e.g. to
+    /// build a table of percentiles.
+    ///
+    /// Returns `None` if any probability lies outside `[0, 1]` or is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(n.inverse_cdf_map(&[0.25, 0.5, 0.75]), Some(vec![1.0, 2.0, 3.0]));
+    /// assert_eq!(n.inverse_cdf_map(&[0.5, 1.5]), None);
+    /// ```
+    fn inverse_cdf_map(&self, ps: &[T]) -> Option<Vec<K>> {
+        let valid = |p: &T| *p >= T::zero() && *p <= T::one();
+        if !ps.iter().all(valid) {
+            return None;
+        }
+        Some(ps.iter().map(|&p| self.inverse_cdf(p)).collect())
+    }
+
+    /// Returns a percentile table as `(percentile, value)` pairs, with the
+    /// percentiles running from `step` to `100 - step` in increments of
+    /// `step` and each value given by [`inverse_cdf`](Self::inverse_cdf).
+    ///
+    /// Returns `None` unless `0 < step < 50`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(
+    ///     n.percentile_curve(25.0),
+    ///     Some(vec![(25.0, 1.0), (50.0, 2.0), (75.0, 3.0)])
+    /// );
+    /// assert_eq!(n.percentile_curve(50.0), None);
+    /// ```
+    fn percentile_curve(&self, step: T) -> Option<Vec<(T, K)>> {
+        let hundred = T::from(100.0).unwrap();
+        if !(step > T::zero() && step < hundred / T::from(2.0).unwrap()) {
+            return None;
+        }
+        // allow for rounding when step divides 100 exactly, e.g. 0.1
+        let slack = T::from(1e-9).unwrap();
+        let count = ((hundred - step) / step + slack).floor().to_usize()?;
+        Some(
+            (1..=count)
+                .map(|i| {
+                    let pct = step * T::from(i).unwrap();
+                    (pct, self.inverse_cdf(pct / hundred))
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns a discretized version of the distribution on the integers
+    /// `lo..=hi`, as a [`Categorical`] whose outcome `i` stands for the
+    /// integer `lo + i`.
+    ///
+    /// Each integer `k` gets the mass `cdf(k + 0.5) - cdf(k - 0.5)`, so
+    /// values are rounded to the nearest integer, and the masses are then
+    /// normalized, which spreads any mass outside `[lo - 0.5, hi + 0.5]`
+    /// proportionally over the grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lo > hi` or the distribution has no mass on the
+    /// grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Discrete, Uniform};
+    ///
+    /// let n = Uniform::new(-0.5, 3.5).unwrap();
+    /// let d = n.discretize(0, 3).unwrap();
+    /// assert_eq!(d.pmf(0), 0.25);
+    /// assert_eq!(d.pmf(3), 0.25);
+    /// ```
+    fn discretize(&self, lo: i64, hi: i64) -> Result<Categorical, CategoricalError> {
+        let half = K::from(0.5).unwrap();
+        let mass: Vec<f64> = (lo..=hi)
+            .map(|k| {
+                let k = K::from(k).unwrap();
+                let p = self.cdf(k + half) - self.cdf(k - half);
+                p.to_f64().unwrap().max(0.0)
+            })
+            .collect();
+        Categorical::new(&mass)
+    }
+
+    /// Returns the value at risk at level `alpha`, the `alpha` quantile of
+    /// the distribution. This is an alias of
+    /// [`inverse_cdf`](Self::inverse_cdf) under its name in risk
+    /// management, with losses in the lower tail.
+    ///
+    /// # Panics
+    ///
+    /// If `alpha` is not in `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.value_at_risk(0.05), n.inverse_cdf(0.05));
+    /// ```
+    fn value_at_risk(&self, alpha: T) -> K {
+        self.inverse_cdf(alpha)
+    }
+
+    /// Returns the time at which the survival function falls to
+    /// `reliability`, e.g. the B10 life for a reliability of `0.9`.
+    ///
+    /// Returns `None` if `reliability` is not in `[0, 1]`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// F^-1(1 - R)
+    /// ```
+    ///
+    /// where `R` is the reliability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Exp};
+    ///
+    /// let n = Exp::new(0.5).unwrap();
+    /// let b10 = n.time_to_reliability(0.9).unwrap();
+    /// assert!((b10 + 0.9f64.ln() / 0.5).abs() < 1e-15);
+    /// ```
+    fn time_to_reliability(&self, reliability: T) -> Option<K> {
+        if !(reliability >= T::zero() && reliability <= T::one()) {
+            return None;
+        }
+        Some(self.inverse_cdf(T::one() - reliability))
+    }
+
+    /// Returns the expected shortfall (tail conditional expectation) at
+    /// level `alpha`, the mean of the distribution below its `alpha`
+    /// quantile, `E[X | X <= F^-1(alpha)]`.
+    ///
+    /// Returns `None` if `alpha` is not in `(0, 1]`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (1 / α) ∫_0^α F^-1(u) du
+    /// ```
+    ///
+    /// The default implementation integrates `inverse_cdf` numerically
+    /// over `(0, α)` with [`adaptive`](crate::function::integration::adaptive).
+    ///
+    /// # Remarks
+    ///
+    /// The result is only meaningful if the lower tail has a finite mean.
+    /// Heavy lower tails make the integrand diverge quickly at zero, which
+    /// costs accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// let es = n.expected_shortfall(0.1).unwrap();
+    /// assert!((es - 0.05).abs() < 1e-12);
+    /// ```
+    fn expected_shortfall(&self, alpha: T) -> Option<K> {
+        use crate::function::integration::adaptive;
+
+        if !(alpha > T::zero() && alpha <= T::one()) {
+            return None;
+        }
+        let alpha_f = alpha.to_f64()?;
+        // the quantile function may be infinite at the endpoints, which
+        // carry no mass
+        let quantile = |u: f64| {
+            let x = self.inverse_cdf(T::from(u).unwrap()).to_f64().unwrap();
+            if x.is_finite() {
+                x
+            } else {
+                0.0
+            }
+        };
+        K::from(adaptive(quantile, 0.0, alpha_f, 1e-14 * alpha_f) / alpha_f)
+    }
+
+    /// Returns the Gini coefficient of a nonnegative distribution, a measure
+    /// of inequality in `[0, 1]` equal to half the expected absolute
+    /// difference of two independent draws relative to the mean.
+    ///
+    /// Returns `None` if the support extends below zero or the mean is not
+    /// finite and positive.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - ∫_0^∞ S(x)^2 dx / ∫_0^∞ S(x) dx
+    /// ```
+    ///
+    /// where `S` is the survival function. This is equivalent to one minus
+    /// twice the area under the Lorenz curve. The default implementation
+    /// evaluates both integrals numerically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!((n.gini_coefficient().unwrap() - 1.0 / 3.0).abs() < 1e-10);
+    /// ```
+    fn gini_coefficient(&self) -> Option<T> {
+        use crate::function::integration::{adaptive, adaptive_semi_infinite};
+
+        let lo = self.min().to_f64()?;
+        let hi = self.max().to_f64()?;
+        if lo.is_nan() || lo < 0.0 {
+            return None;
+        }
+        let sf = |x: f64| self.sf(K::from(x).unwrap()).to_f64().unwrap();
+        let integrate = |f: &dyn Fn(f64) -> f64| {
+            if hi.is_finite() {
+                adaptive(f, lo, hi, 1e-12)
+            } else {
+                adaptive_semi_infinite(f, lo, 1e-12)
+            }
+        };
+        // the survival function is one on [0, lo)
+        let mean = lo + integrate(&sf);
+        if !(mean.is_finite() && mean > 0.0) {
+            return None;
+        }
+        let squared = lo + integrate(&|x| sf(x).powi(2));
+        T::from(1.0 - squared / mean)
+    }
+
+    /// Returns the cumulative distribution function at `x` of the `k`-th
+    /// smallest of `n` independent draws, so `k = 1` gives the minimum and
+    /// `k = n` the maximum.
+    ///
+    /// Returns `None` if `k` is zero or greater than `n`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// I_F(x)(k, n - k + 1)
+    /// ```
+    ///
+    /// where `F` is the cdf and `I` is the regularized incomplete beta
+    /// function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// // the largest of three draws is below 0.5 only if all three are
+    /// let max = n.nth_order_statistic_cdf(3, 3, 0.5).unwrap();
+    /// assert!((max - 0.125).abs() < 1e-15);
+    /// ```
+    fn nth_order_statistic_cdf(&self, k: usize, n: usize, x: K) -> Option<T> {
+        if k == 0 || k > n {
+            return None;
+        }
+        let p = self.cdf(x).to_f64()?;
+        let prob = crate::function::beta::beta_reg(k as f64, (n - k + 1) as f64, p);
+        T::from(prob)
+    }
+
+    /// Returns the highest density interval `(a, b)`, the shortest interval
+    /// carrying probability `prob`, of a unimodal distribution.
+    ///
+    /// Returns `None` if `prob` is not in `(0, 1)`.
+    ///
+    /// # Remarks
+    ///
+    /// The lower tail mass `p` is found by bisection on
+    /// `pdf(F^-1(p + prob)) - pdf(F^-1(p))`, which decreases in `p` for
+    /// unimodal densities, so at the result `pdf(a) = pdf(b)` unless the
+    /// mode lies on the boundary of the support. For symmetric
+    /// distributions this is the equal-tailed interval. This relies on
+    /// `inverse_cdf`, so it should only be used with distributions that
+    /// specialize it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, LogNormal};
+    ///
+    /// let n = LogNormal::new(0.0, 1.0).unwrap();
+    /// let (a, b) = n.highest_density_interval(0.9).unwrap();
+    /// assert!((n.cdf(b) - n.cdf(a) - 0.9).abs() < 1e-9);
+    /// assert!(b - a < n.inverse_cdf(0.95) - n.inverse_cdf(0.05));
+    /// ```
+    fn highest_density_interval(&self, prob: T) -> Option<(K, K)>
+    where
+        Self: Continuous<K, T>,
+    {
+        if !(prob > T::zero() && prob < T::one()) {
+            return None;
+        }
+        let two = T::one() + T::one();
+        let mut lo = T::zero();
+        let mut hi = T::one() - prob;
+        for _ in 0..200 {
+            let mid = (lo + hi) / two;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            let a = self.inverse_cdf(mid);
+            let b = self.inverse_cdf(mid + prob);
+            if self.pdf(b) > self.pdf(a) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let p = (lo + hi) / two;
+        Some((self.inverse_cdf(p), self.inverse_cdf(p + prob)))
+    }
+
+    /// Maps a point `u` in `(0, 1)`, typically taken from a low-discrepancy
+    /// sequence such as [`HaltonSequence`](crate::quasi::HaltonSequence),
+    /// onto the distribution through its inverse cdf.
+    ///
+    /// Feeding an evenly spread sequence of points yields a deterministic
+    /// sample suitable for quasi-Monte-Carlo integration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    /// use statrs::quasi::HaltonSequence;
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let x: Vec<f64> = HaltonSequence::new(2).take(3).map(|u| n.sample_quasi(u)).collect();
+    /// assert_eq!(x[0], 0.0);
+    /// assert!(x[1] < 0.0 && x[2] > 0.0);
+    /// ```
+    fn sample_quasi(&self, u: T) -> K {
+        self.inverse_cdf(u)
+    }
+
+    /// Runs a quick numerical self-check of the distribution, useful as a
+    /// guard after constructing one from extreme parameters.
+    ///
+    /// The check fails if the mean or variance is reported but is not
+    /// finite, if the `1e-4` or `1 - 1e-4` quantile is not finite, if the
+    /// pdf is not finite at either of those quantiles or at the median, or
+    /// if the pdf integrated between those quantiles is further than `1e-3`
+    /// from `1 - 2e-4`.
+    ///
+    /// # Remarks
+    ///
+    /// Distributions whose moments diverge and are reported as infinite
+    /// rather than `None`, such as [`Levy`], fail the moment check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal, ValidationError};
+    ///
+    /// assert_eq!(Normal::new(0.0, 1.0).unwrap().validate(), Ok(()));
+    ///
+    /// let n = Normal::new(0.0, f64::INFINITY).unwrap();
+    /// assert_eq!(n.validate(), Err(ValidationError::VarianceNotFinite));
+    /// ```
+    fn validate(&self) -> Result<(), ValidationError>
+    where
+        Self: Continuous<K, T> + crate::statistics::Distribution<K>,
+    {
+        const TAIL: f64 = 1e-4;
+        let moments = [
+            (self.mean(), ValidationError::MeanNotFinite),
+            (self.variance(), ValidationError::VarianceNotFinite),
+        ];
+        for (moment, err) in moments {
+            if matches!(moment, Some(m) if !m.is_finite()) {
+                return Err(err);
+            }
+        }
+
+        let lo = self.inverse_cdf(T::from(TAIL).unwrap()).to_f64().unwrap();
+        let hi = self
+            .inverse_cdf(T::from(1.0 - TAIL).unwrap())
+            .to_f64()
+            .unwrap();
+        if !lo.is_finite() || !hi.is_finite() || lo > hi {
+            return Err(ValidationError::QuantileNotFinite);
+        }
+
+        let pdf = |x: f64| self.pdf(K::from(x).unwrap()).to_f64().unwrap();
+        let median = self.inverse_cdf(T::from(0.5).unwrap()).to_f64().unwrap();
+        if [lo, median, hi].iter().any(|&x| !pdf(x).is_finite()) {
+            return Err(ValidationError::MassNotNormalized);
+        }
+        let mass = crate::function::integration::adaptive(pdf, lo, hi, 1e-6);
+        if mass.is_nan() || (mass - (1.0 - 2.0 * TAIL)).abs() > 1e-3 {
+            return Err(ValidationError::MassNotNormalized);
+        }
+        Ok(())
+    }
+
+    /// Draws a pair of antithetic variates `(F^-1(u), F^-1(1 - u))` from a
+    /// single uniform `u` on `(0, 1)`.
+    ///
+    /// Each element of the pair is distributed according to the
+    /// distribution, while the two are negatively correlated, which
+    /// reduces the variance of Monte-Carlo estimates of monotone
+    /// functions. This relies on `inverse_cdf`, so it should only be used
+    /// with distributions that specialize it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// let (a, b) = n.sample_antithetic(&mut rng);
+    /// assert!((a + b - 2.0).abs() < 1e-9);
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    fn sample_antithetic<R: ::rand::Rng + ?Sized>(&self, r: &mut R) -> (K, K)
+    where
+        ::rand::distributions::Open01: ::rand::distributions::Distribution<T>,
+    {
+        let u: T = r.sample(::rand::distributions::Open01);
+        (self.inverse_cdf(u), self.inverse_cdf(T::one() - u))
+    }
+
+    /// Draws a sample conditional on it lying in the interval `[a, b]`.
+    ///
+    /// A uniform `u` is drawn on `(cdf(a), cdf(b))` and mapped through
+    /// `inverse_cdf`, which unlike rejection sampling costs the same however
+    /// little probability the interval carries. If `a` lies in the upper
+    /// half of the distribution, where the cdf rounds towards one, the
+    /// uniform is instead drawn on `(sf(b), sf(a))` and the sample found by
+    /// bisection on `sf` within the interval. The lower branch relies on
+    /// `inverse_cdf`, so this should only be used with distributions that
+    /// specialize it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `a > b` or either bound is `NaN`, or if the
+    /// interval carries no probability.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use statrs::distribution::{ContinuousCDF, Normal, SampleIntervalError};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let x = n.sample_in_interval(&mut rng, 3.0, 4.0).unwrap();
+    /// assert!((3.0..=4.0).contains(&x));
+    /// assert_eq!(
+    ///     n.sample_in_interval(&mut rng, 4.0, 3.0),
+    ///     Err(SampleIntervalError::IntervalInvalid)
+    /// );
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    fn sample_in_interval<R: ::rand::Rng + ?Sized>(
+        &self,
+        r: &mut R,
+        a: K,
+        b: K,
+    ) -> Result<K, SampleIntervalError>
+    where
+        ::rand::distributions::Open01: ::rand::distributions::Distribution<T>,
+    {
+        if a.is_nan() || b.is_nan() || a > b {
+            return Err(SampleIntervalError::IntervalInvalid);
+        }
+        let lo = self.cdf(a);
+        if lo <= T::from(0.5).unwrap() {
+            let hi = self.cdf(b);
+            if lo >= hi {
+                return Err(SampleIntervalError::IntervalEmpty);
+            }
+            let u: T = r.sample(::rand::distributions::Open01);
+            // clamp, as the inverse cdf may not round trip exactly at the bounds
+            return Ok(self.inverse_cdf(lo + (hi - lo) * u).max(a).min(b));
+        }
+
+        let (upper, lower) = (self.sf(a), self.sf(b));
+        if lower >= upper {
+            return Err(SampleIntervalError::IntervalEmpty);
+        }
+        let u: T = r.sample(::rand::distributions::Open01);
+        let target = lower + (upper - lower) * u;
+        let two = K::one() + K::one();
+        let mut low = a;
+        let mut high = b;
+        if high.is_infinite() {
+            // grow a finite bracket, as sf(b) = 0 < target
+            let mut step = a.abs().max(K::one());
+            high = a + step;
+            while self.sf(high) > target {
+                step = step + step;
+                high = a + step;
+            }
+        }
+        loop {
+            let mid = low + (high - low) / two;
+            if mid <= low || mid >= high {
+                return Ok(mid);
+            }
+            if self.sf(mid) > target {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+    }
+
+    /// Draws one sample from each of `strata` equally likely quantile
+    /// strata, by drawing a uniform within each interval
+    /// `[i / strata, (i + 1) / strata)` of probability space and mapping it
+    /// through `inverse_cdf`. The `i`-th sample lies in the `i`-th stratum.
+    ///
+    /// The samples cover the distribution evenly, so their average
+    /// estimates expectations with lower variance than the same number of
+    /// independent draws. This relies on `inverse_cdf`, so it should only be
+    /// used with distributions that specialize it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `strata` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use statrs::distribution::{ContinuousCDF, SampleStratifiedError, Uniform};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// let x = n.sample_stratified(&mut rng, 4).unwrap();
+    /// for (i, x) in x.into_iter().enumerate() {
+    ///     assert!(i as f64 <= x && x < (i + 1) as f64);
+    /// }
+    /// assert_eq!(
+    ///     n.sample_stratified(&mut rng, 0),
+    ///     Err(SampleStratifiedError::StrataInvalid)
+    /// );
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    fn sample_stratified<R: ::rand::Rng + ?Sized>(
+        &self,
+        r: &mut R,
+        strata: usize,
+    ) -> Result<Vec<K>, SampleStratifiedError>
+    where
+        ::rand::distributions::Open01: ::rand::distributions::Distribution<T>,
+    {
+        if strata == 0 {
+            return Err(SampleStratifiedError::StrataInvalid);
+        }
+        let width = T::one() / T::from(strata).unwrap();
+        Ok((0..strata)
+            .map(|i| {
+                let u: T = r.sample(::rand::distributions::Open01);
+                self.inverse_cdf((T::from(i).unwrap() + u) * width)
+            })
+            .collect())
+    }
+}
+
+/// Represents the ways a distribution can fail
+/// [`ContinuousCDF::validate`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The mean is reported as infinite or NaN.
+    MeanNotFinite,
+
+    /// The variance is reported as infinite or NaN.
+    VarianceNotFinite,
+
+    /// A quantile inside the distribution's support is infinite or NaN.
+    QuantileNotFinite,
+
+    /// The pdf does not integrate to approximately the probability given
+    /// by the cdf.
+    MassNotNormalized,
+}
+
+impl std::fmt::Display for ValidationError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValidationError::MeanNotFinite => write!(f, "Mean is infinite or NaN"),
+            ValidationError::VarianceNotFinite => write!(f, "Variance is infinite or NaN"),
+            ValidationError::QuantileNotFinite => {
+                write!(f, "Quantile inside the support is infinite or NaN")
+            }
+            ValidationError::MassNotNormalized => write!(f, "Pdf does not integrate to one"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Represents the errors that can occur when searching for a quantile with
+/// [`ContinuousCDF::inverse_cdf_with`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum InverseCdfError {
+    /// The probability is NaN or outside `[0, 1]`.
+    ProbabilityInvalid,
+
+    /// The search did not converge within the allowed number of iterations.
+    NotConverged,
+}
+
+impl std::fmt::Display for InverseCdfError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InverseCdfError::ProbabilityInvalid => {
+                write!(f, "Probability is NaN or outside [0, 1]")
+            }
+            InverseCdfError::NotConverged => {
+                write!(f, "Quantile search did not converge")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InverseCdfError {}
+
+/// Represents the errors that can occur when sampling within an interval
+/// with [`ContinuousCDF::sample_in_interval`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SampleIntervalError {
+    /// A bound is NaN or the lower bound exceeds the upper bound.
+    IntervalInvalid,
+
+    /// The interval carries no probability.
+    IntervalEmpty,
+}
+
+impl std::fmt::Display for SampleIntervalError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SampleIntervalError::IntervalInvalid => {
+                write!(
+                    f,
+                    "Interval bound is NaN or lower bound exceeds upper bound"
+                )
+            }
+            SampleIntervalError::IntervalEmpty => write!(f, "Interval carries no probability"),
+        }
+    }
+}
+
+impl std::error::Error for SampleIntervalError {}
+
+/// Represents the errors that can occur when drawing stratified samples
+/// with [`ContinuousCDF::sample_stratified`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SampleStratifiedError {
+    /// The number of strata is zero.
+    StrataInvalid,
+}
+
+impl std::fmt::Display for SampleStratifiedError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SampleStratifiedError::StrataInvalid => write!(f, "Number of strata is zero"),
+        }
     }
 }
 
+impl std::error::Error for SampleStratifiedError {}
+
 /// The `DiscreteCDF` trait is used to specify an interface for univariate
 /// discrete distributions.
 pub trait DiscreteCDF<K: Sized + Num + Ord + Clone + NumAssignOps, T: Float>:
@@ -259,6 +1061,272 @@ pub trait Continuous<K, T> {
     /// assert_eq!(0.0, n.ln_pdf(0.5));
     /// ```
     fn ln_pdf(&self, x: K) -> T;
+
+    /// Returns the surprisal (self-information) `-ln_pdf(x)` of `x`, in nats,
+    /// e.g. as a coding cost or anomaly score. Points outside the support
+    /// have infinite surprisal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(n.surprisal(1.0), 4f64.ln());
+    /// assert_eq!(n.surprisal(5.0), f64::INFINITY);
+    /// ```
+    fn surprisal(&self, x: K) -> T
+    where
+        T: Float,
+    {
+        -self.ln_pdf(x)
+    }
+
+    /// Returns the likelihood ratio `pdf(x) / other.pdf(x)` of `self`
+    /// against `other` at `x`.
+    ///
+    /// Computed as `exp(ln_pdf(x) - other.ln_pdf(x))`, so the ratio stays
+    /// accurate in the tails where both densities underflow to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Normal};
+    ///
+    /// let h0 = Normal::new(0.0, 1.0).unwrap();
+    /// let h1 = Normal::new(1.0, 1.0).unwrap();
+    /// assert_eq!(h0.pdf(40.0), 0.0);
+    /// assert!((h1.likelihood_ratio(&h0, 40.0) - 39.5f64.exp()).abs() < 1e-12 * 39.5f64.exp());
+    /// ```
+    fn likelihood_ratio(&self, other: &Self, x: K) -> T
+    where
+        K: Clone,
+        T: Float,
+    {
+        (self.ln_pdf(x.clone()) - other.ln_pdf(x)).exp()
+    }
+
+    /// Returns the log importance weight `ln_pdf(x) - proposal.ln_pdf(x)` of
+    /// a sample `x` drawn from `proposal` when targeting `self`.
+    ///
+    /// Unlike [`likelihood_ratio`](Self::likelihood_ratio) the proposal may
+    /// be any continuous distribution, and the weight is left in log space so
+    /// it neither overflows nor underflows deep in the tails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Normal, StudentsT};
+    ///
+    /// let target = Normal::new(0.0, 1.0).unwrap();
+    /// let proposal = StudentsT::new(0.0, 1.0, 3.0).unwrap();
+    /// let w = target.log_importance_weight(&proposal, 0.5);
+    /// assert_eq!(w, target.ln_pdf(0.5) - proposal.ln_pdf(0.5));
+    /// ```
+    fn log_importance_weight<D>(&self, proposal: &D, x: K) -> T
+    where
+        D: Continuous<K, T>,
+        K: Clone,
+        T: Float,
+    {
+        self.ln_pdf(x.clone()) - proposal.ln_pdf(x)
+    }
+
+    /// Locates the mode numerically by maximizing `ln_pdf` over the support
+    /// `[min, max]` with golden-section search, for distributions whose mode
+    /// has no closed form.
+    ///
+    /// An infinite support is first mapped onto a bounded interval by a
+    /// monotone change of variable, which leaves the location of the maximum
+    /// unchanged.
+    ///
+    /// # Remarks
+    ///
+    /// The density is assumed to be unimodal; otherwise a local mode is
+    /// returned. Because the density is flat at its peak the result is only
+    /// accurate to roughly the square root of machine precision, relative to
+    /// the scale of the distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Gamma};
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!((n.mode_numeric() - 1.0).abs() < 1e-7);
+    /// ```
+    fn mode_numeric(&self) -> K
+    where
+        Self: Min<K> + Max<K>,
+        K: Float,
+        T: Float,
+    {
+        let (lo, hi) = (self.min(), self.max());
+        let one = K::one();
+        // maps t in (a, b) monotonically onto the support
+        let bounded = (lo.is_finite(), hi.is_finite());
+        let to_x = |t: K| match bounded {
+            (true, true) => t,
+            (true, false) => lo + t / (one - t),
+            (false, true) => hi - t / (one - t),
+            (false, false) => t / (one - t * t),
+        };
+        let (a, b) = match bounded {
+            (true, true) => (lo, hi),
+            (false, false) => (-one, one),
+            _ => (K::zero(), one),
+        };
+        let f = |t: K| {
+            let y = self.ln_pdf(to_x(t));
+            if y.is_nan() {
+                T::neg_infinity()
+            } else {
+                y
+            }
+        };
+
+        let inv_phi = K::from(0.5 * (5f64.sqrt() - 1.0)).unwrap();
+        let (mut a, mut b) = (a, b);
+        let mut c = b - inv_phi * (b - a);
+        let mut d = a + inv_phi * (b - a);
+        let (mut fc, mut fd) = (f(c), f(d));
+        for _ in 0..200 {
+            if to_x(c) == to_x(d) {
+                break;
+            }
+            if fc >= fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - inv_phi * (b - a);
+                fc = f(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + inv_phi * (b - a);
+                fd = f(d);
+            }
+        }
+        to_x(if fc >= fd { c } else { d })
+    }
+
+    /// Returns the lower partial moment of the given `order` about
+    /// `threshold`, the expected shortfall of `X` below `threshold` raised
+    /// to `order`. Order 2 gives the squared downside deviation used in the
+    /// Sortino ratio.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// E[(t - X)^n 1{X < t}] = ∫_min^t (t - x)^n f(x) dx
+    /// ```
+    ///
+    /// where `t` is `threshold`, `n` is `order` and `f` is the pdf. The
+    /// default implementation integrates numerically; with order 0 it is the
+    /// cdf at `t`.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `NaN` if `threshold` is `NaN`. Returns `f64::INFINITY` if the
+    /// support is unbounded below and the moment of the given order does
+    /// not [exist](crate::statistics::Distribution::moment_exists), which
+    /// is decided for both tails together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!((n.lower_partial_moment(2, 0.5) - 1.0 / 24.0).abs() < 1e-12);
+    /// ```
+    fn lower_partial_moment(&self, order: u32, threshold: K) -> T
+    where
+        Self: Min<K> + Max<K> + crate::statistics::Distribution<K>,
+        K: Float,
+        T: Float,
+    {
+        use crate::function::integration::{adaptive, adaptive_semi_infinite};
+
+        let t = threshold.to_f64().unwrap();
+        let lo = self.min().to_f64().unwrap();
+        if t.is_nan() {
+            return T::nan();
+        }
+        if t <= lo {
+            return T::zero();
+        }
+        if !lo.is_finite() && !self.moment_exists(order) {
+            return T::infinity();
+        }
+        let pdf = |x: f64| self.pdf(K::from(x).unwrap()).to_f64().unwrap();
+        let moment = if lo.is_finite() {
+            adaptive(|x| (t - x).powi(order as i32) * pdf(x), lo, t, 1e-12)
+        } else {
+            adaptive_semi_infinite(|s| s.powi(order as i32) * pdf(t - s), 0.0, 1e-12)
+        };
+        T::from(moment).unwrap()
+    }
+
+    /// Returns the upper partial moment of the given `order` about
+    /// `threshold`, the expected excess of `X` above `threshold` raised to
+    /// `order`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// E[(X - t)^n 1{X > t}] = ∫_t^max (x - t)^n f(x) dx
+    /// ```
+    ///
+    /// where `t` is `threshold`, `n` is `order` and `f` is the pdf. The
+    /// default implementation integrates numerically.
+    ///
+    /// # Remarks
+    ///
+    /// For order 1 the upper and lower partial moments differ by the mean
+    /// less the threshold.
+    ///
+    /// Returns `NaN` if `threshold` is `NaN`. Returns `f64::INFINITY` if the
+    /// support is unbounded above and the moment of the given order does
+    /// not [exist](crate::statistics::Distribution::moment_exists), which
+    /// is decided for both tails together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Continuous, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!((n.upper_partial_moment(1, 0.5) - 0.125).abs() < 1e-12);
+    /// ```
+    fn upper_partial_moment(&self, order: u32, threshold: K) -> T
+    where
+        Self: Min<K> + Max<K> + crate::statistics::Distribution<K>,
+        K: Float,
+        T: Float,
+    {
+        use crate::function::integration::{adaptive, adaptive_semi_infinite};
+
+        let t = threshold.to_f64().unwrap();
+        let hi = self.max().to_f64().unwrap();
+        if t.is_nan() {
+            return T::nan();
+        }
+        if t >= hi {
+            return T::zero();
+        }
+        if !hi.is_finite() && !self.moment_exists(order) {
+            return T::infinity();
+        }
+        let pdf = |x: f64| self.pdf(K::from(x).unwrap()).to_f64().unwrap();
+        let moment = if hi.is_finite() {
+            adaptive(|x| (x - t).powi(order as i32) * pdf(x), t, hi, 1e-12)
+        } else {
+            adaptive_semi_infinite(|s| s.powi(order as i32) * pdf(t + s), 0.0, 1e-12)
+        };
+        T::from(moment).unwrap()
+    }
 }
 
 /// The `Discrete` trait provides an interface for interacting with discrete
@@ -269,6 +1337,22 @@ pub trait Continuous<K, T> {
 /// All methods provided by the `Discrete` trait are unchecked, meaning
 /// they can panic if in an invalid state or encountering invalid input
 /// depending on the implementing distribution.
+///
+/// With the `rand` feature, discrete distributions implement
+/// [`rand::distributions::Distribution`] for their integer support type as
+/// well as for `f64`, so integer samples can be drawn directly without a
+/// round trip through `f64`.
+///
+/// ```
+/// # #[cfg(feature = "rand")] {
+/// use rand::Rng;
+/// use statrs::distribution::Binomial;
+///
+/// let n = Binomial::new(0.5, 10).unwrap();
+/// let k: u64 = rand::thread_rng().sample(n);
+/// assert!(k <= 10);
+/// # }
+/// ```
 pub trait Discrete<K, T> {
     /// Returns the probability mass function calculated at `x` for a given
     /// distribution.
@@ -0,0 +1,122 @@
+use crate::distribution::ContinuousCDF;
+
+/// Evaluates at `x` the cdf of the mixture `(1 - t)·p + t·q`, which morphs
+/// from `p` at `t = 0` to `q` at `t = 1`.
+///
+/// Returns `None` if `t` is outside `[0, 1]` or is `NaN`.
+///
+/// # Formula
+///
+/// ```text
+/// (1 - t) F_p(x) + t F_q(x)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{cdf_morph, ContinuousCDF, Uniform};
+///
+/// let p = Uniform::new(0.0, 1.0).unwrap();
+/// let q = Uniform::new(0.0, 2.0).unwrap();
+/// assert_eq!(cdf_morph(&p, &q, 0.5, 0.5), Some(0.375));
+/// assert_eq!(cdf_morph(&p, &q, 1.5, 0.5), None);
+/// ```
+pub fn cdf_morph<P, Q>(p: &P, q: &Q, t: f64, x: f64) -> Option<f64>
+where
+    P: ContinuousCDF<f64, f64>,
+    Q: ContinuousCDF<f64, f64>,
+{
+    if !(0.0..=1.0).contains(&t) {
+        return None;
+    }
+    Some((1.0 - t) * p.cdf(x) + t * q.cdf(x))
+}
+
+/// Evaluates at probability `u` the quantile function of the displacement
+/// interpolation between `p` and `q`, which morphs from `p` at `t = 0` to `q`
+/// at `t = 1` by moving each quantile along a straight line. Unlike
+/// [`cdf_morph`], intermediate distributions keep the shape of the endpoints
+/// rather than becoming bimodal mixtures.
+///
+/// Returns `None` if `t` or `u` is outside `[0, 1]` or is `NaN`.
+///
+/// # Formula
+///
+/// ```text
+/// (1 - t) F_p^-1(u) + t F_q^-1(u)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{quantile_morph, ContinuousCDF, Normal};
+///
+/// // halfway between N(0, 1) and N(4, 3) is N(2, 2)
+/// let p = Normal::new(0.0, 1.0).unwrap();
+/// let q = Normal::new(4.0, 3.0).unwrap();
+/// let mid = Normal::new(2.0, 2.0).unwrap();
+/// let x = quantile_morph(&p, &q, 0.5, 0.9).unwrap();
+/// assert!((x - mid.inverse_cdf(0.9)).abs() < 1e-12);
+/// ```
+pub fn quantile_morph<P, Q>(p: &P, q: &Q, t: f64, u: f64) -> Option<f64>
+where
+    P: ContinuousCDF<f64, f64>,
+    Q: ContinuousCDF<f64, f64>,
+{
+    if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    // the endpoints avoid 0 * inf from the other quantile at u = 0 or 1
+    if t == 0.0 {
+        return Some(p.inverse_cdf(u));
+    }
+    if t == 1.0 {
+        return Some(q.inverse_cdf(u));
+    }
+    Some((1.0 - t) * p.inverse_cdf(u) + t * q.inverse_cdf(u))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Normal};
+
+    #[test]
+    fn test_cdf_morph_endpoints() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Exp::new(2.0).unwrap();
+        for &x in &[-1.0, 0.0, 0.3, 2.0] {
+            assert_eq!(cdf_morph(&p, &q, 0.0, x), Some(p.cdf(x)));
+            assert_eq!(cdf_morph(&p, &q, 1.0, x), Some(q.cdf(x)));
+        }
+        let mid = cdf_morph(&p, &q, 0.25, 0.3).unwrap();
+        assert_almost_eq!(mid, 0.75 * p.cdf(0.3) + 0.25 * q.cdf(0.3), 1e-15);
+    }
+
+    #[test]
+    fn test_quantile_morph_endpoints() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Exp::new(2.0).unwrap();
+        for &u in &[0.1, 0.5, 0.9] {
+            assert_eq!(quantile_morph(&p, &q, 0.0, u), Some(p.inverse_cdf(u)));
+            assert_eq!(quantile_morph(&p, &q, 1.0, u), Some(q.inverse_cdf(u)));
+        }
+        // one quantile function is infinite where the other is finite
+        assert_eq!(quantile_morph(&p, &q, 0.0, 0.0), Some(f64::NEG_INFINITY));
+        assert_eq!(quantile_morph(&p, &q, 1.0, 0.0), Some(0.0));
+        assert_eq!(quantile_morph(&q, &p, 0.0, 0.0), Some(0.0));
+        assert_eq!(quantile_morph(&q, &p, 1.0, 1.0), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_bad_arguments() {
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Normal::new(1.0, 1.0).unwrap();
+        assert_eq!(cdf_morph(&p, &q, -0.1, 0.0), None);
+        assert_eq!(cdf_morph(&p, &q, f64::NAN, 0.0), None);
+        assert_eq!(quantile_morph(&p, &q, 1.1, 0.5), None);
+        assert_eq!(quantile_morph(&p, &q, 0.5, -0.5), None);
+        assert_eq!(quantile_morph(&p, &q, 0.5, f64::NAN), None);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::distribution::Continuous;
-use crate::statistics::{Max, MeanN, Min, Mode, VarianceN};
+use crate::statistics::{Max, MeanN, Min, Mode, RunningCovariance, VarianceN};
 use nalgebra::{Cholesky, Const, DMatrix, DVector, Dim, DimMin, Dyn, OMatrix, OVector};
 use std::f64;
 use std::f64::consts::{E, PI};
@@ -114,6 +114,10 @@ pub enum MultivariateNormalError {
 
     /// After all other validation, computing the Cholesky decomposition failed.
     CholeskyFailed,
+
+    /// The data to fit is empty, contains rows of differing lengths or
+    /// contains a non-finite value.
+    DataInvalid,
 }
 
 impl std::fmt::Display for MultivariateNormalError {
@@ -131,6 +135,9 @@ impl std::fmt::Display for MultivariateNormalError {
             MultivariateNormalError::CholeskyFailed => {
                 write!(f, "Computing the Cholesky decomposition failed")
             }
+            MultivariateNormalError::DataInvalid => {
+                write!(f, "Data is empty, ragged or contains a non-finite value")
+            }
         }
     }
 }
@@ -150,6 +157,52 @@ impl MultivariateNormal<Dyn> {
         let cov = DMatrix::from_vec(mean.len(), mean.len(), cov);
         MultivariateNormal::new_from_nalgebra(mean, cov)
     }
+
+    /// Fits a multivariate normal distribution to `data`, where each element
+    /// is one observation, using the sample mean vector and the unbiased
+    /// sample covariance matrix
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataInvalid` if `data` is empty, its rows differ in length
+    /// or it contains a non-finite value, and `CholeskyFailed` if the sample
+    /// covariance is not positive-definite, which is always the case when
+    /// there are no more observations than dimensions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    /// use statrs::statistics::MeanN;
+    ///
+    /// let data = vec![vec![1.0, 2.0], vec![3.0, 1.0], vec![2.0, 4.0]];
+    /// let mvn = MultivariateNormal::from_data(&data).unwrap();
+    /// assert_eq!(mvn.mean().unwrap().as_slice(), &[2.0, 7.0 / 3.0]);
+    ///
+    /// assert!(MultivariateNormal::from_data(&data[..2]).is_err());
+    /// ```
+    pub fn from_data(data: &[Vec<f64>]) -> Result<Self, MultivariateNormalError> {
+        let dim = match data.first() {
+            Some(row) if !row.is_empty() => row.len(),
+            _ => return Err(MultivariateNormalError::DataInvalid),
+        };
+        if data
+            .iter()
+            .any(|row| row.len() != dim || row.iter().any(|x| !x.is_finite()))
+        {
+            return Err(MultivariateNormalError::DataInvalid);
+        }
+        if data.len() <= dim {
+            return Err(MultivariateNormalError::CholeskyFailed);
+        }
+
+        let mut acc = RunningCovariance::new(dim);
+        for row in data {
+            acc.push(row);
+        }
+        let cov = acc.covariance().concat();
+        MultivariateNormal::new(acc.mean(), cov)
+    }
 }
 
 impl<D> MultivariateNormal<D>
@@ -697,6 +750,41 @@ mod tests  {
         mvn.pdf(&vec![1.].into()); // x.size != mu.size
     }
 
+    #[test]
+    fn test_from_data_invalid() {
+        let err = |data: &[Vec<f64>]| MultivariateNormal::from_data(data).err();
+        assert_eq!(err(&[]), Some(MultivariateNormalError::DataInvalid));
+        assert_eq!(err(&[vec![], vec![]]), Some(MultivariateNormalError::DataInvalid));
+        assert_eq!(err(&[vec![1., 2.], vec![1.], vec![0., 3.]]), Some(MultivariateNormalError::DataInvalid));
+        assert_eq!(err(&[vec![1., 2.], vec![f64::NAN, 1.], vec![0., 3.]]), Some(MultivariateNormalError::DataInvalid));
+        // no more samples than dimensions
+        assert_eq!(err(&[vec![1., 2.], vec![3., 1.]]), Some(MultivariateNormalError::CholeskyFailed));
+        // collinear samples
+        assert_eq!(err(&[vec![1., 2.], vec![2., 4.], vec![3., 6.]]), Some(MultivariateNormalError::CholeskyFailed));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_from_data() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let truth = MultivariateNormal::new(vec![1., -2.], vec![2., 0.6, 0.6, 0.5]).unwrap();
+        let mut rng = StdRng::seed_from_u64(1600);
+        let data: Vec<Vec<f64>> = (0..50_000)
+            .map(|_| truth.sample(&mut rng).as_slice().to_vec())
+            .collect();
+        let fitted = MultivariateNormal::from_data(&data).unwrap();
+
+        let mean = fitted.mean().unwrap();
+        let cov = fitted.variance().unwrap();
+        for (a, b) in mean.iter().zip(truth.mean().unwrap().iter()) {
+            assert_almost_eq!(*a, *b, 0.02);
+        }
+        for (a, b) in cov.iter().zip(truth.variance().unwrap().iter()) {
+            assert_almost_eq!(*a, *b, 0.03);
+        }
+    }
+
     #[test]
     fn test_error_is_sync_send() {
         fn assert_sync_send<T: Sync + Send>() {}
@@ -322,7 +322,6 @@ impl Discrete<u64, f64> for NegativeBinomial {
 mod tests {
     use super::*;
     use crate::distribution::internal::test;
-    use crate::testing_boiler;
 
     testing_boiler!(r: f64, p: f64; NegativeBinomial; NegativeBinomialError);
 
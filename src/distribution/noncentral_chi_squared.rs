@@ -0,0 +1,514 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the
+/// [Noncentral chi-squared](https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution)
+/// distribution, the distribution of a sum of squares of independent
+/// normal variables with unit variance and nonzero means.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralChiSquared, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 5.0);
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.138464022717677565, 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NoncentralChiSquared {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+/// Represents the errors that can occur when creating a [`NoncentralChiSquared`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum NoncentralChiSquaredError {
+    /// The degrees of freedom are NaN, infinite, zero or less than zero.
+    FreedomInvalid,
+
+    /// The noncentrality is NaN, infinite or less than zero.
+    NoncentralityInvalid,
+}
+
+impl std::fmt::Display for NoncentralChiSquaredError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NoncentralChiSquaredError::FreedomInvalid => write!(
+                f,
+                "Degrees of freedom are NaN, infinite, zero or less than zero"
+            ),
+            NoncentralChiSquaredError::NoncentralityInvalid => {
+                write!(f, "Noncentrality is NaN, infinite or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NoncentralChiSquaredError {}
+
+/// Terms of the Poisson mixture smaller than `exp(LN_SERIES_TOL)` times the
+/// running sum are considered negligible.
+const LN_SERIES_TOL: f64 = -40.0;
+
+/// Upper bound on the number of mixture terms summed on either side of the
+/// Poisson mode.
+const MAX_SERIES_TERMS: u64 = 100_000;
+
+impl NoncentralChiSquared {
+    /// Constructs a new noncentral chi-squared distribution with `freedom`
+    /// degrees of freedom and noncentrality parameter `noncentrality`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` is `NaN`, infinite or less than or
+    /// equal to `0.0`, or if `noncentrality` is `NaN`, infinite or less
+    /// than `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let mut result = NoncentralChiSquared::new(3.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralChiSquared::new(3.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        freedom: f64,
+        noncentrality: f64,
+    ) -> Result<NoncentralChiSquared, NoncentralChiSquaredError> {
+        if freedom.is_nan() || freedom.is_infinite() || freedom <= 0.0 {
+            return Err(NoncentralChiSquaredError::FreedomInvalid);
+        }
+
+        if noncentrality.is_nan() || noncentrality.is_infinite() || noncentrality < 0.0 {
+            return Err(NoncentralChiSquaredError::NoncentralityInvalid);
+        }
+
+        Ok(NoncentralChiSquared {
+            freedom,
+            noncentrality,
+        })
+    }
+
+    /// Returns the degrees of freedom of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.freedom(), 3.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality parameter of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 2.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Evaluates `ln(sum_j w_j * exp(ln_term(k + 2j)))` where the `w_j` are
+    /// the Poisson(λ / 2) weights.
+    ///
+    /// The summands are log-concave in `j`, so the sum is started at the
+    /// Poisson mode and extended in both directions until the terms become
+    /// negligible.
+    fn ln_poisson_mixture<F: Fn(f64) -> f64>(&self, ln_term: F) -> f64 {
+        if self.noncentrality == 0.0 {
+            return ln_term(self.freedom);
+        }
+
+        let half = self.noncentrality / 2.0;
+        let ln_half = half.ln();
+        let summand = |j: u64| {
+            let j = j as f64;
+            -half + j * ln_half - gamma::ln_gamma(j + 1.0) + ln_term(self.freedom + 2.0 * j)
+        };
+        let is_negligible = |v: f64, prev: f64, acc: f64| {
+            v <= prev && (v == f64::NEG_INFINITY || v - acc < LN_SERIES_TOL)
+        };
+
+        let mode = half.floor() as u64;
+        let mut acc = f64::NEG_INFINITY;
+
+        let mut prev = f64::NEG_INFINITY;
+        for j in mode..mode + MAX_SERIES_TERMS {
+            let v = summand(j);
+            acc = ln_add_exp(acc, v);
+            if is_negligible(v, prev, acc) {
+                break;
+            }
+            prev = v;
+        }
+
+        let mut prev = summand(mode);
+        for j in (mode.saturating_sub(MAX_SERIES_TERMS)..mode).rev() {
+            let v = summand(j);
+            acc = ln_add_exp(acc, v);
+            if is_negligible(v, prev, acc) {
+                break;
+            }
+            prev = v;
+        }
+
+        acc
+    }
+}
+
+/// Computes `ln(exp(a) + exp(b))` without overflow
+fn ln_add_exp(a: f64, b: f64) -> f64 {
+    if a == f64::NEG_INFINITY {
+        b
+    } else if b == f64::NEG_INFINITY {
+        a
+    } else {
+        a.max(b) + (-(a - b).abs()).exp().ln_1p()
+    }
+}
+
+/// Computes the log density of a central chi-squared distribution with
+/// `freedom` degrees of freedom at `x > 0`
+fn ln_chi_squared_pdf(freedom: f64, x: f64) -> f64 {
+    let shape = freedom / 2.0;
+    (shape - 1.0) * x.ln() - x / 2.0 - shape * f64::consts::LN_2 - gamma::ln_gamma(shape)
+}
+
+impl std::fmt::Display for NoncentralChiSquared {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "χ'^2_{}({})", self.freedom, self.noncentrality)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for NoncentralChiSquared {
+    /// Generates a sample as a Poisson mixture of central chi-squared
+    /// variables, i.e. draws `J ~ Poisson(λ / 2)` and then a chi-squared
+    /// variable with `k + 2J` degrees of freedom.
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let j = if self.noncentrality > 0.0 {
+            super::poisson::sample_unchecked(rng, self.noncentrality / 2.0)
+        } else {
+            0.0
+        };
+        super::gamma::sample_unchecked(rng, self.freedom / 2.0 + j, 0.5)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralChiSquared {
+    /// Calculates the cumulative distribution function for the
+    /// noncentral chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// sum_j e^(-λ / 2) * (λ / 2)^j / j! * P(k / 2 + j, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `P` is the lower regularized incomplete gamma function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            self.ln_poisson_mixture(|nu| gamma::gamma_lr(nu / 2.0, x / 2.0).ln())
+                .exp()
+                .min(1.0)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// noncentral chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// sum_j e^(-λ / 2) * (λ / 2)^j / j! * Q(k / 2 + j, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `Q` is the upper regularized incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            self.ln_poisson_mixture(|nu| gamma::gamma_ur(nu / 2.0, x / 2.0).ln())
+                .exp()
+                .min(1.0)
+        }
+    }
+}
+
+impl Min<f64> for NoncentralChiSquared {
+    /// Returns the minimum value in the domain of the
+    /// noncentral chi-squared distribution representable by a double
+    /// precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralChiSquared {
+    /// Returns the maximum value in the domain of the
+    /// noncentral chi-squared distribution representable by a double
+    /// precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::INFINITY
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralChiSquared {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays exponentially
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
+    /// Returns the mean of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// k + λ
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        Some(self.freedom + self.noncentrality)
+    }
+
+    /// Returns the variance of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2(k + 2λ)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * (self.freedom + 2.0 * self.noncentrality))
+    }
+
+    /// Returns the skewness of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2^(3 / 2) * (k + 3λ) / (k + 2λ)^(3 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn skewness(&self) -> Option<f64> {
+        let s = self.freedom + 2.0 * self.noncentrality;
+        Some(8f64.sqrt() * (self.freedom + 3.0 * self.noncentrality) / (s * s.sqrt()))
+    }
+
+    /// Returns the excess kurtosis of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 12(k + 4λ) / (k + 2λ)^2
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let s = self.freedom + 2.0 * self.noncentrality;
+        Some(12.0 * (self.freedom + 4.0 * self.noncentrality) / (s * s))
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralChiSquared {
+    /// Calculates the probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// sum_j e^(-λ / 2) * (λ / 2)^j / j! * f_(k + 2j)(x)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f_n` is the density of a central chi-squared distribution with `n`
+    /// degrees of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(sum_j e^(-λ / 2) * (λ / 2)^j / j! * f_(k + 2j)(x))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f_n` is the density of a central chi-squared distribution with `n`
+    /// degrees of freedom
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            // only the leading term of the mixture contributes at the origin
+            match self.freedom.partial_cmp(&2.0) {
+                Some(std::cmp::Ordering::Less) => f64::INFINITY,
+                Some(std::cmp::Ordering::Equal) => -self.noncentrality / 2.0 - f64::consts::LN_2,
+                _ => f64::NEG_INFINITY,
+            }
+        } else {
+            self.ln_poisson_mixture(|nu| ln_chi_squared_pdf(nu, x))
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+    use crate::distribution::ChiSquared;
+
+    testing_boiler!(freedom: f64, noncentrality: f64; NoncentralChiSquared; NoncentralChiSquaredError);
+
+    #[test]
+    fn test_create() {
+        create_ok(1.0, 0.0);
+        create_ok(3.0, 2.0);
+        create_ok(0.5, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(0.0, 1.0, NoncentralChiSquaredError::FreedomInvalid);
+        test_create_err(f64::NAN, 1.0, NoncentralChiSquaredError::FreedomInvalid);
+        test_create_err(f64::INFINITY, 1.0, NoncentralChiSquaredError::FreedomInvalid);
+        test_create_err(1.0, -1.0, NoncentralChiSquaredError::NoncentralityInvalid);
+        test_create_err(1.0, f64::NAN, NoncentralChiSquaredError::NoncentralityInvalid);
+        test_create_err(1.0, f64::INFINITY, NoncentralChiSquaredError::NoncentralityInvalid);
+    }
+
+    #[test]
+    fn test_moments() {
+        test_exact(3.0, 2.0, 5.0, |x| x.mean().unwrap());
+        test_exact(3.0, 2.0, 14.0, |x| x.variance().unwrap());
+        test_absolute(3.0, 2.0, 8f64.sqrt() * 9.0 / 7f64.powf(1.5), 1e-15, |x| x.skewness().unwrap());
+        test_absolute(3.0, 2.0, 132.0 / 49.0, 1e-15, |x| x.excess_kurtosis().unwrap());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: NoncentralChiSquared| x.pdf(arg);
+        test_absolute(3.0, 2.0, 0.09498153621397692782, 1e-15, pdf(0.5));
+        test_absolute(3.0, 2.0, 0.138464022717677565, 1e-15, pdf(2.0));
+        test_absolute(3.0, 2.0, 0.030603103916225473379, 1e-15, pdf(10.0));
+        test_relative(3.0, 2.0, 2.4291976889056891034e-21, pdf(120.0));
+        test_absolute(4.5, 10.0, 0.058861162466730524255, 1e-15, pdf(10.0));
+        test_absolute(1.0, 0.5, 0.38587166612902681931, 1e-15, pdf(0.5));
+        test_relative(20.0, 100.0, 2.5883605265779709101e-33, pdf(0.5));
+        test_absolute(20.0, 100.0, 0.018961396271998889677, 1e-15, pdf(120.0));
+        test_exact(3.0, 2.0, 0.0, pdf(0.0));
+        test_exact(1.0, 2.0, f64::INFINITY, pdf(0.0));
+        test_exact(3.0, 2.0, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: NoncentralChiSquared| x.cdf(arg);
+        test_absolute(3.0, 2.0, 0.032839561903178239051, 1e-14, cdf(0.5));
+        test_absolute(3.0, 2.0, 0.59340518008315562709, 1e-14, cdf(5.0));
+        test_absolute(3.0, 2.0, 0.97930105046246893843, 1e-14, cdf(15.0));
+        test_absolute(4.5, 10.0, 0.2869304558401423242, 1e-14, cdf(10.0));
+        test_absolute(1.0, 0.5, 0.74330251214417863237, 1e-14, cdf(2.0));
+        test_relative(20.0, 100.0, 1.1967585287953098321e-34, cdf(0.5));
+        test_absolute(20.0, 100.0, 0.51846275514078819551, 1e-14, cdf(120.0));
+        test_exact(3.0, 2.0, 0.0, cdf(0.0));
+        test_exact(3.0, 2.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: NoncentralChiSquared| x.sf(arg);
+        test_absolute(3.0, 2.0, 0.77926691292587876293, 1e-14, sf(2.0));
+        test_relative(3.0, 2.0, 5.5709226808058563358e-21, sf(120.0));
+        test_relative(4.5, 10.0, 2.8992221910506788172e-14, sf(120.0));
+        test_absolute(20.0, 100.0, 0.48153724485921180449, 1e-14, sf(120.0));
+        test_exact(3.0, 2.0, 1.0, sf(0.0));
+    }
+
+    #[test]
+    fn test_zero_noncentrality_is_central() {
+        for &k in &[1.0, 2.0, 3.0, 7.5] {
+            let nc = create_ok(k, 0.0);
+            let c = ChiSquared::new(k).unwrap();
+            assert_eq!(nc.mean(), c.mean());
+            assert_eq!(nc.variance(), c.variance());
+            assert_almost_eq!(nc.skewness().unwrap(), c.skewness().unwrap(), 1e-15);
+            assert_almost_eq!(nc.excess_kurtosis().unwrap(), 12.0 / k, 1e-15);
+            for &x in &[0.1, 0.5, 1.0, 2.5, 10.0, 30.0] {
+                assert_almost_eq!(nc.pdf(x), c.pdf(x), 1e-14);
+                assert_almost_eq!(nc.cdf(x), c.cdf(x), 1e-14);
+                assert_almost_eq!(nc.sf(x), c.sf(x), 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&create_ok(3.0, 2.0), 0.0, 40.0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let dist = create_ok(3.0, 2.0);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let samples: Vec<f64> = dist.sample_iter(&mut rng).take(100_000).collect();
+
+        assert_almost_eq!(Statistics::mean(&samples), dist.mean().unwrap(), 0.05);
+        assert_almost_eq!(Statistics::variance(&samples), dist.variance().unwrap(), 0.3);
+    }
+}
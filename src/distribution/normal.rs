@@ -1,5 +1,7 @@
 use crate::consts;
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{
+    Continuous, ContinuousCDF, Gamma, GammaError, TruncatedNormal, TruncatedNormalError,
+};
 use crate::function::erf;
 use crate::statistics::*;
 use std::f64;
@@ -32,6 +34,14 @@ pub enum NormalError {
 
     /// The standard deviation is NaN, zero or less than zero.
     StandardDeviationInvalid,
+
+    /// The quantile constraints are not satisfiable: a probability is not
+    /// in `(0, 1)`, a value is not finite, or the values do not strictly
+    /// increase with the probabilities.
+    QuantilesInvalid,
+
+    /// The sample size is zero.
+    SampleSizeInvalid,
 }
 
 impl std::fmt::Display for NormalError {
@@ -42,6 +52,10 @@ impl std::fmt::Display for NormalError {
             NormalError::StandardDeviationInvalid => {
                 write!(f, "Standard deviation is NaN, zero or less than zero")
             }
+            NormalError::QuantilesInvalid => {
+                write!(f, "Quantile constraints are not satisfiable")
+            }
+            NormalError::SampleSizeInvalid => write!(f, "Sample size is zero"),
         }
     }
 }
@@ -97,6 +111,384 @@ impl Normal {
             std_dev: 1.0,
         }
     }
+
+    /// Constructs the normal distribution whose `p1` and `p2` quantiles are
+    /// `x1` and `x2`, e.g. from an expert's statement that the value is
+    /// between 10 and 20 with 90% probability
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// σ = (x2 - x1) / (z2 - z1)
+    /// μ = x1 - σ * z1
+    /// ```
+    ///
+    /// where `z1` and `z2` are the standard normal quantiles at `p1` and `p2`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p1` or `p2` are not in `(0, 1)`, `x1` or `x2`
+    /// are not finite, `p1 == p2`, `x1 == x2`, or the larger value does not
+    /// belong to the larger probability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::from_quantiles(0.05, 10.0, 0.95, 20.0).unwrap();
+    /// assert!((n.cdf(20.0) - n.cdf(10.0) - 0.9).abs() < 1e-10);
+    /// ```
+    pub fn from_quantiles(p1: f64, x1: f64, p2: f64, x2: f64) -> Result<Normal, NormalError> {
+        let in_range = |p: f64| p > 0.0 && p < 1.0;
+        if !in_range(p1) || !in_range(p2) || !x1.is_finite() || !x2.is_finite() {
+            return Err(NormalError::QuantilesInvalid);
+        }
+        let z1 = erf::probit(p1);
+        let z2 = erf::probit(p2);
+        let std_dev = (x2 - x1) / (z2 - z1);
+        if !std_dev.is_finite() || std_dev <= 0.0 {
+            return Err(NormalError::QuantilesInvalid);
+        }
+        Normal::new(x1 - std_dev * z1, std_dev)
+    }
+
+    /// Returns the percent-point function (ppf) of the normal distribution
+    /// at `p`, an alias of [`inverse_cdf`](ContinuousCDF::inverse_cdf) under
+    /// the name used by SciPy.
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.ppf(0.25), n.inverse_cdf(0.25));
+    /// ```
+    pub fn ppf(&self, p: f64) -> f64 {
+        self.inverse_cdf(p)
+    }
+
+    /// Returns the central interval containing a future draw from the
+    /// distribution with probability `confidence`.
+    ///
+    /// This is not a confidence interval for the mean; it does not shrink as
+    /// more data is used to fit the distribution.
+    ///
+    /// Returns `None` if `confidence` is not in `[0, 1]`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (F^-1((1 - c) / 2), F^-1((1 + c) / 2))
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf and `c` is `confidence`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// let (lo, hi) = n.prediction_interval(0.95).unwrap();
+    /// assert!((lo - 6.08).abs() < 0.01 && (hi - 13.92).abs() < 0.01);
+    /// ```
+    pub fn prediction_interval(&self, confidence: f64) -> Option<(f64, f64)> {
+        if !(0.0..=1.0).contains(&confidence) {
+            return None;
+        }
+        Some((
+            self.inverse_cdf((1.0 - confidence) / 2.0),
+            self.inverse_cdf((1.0 + confidence) / 2.0),
+        ))
+    }
+
+    /// Returns the reduction in differential entropy from `self` to
+    /// `posterior`, i.e. the information gained (in nats) when updating a
+    /// normal prior to a normal posterior
+    ///
+    /// The result is negative if the posterior is wider than the prior.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// H(prior) - H(posterior) = ln(σ_prior / σ_posterior)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let prior = Normal::new(0.0, 2.0).unwrap();
+    /// let posterior = Normal::new(0.5, 1.0).unwrap();
+    /// assert_eq!(prior.entropy_reduction(&posterior), 2f64.ln());
+    /// ```
+    pub fn entropy_reduction(&self, posterior: &Normal) -> f64 {
+        (self.std_dev / posterior.std_dev).ln()
+    }
+
+    /// Returns the score of the distribution at `x`, the derivative of the
+    /// log density with respect to `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -(x - μ) / σ^2
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.score(3.0), -0.5);
+    /// ```
+    pub fn score(&self, x: f64) -> f64 {
+        -(x - self.mean) / (self.std_dev * self.std_dev)
+    }
+
+    /// Returns the derivative of the log density at `x` with respect to the
+    /// mean
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (x - μ) / σ^2
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.grad_mu_ln_pdf(3.0), 0.5);
+    /// ```
+    pub fn grad_mu_ln_pdf(&self, x: f64) -> f64 {
+        (x - self.mean) / (self.std_dev * self.std_dev)
+    }
+
+    /// Returns the derivative of the log density at `x` with respect to the
+    /// standard deviation
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ((x - μ)^2 / σ^2 - 1) / σ
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.grad_sigma_ln_pdf(5.0), 1.5);
+    /// ```
+    pub fn grad_sigma_ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        (z * z - 1.0) / self.std_dev
+    }
+
+    /// Returns the Fisher information matrix of a single observation for
+    /// the parameters `(μ, σ)`
+    ///
+    /// Its inverse divided by the sample size is the asymptotic covariance
+    /// of the maximum likelihood estimates of the mean and standard
+    /// deviation.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [[1 / σ^2, 0      ],
+    ///  [0,       2 / σ^2]]
+    /// ```
+    ///
+    /// where `σ` is the standard deviation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.fisher_information(), [[0.25, 0.0], [0.0, 0.5]]);
+    /// ```
+    pub fn fisher_information(&self) -> [[f64; 2]; 2] {
+        let precision = 1.0 / (self.std_dev * self.std_dev);
+        [[precision, 0.0], [0.0, 2.0 * precision]]
+    }
+
+    /// Returns the distribution conditioned on being nonnegative, i.e.
+    /// truncated to `[0, ∞)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the probability of a nonnegative value is so
+    /// small that it underflows to zero, or if the standard deviation is
+    /// infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap().positive().unwrap();
+    /// assert_eq!(n.cdf(0.0), 0.0);
+    /// ```
+    pub fn positive(&self) -> Result<TruncatedNormal, TruncatedNormalError> {
+        TruncatedNormal::new(self.mean, self.std_dev, 0.0, f64::INFINITY)
+    }
+
+    /// Returns the sampling distribution of the mean of `n` independent
+    /// draws from the distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// N(μ, σ / sqrt(n))
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` is the standard deviation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let mean = Normal::new(1.0, 3.0).unwrap().sample_mean_distribution(9).unwrap();
+    /// assert_eq!(mean, Normal::new(1.0, 1.0).unwrap());
+    /// ```
+    pub fn sample_mean_distribution(&self, n: usize) -> Result<Normal, NormalError> {
+        if n == 0 {
+            return Err(NormalError::SampleSizeInvalid);
+        }
+        Normal::new(self.mean, self.std_dev / (n as f64).sqrt())
+    }
+
+    /// Returns the sampling distribution of the unbiased sample variance of
+    /// `n` independent draws from the distribution
+    ///
+    /// This is `σ^2 / (n - 1)` times a chi-squared distribution with `n - 1`
+    /// degrees of freedom, which is the gamma distribution below. Its mean
+    /// is `σ^2` and its variance `2σ^4 / (n - 1)`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// Gamma(shape = (n - 1) / 2, rate = (n - 1) / (2σ^2))
+    /// ```
+    ///
+    /// where `σ` is the standard deviation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n < 2`, since the sample variance is undefined,
+    /// or if the standard deviation is infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::Distribution;
+    ///
+    /// let s2 = Normal::new(0.0, 2.0).unwrap().sample_variance_distribution(9).unwrap();
+    /// assert_eq!(s2.mean().unwrap(), 4.0);
+    /// assert_eq!(s2.variance().unwrap(), 4.0);
+    /// ```
+    pub fn sample_variance_distribution(&self, n: usize) -> Result<Gamma, GammaError> {
+        let dof = n as f64 - 1.0;
+        Gamma::new(dof / 2.0, dof / (2.0 * self.std_dev * self.std_dev))
+    }
+
+    /// Returns a [`NormalBuilder`] whose unset parameters default to those
+    /// of the standard normal distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::builder().mean(5.0).build().unwrap();
+    /// assert_eq!(n, Normal::new(5.0, 1.0).unwrap());
+    /// ```
+    pub fn builder() -> NormalBuilder {
+        NormalBuilder::default()
+    }
+}
+
+/// Builds a [`Normal`] from named parameters, defaulting to a mean of 0
+/// and a standard deviation of 1 for any parameter left unset.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Normal, NormalBuilder};
+///
+/// let n = NormalBuilder::new().std_dev(2.0).mean(-1.0).build().unwrap();
+/// assert_eq!(n, Normal::new(-1.0, 2.0).unwrap());
+///
+/// let result = NormalBuilder::new().std_dev(0.0).build();
+/// assert!(result.is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NormalBuilder {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl NormalBuilder {
+    /// Constructs a new builder for the standard normal distribution
+    pub fn new() -> NormalBuilder {
+        NormalBuilder {
+            mean: 0.0,
+            std_dev: 1.0,
+        }
+    }
+
+    /// Sets the mean of the distribution to build
+    pub fn mean(mut self, mean: f64) -> NormalBuilder {
+        self.mean = mean;
+        self
+    }
+
+    /// Sets the standard deviation of the distribution to build
+    pub fn std_dev(mut self, std_dev: f64) -> NormalBuilder {
+        self.std_dev = std_dev;
+        self
+    }
+
+    /// Constructs the normal distribution from the configured parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Normal::new`]
+    pub fn build(self) -> Result<Normal, NormalError> {
+        Normal::new(self.mean, self.std_dev)
+    }
+}
+
+impl std::default::Default for NormalBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl std::fmt::Display for Normal {
@@ -153,6 +545,22 @@ impl ContinuousCDF<f64, f64> for Normal {
         sf_unchecked(x, self.mean, self.std_dev)
     }
 
+    /// Calculates the log of the cumulative distribution function for the
+    /// normal distribution at `x`, staying finite far in the lower tail
+    /// where the cdf itself underflows
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(Φ(z)) ≈ -z^2 / 2 - ln(-z) - ln(√(2π)) + ln(1 - 1/z^2 + 3/z^4 - ...)
+    /// ```
+    ///
+    /// for `z = (x - μ) / σ` below `-30`, and `ln(Φ(z))` computed directly
+    /// otherwise
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_cdf_unchecked(x, self.mean, self.std_dev)
+    }
+
     /// Calculates the inverse cumulative distribution function for the
     /// normal distribution at `x`.
     /// In other languages, such as R, this is known as the the quantile function.
@@ -176,6 +584,26 @@ impl ContinuousCDF<f64, f64> for Normal {
             self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
         }
     }
+
+    /// Returns the expected shortfall of the normal distribution at level
+    /// `alpha`, the mean below its `alpha` quantile, or `None` if `alpha` is
+    /// not in `(0, 1]`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ - σ φ(Φ^-1(α)) / α
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` the standard deviation, and `φ` and `Φ` the
+    /// standard normal pdf and cdf
+    fn expected_shortfall(&self, alpha: f64) -> Option<f64> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return None;
+        }
+        let z = erf::probit(alpha);
+        Some(self.mean - self.std_dev * pdf_unchecked(z, 0.0, 1.0) / alpha)
+    }
 }
 
 impl Min<f64> for Normal {
@@ -259,6 +687,23 @@ impl Distribution<f64> for Normal {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+
+    /// Returns the excess kurtosis of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    /// Returns whether the moment of the given order exists, which is the
+    /// case for every order unless the standard deviation is infinite
+    fn moment_exists(&self, order: u32) -> bool {
+        order == 0 || self.std_dev.is_finite()
+    }
 }
 
 impl Median<f64> for Normal {
@@ -320,6 +765,61 @@ impl Continuous<f64, f64> for Normal {
     fn ln_pdf(&self, x: f64) -> f64 {
         ln_pdf_unchecked(x, self.mean, self.std_dev)
     }
+
+    /// Calculates the lower partial moment of the normal distribution of
+    /// the given `order` about `threshold`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// σ^n J_n((t - μ) / σ)
+    /// ```
+    ///
+    /// where `t` is the threshold, `μ` is the mean, `σ` is the standard
+    /// deviation and `J_n` satisfies the recurrence
+    ///
+    /// ```text
+    /// J_0(z) = Φ(z), J_1(z) = z Φ(z) + φ(z), J_n(z) = z J_(n-1)(z) + (n - 1) J_(n-2)(z)
+    /// ```
+    ///
+    /// with `φ` and `Φ` the standard normal pdf and cdf
+    fn lower_partial_moment(&self, order: u32, threshold: f64) -> f64 {
+        let z = (threshold - self.mean) / self.std_dev;
+        self.std_dev.powi(order as i32) * partial_moment_unchecked(order, z)
+    }
+
+    /// Calculates the upper partial moment of the normal distribution of
+    /// the given `order` about `threshold`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// σ^n J_n((μ - t) / σ)
+    /// ```
+    ///
+    /// with `J_n` as for the
+    /// [`lower_partial_moment`](Self::lower_partial_moment), by the symmetry
+    /// of the distribution about its mean
+    fn upper_partial_moment(&self, order: u32, threshold: f64) -> f64 {
+        let z = (self.mean - threshold) / self.std_dev;
+        self.std_dev.powi(order as i32) * partial_moment_unchecked(order, z)
+    }
+}
+
+/// computes `E[(z - Z)^n 1{Z < z}]` for a standard normal `Z`
+fn partial_moment_unchecked(order: u32, z: f64) -> f64 {
+    let cdf = cdf_unchecked(z, 0.0, 1.0);
+    let mut prev = cdf;
+    let mut current = z * cdf + pdf_unchecked(z, 0.0, 1.0);
+    if order == 0 {
+        return prev;
+    }
+    for n in 2..=order {
+        let next = z * current + (n - 1) as f64 * prev;
+        prev = current;
+        current = next;
+    }
+    current
 }
 
 /// performs an unchecked cdf calculation for a normal distribution
@@ -334,6 +834,24 @@ pub fn sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// performs an unchecked log cdf calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn ln_cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    let z = (x - mean) / std_dev;
+    if z > 0.0 {
+        (-sf_unchecked(x, mean, std_dev)).ln_1p()
+    } else if z > -30.0 {
+        cdf_unchecked(x, mean, std_dev).ln()
+    } else {
+        // asymptotic expansion of the Mills ratio, accurate to double
+        // precision this far out
+        let w = 1.0 / (z * z);
+        let series =
+            1.0 - w * (1.0 - w * (3.0 - w * (15.0 - w * (105.0 - w * (945.0 - w * 10395.0)))));
+        -0.5 * z * z - (-z).ln() - consts::LN_SQRT_2PI + series.ln()
+    }
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -350,13 +868,54 @@ pub fn ln_pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
 
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
-/// draws a sample from a normal distribution using the Box-Muller algorithm
+/// draws a sample from a normal distribution using the ziggurat algorithm,
+/// which yields one deviate per call with nothing left over to cache
 pub fn sample_unchecked<R: ::rand::Rng + ?Sized>(rng: &mut R, mean: f64, std_dev: f64) -> f64 {
     use crate::distribution::ziggurat;
 
     mean + std_dev * ziggurat::sample_std_normal(rng)
 }
 
+/// Draws a pair from the bivariate normal distribution with means `mu`,
+/// standard deviations `sigma` and correlation `rho`.
+///
+/// Two independent standard normals `z1`, `z2` are combined through the
+/// Cholesky factor of the correlation matrix, giving
+/// `(μ1 + σ1 z1, μ2 + σ2 (ρ z1 + sqrt(1 - ρ^2) z2))`.
+///
+/// # Panics
+///
+/// If `rho` is not in `(-1, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use statrs::distribution::sample_bivariate_normal;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let (x, y) = sample_bivariate_normal(&mut rng, (0.0, 10.0), (1.0, 2.0), 0.8);
+/// assert!(x.is_finite() && y.is_finite());
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn sample_bivariate_normal<R: ::rand::Rng + ?Sized>(
+    r: &mut R,
+    mu: (f64, f64),
+    sigma: (f64, f64),
+    rho: f64,
+) -> (f64, f64) {
+    use crate::distribution::ziggurat;
+
+    if !(rho > -1.0 && rho < 1.0) {
+        panic!("rho must be in (-1, 1)");
+    }
+    let z1 = ziggurat::sample_std_normal(r);
+    let z2 = ziggurat::sample_std_normal(r);
+    let z2 = rho * z1 + (1.0 - rho * rho).sqrt() * z2;
+    (mu.0 + sigma.0 * z1, mu.1 + sigma.1 * z2)
+}
+
 impl std::default::Default for Normal {
     /// Returns the standard normal distribution with a mean of 0
     /// and a standard deviation of 1.
@@ -370,7 +929,6 @@ impl std::default::Default for Normal {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(mean: f64, std_dev: f64; Normal; NormalError);
 
@@ -410,6 +968,57 @@ mod tests {
         test_exact(0.0, f64::INFINITY, f64::INFINITY, entropy);
     }
 
+    #[test]
+    fn test_entropy_bits() {
+        let entropy_bits = |x: Normal| x.entropy_bits().unwrap();
+        // 0.5 * log2(2πe)
+        test_absolute(0.0, 1.0, 0.5 * (2.0 * std::f64::consts::PI * std::f64::consts::E).log2(), 1e-15, entropy_bits);
+        test_absolute(5.0, 2.0, 0.5 * (2.0 * std::f64::consts::PI * std::f64::consts::E).log2() + 1.0, 1e-15, entropy_bits);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_moments() {
+        use crate::statistics::Statistics;
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        for &(mean, std_dev) in &[(0.0, 1.0), (3.0, 2.0), (-10.0, 0.1)] {
+            let d = create_ok(mean, std_dev);
+            let x: Vec<f64> = d.sample_iter(StdRng::seed_from_u64(1600)).take(100_000).collect();
+            assert_almost_eq!(Statistics::mean(&x), mean, 0.02 * std_dev);
+            assert_almost_eq!(Statistics::variance(&x) / (std_dev * std_dev), 1.0, 0.02);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_sample_n() {
+        use crate::statistics::Statistics;
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        let moments = |x: &[f64]| (x.mean(), x.variance());
+        let d = create_ok(3.0, 2.0);
+        let n_samples = 100_003;
+        let parallel = d.par_sample_n(1600, n_samples);
+        let sequential: Vec<f64> = d.sample_iter(StdRng::seed_from_u64(1600)).take(n_samples).collect();
+        assert_eq!(parallel.len(), n_samples);
+
+        let (par_mean, par_var) = moments(&parallel);
+        let (seq_mean, seq_var) = moments(&sequential);
+        assert_almost_eq!(par_mean, 3.0, 0.03);
+        assert_almost_eq!(par_var, 4.0, 0.08);
+        assert_almost_eq!(par_mean, seq_mean, 0.05);
+        assert_almost_eq!(par_var, seq_var, 0.12);
+
+        // the same seed reproduces the samples, also as a prefix
+        assert_eq!(d.par_sample_n(1600, n_samples), parallel);
+        assert_eq!(d.par_sample_n(1600, 5000)[..], parallel[..5000]);
+        assert_ne!(d.par_sample_n(1601, 5000)[..], parallel[..5000]);
+        // chunks are not copies of each other
+        assert_ne!(parallel[..4096], parallel[4096..8192]);
+        assert!(d.par_sample_n(1600, 0).is_empty());
+    }
+
     #[test]
     fn test_skewness() {
         let skewness = |x: Normal| x.skewness().unwrap();
@@ -419,6 +1028,102 @@ mod tests {
         test_exact(0.0, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_partial_moments() {
+        use crate::function::integration::adaptive_semi_infinite;
+
+        let n = create_ok(1.0, 2.0);
+        for t in [-3.0, 0.0, 1.0, 2.5, 6.0] {
+            // order 1 partial moments differ by the mean less the threshold
+            let (lower, upper) = (n.lower_partial_moment(1, t), n.upper_partial_moment(1, t));
+            assert_almost_eq!(upper - lower, 1.0 - t, 1e-13);
+            assert_almost_eq!(n.lower_partial_moment(0, t), n.cdf(t), 1e-15);
+
+            for order in 1..=4 {
+                let numeric_lower = adaptive_semi_infinite(|s| s.powi(order) * n.pdf(t - s), 0.0, 1e-12);
+                let numeric_upper = adaptive_semi_infinite(|s| s.powi(order) * n.pdf(t + s), 0.0, 1e-12);
+                assert_almost_eq!(n.lower_partial_moment(order as u32, t), numeric_lower, 1e-9 * numeric_lower.max(1.0));
+                assert_almost_eq!(n.upper_partial_moment(order as u32, t), numeric_upper, 1e-9 * numeric_upper.max(1.0));
+            }
+        }
+        // about the mean each half holds half the central moment
+        assert_almost_eq!(n.lower_partial_moment(2, 1.0), 2.0, 1e-14);
+        assert_almost_eq!(n.upper_partial_moment(4, 1.0), 24.0, 1e-13);
+    }
+
+    #[test]
+    fn test_discretize() {
+        use crate::distribution::{CategoricalError, Discrete};
+
+        let n = create_ok(2.3, 1.0);
+        let (lo, hi) = (-5, 10);
+        let d = n.discretize(lo, hi).unwrap();
+        let pmf: Vec<f64> = (0..=(hi - lo) as u64).map(|i| d.pmf(i)).collect();
+        assert_almost_eq!(pmf.iter().sum::<f64>(), 1.0, 1e-12);
+
+        // the most likely integer is round(μ), with the mass of [1.5, 2.5)
+        let at_mode = (2 - lo) as u64;
+        assert!(pmf.iter().all(|p| *p <= d.pmf(at_mode)));
+        assert_almost_eq!(d.pmf(at_mode), n.cdf(2.5) - n.cdf(1.5), 1e-9);
+        let near: f64 = (at_mode - 2..=at_mode + 2).map(|i| d.pmf(i)).sum();
+        assert!(near > 0.95);
+
+        assert_eq!(n.discretize(1, 0), Err(CategoricalError::ProbMassEmpty));
+        assert_eq!(n.discretize(100, 110), Err(CategoricalError::ProbMassSumZero));
+    }
+
+    #[test]
+    fn test_sample_mean_distribution() {
+        let n = create_ok(5.0, 2.0);
+        let mut prev = f64::INFINITY;
+        for size in [1, 2, 10, 100, 10_000] {
+            let mean = n.sample_mean_distribution(size).unwrap();
+            let sd = mean.std_dev().unwrap();
+            assert_eq!(mean.mean().unwrap(), 5.0);
+            assert_almost_eq!(sd, 2.0 / (size as f64).sqrt(), 1e-15);
+            assert!(sd < prev);
+            prev = sd;
+        }
+        assert_eq!(n.sample_mean_distribution(0), Err(NormalError::SampleSizeInvalid));
+    }
+
+    #[test]
+    fn test_sample_variance_distribution() {
+        for (sd, n) in [(1.0, 2), (2.0, 9), (0.3, 100)] {
+            let s2 = create_ok(5.0, sd).sample_variance_distribution(n).unwrap();
+            let var = sd * sd;
+            assert_almost_eq!(s2.mean().unwrap(), var, 1e-14);
+            assert_almost_eq!(s2.variance().unwrap(), 2.0 * var * var / (n - 1) as f64, 1e-14);
+        }
+        assert!(create_ok(0.0, 1.0).sample_variance_distribution(1).is_err());
+        assert!(create_ok(0.0, 1.0).sample_variance_distribution(0).is_err());
+        assert!(create_ok(0.0, f64::INFINITY).sample_variance_distribution(5).is_err());
+    }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        let kurtosis = |x: Normal| x.excess_kurtosis().unwrap();
+        test_exact(0.0, 0.1, 0.0, kurtosis);
+        test_exact(4.0, 1.0, 0.0, kurtosis);
+        test_exact(0.3, 10.0, 0.0, kurtosis);
+    }
+
+    #[test]
+    fn test_moment_exists() {
+        test_exact(0.0, 1.0, true, |x| x.moment_exists(1));
+        test_exact(4.0, 10.0, true, |x| x.moment_exists(100));
+        test_exact(0.0, f64::INFINITY, true, |x| x.moment_exists(0));
+        test_exact(0.0, f64::INFINITY, false, |x| x.moment_exists(2));
+    }
+
+    #[test]
+    fn test_tail_classification() {
+        let tail = |x: Normal| x.tail_classification();
+        test_exact(0.0, 0.1, Some(TailType::Mesokurtic), tail);
+        test_exact(4.0, 1.0, Some(TailType::Mesokurtic), tail);
+        test_exact(0.3, 10.0, Some(TailType::Mesokurtic), tail);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Normal| x.mode().unwrap();
@@ -430,6 +1135,26 @@ mod tests {
         test_exact(f64::INFINITY, 1.0, f64::INFINITY, mode);
     }
 
+    #[test]
+    fn test_surprisal() {
+        let n = Normal::new(2.0, 0.5).unwrap();
+        let at_mode = n.surprisal(n.mode().unwrap());
+        assert_almost_eq!(at_mode, (0.5 * (2.0 * f64::consts::PI).sqrt()).ln(), 1e-15);
+        for &x in &[-3.0, 0.0, 1.9, 2.1, 2.5, 10.0] {
+            assert!(n.surprisal(x) > at_mode);
+            assert_eq!(n.surprisal(x), -n.ln_pdf(x));
+        }
+    }
+
+    #[test]
+    fn test_mode_numeric() {
+        let mode = |x: Normal| x.mode_numeric();
+        test_absolute(0.0, 1.0, 0.0, 1e-7, mode);
+        test_absolute(3.5, 0.2, 3.5, 1e-7, mode);
+        test_absolute(-40.0, 7.0, -40.0, 1e-6, mode);
+        test_absolute(1e6, 1.0, 1e6, 1e-2, mode);
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Normal| x.median();
@@ -533,6 +1258,33 @@ mod tests {
         test_absolute(5.0, 2.0, 0.006209665325512148, 1e-12, sf(10.0));
     }
 
+    #[test]
+    fn test_sf_far_tail() {
+        // 30 standard deviations above the mean, where 1 - cdf is exactly zero
+        let n = create_ok(5.0, 2.0);
+        assert_eq!(1.0 - n.cdf(65.0), 0.0);
+        // mpmath: ncdf(-30)
+        assert_almost_eq!(n.sf(65.0), 4.906713927148187e-198, 1e-207);
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        // mpmath: log(ncdf((x - 5) / 2))
+        let ln_cdf = |arg: f64| move |x: Normal| x.ln_cdf(arg);
+        test_exact(5.0, 2.0, f64::NEG_INFINITY, ln_cdf(f64::NEG_INFINITY));
+        test_relative(5.0, 2.0, -804.6084420137537881666, ln_cdf(-75.0));
+        test_relative(5.0, 2.0, -454.3212439563431971074, ln_cdf(-55.0));
+        test_relative(5.0, 2.0, -424.7874199097301626793, ln_cdf(-53.0));
+        test_relative(5.0, 2.0, -53.23128515051247057835, ln_cdf(-15.0));
+        test_relative(5.0, 2.0, -1.841021645009263505771, ln_cdf(3.0));
+        #[allow(clippy::approx_constant)]
+        test_relative(5.0, 2.0, -0.6931471805599453094172, ln_cdf(5.0));
+        test_relative(5.0, 2.0, -0.1727537790234498895265, ln_cdf(7.0));
+        test_relative(5.0, 2.0, -2.866516129637635933846e-7, ln_cdf(15.0));
+        test_relative(5.0, 2.0, -7.619853024160526070429e-24, ln_cdf(25.0));
+        test_exact(5.0, 2.0, 0.0, ln_cdf(f64::INFINITY));
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&create_ok(0.0, 1.0), -10.0, 10.0);
@@ -554,6 +1306,17 @@ mod tests {
         test_exact(5.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
     }
 
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        let n = create_ok(5.0, 2.0);
+        assert_eq!(n.inverse_cdf(0.0), n.min());
+        assert_eq!(n.inverse_cdf(1.0), n.max());
+        for i in -60..=60 {
+            let x = 5.0 + i as f64 / 10.0;
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-8);
+        }
+    }
+
     #[test]
     fn test_default() {
         let n = Normal::default();
@@ -566,4 +1329,366 @@ mod tests {
         // Check that the standard deviation of the distribution is close to 1
         assert_almost_eq!(n_std, 1.0, 1e-15);
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_bivariate_normal() {
+        use crate::statistics::Statistics;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        for &rho in &[-0.9, 0.0, 0.5, 0.99] {
+            let (x, y): (Vec<f64>, Vec<f64>) = (0..100_000)
+                .map(|_| sample_bivariate_normal(&mut rng, (1.0, -3.0), (2.0, 0.5), rho))
+                .unzip();
+            assert_almost_eq!(x.iter().mean(), 1.0, 0.02);
+            assert_almost_eq!(y.iter().mean(), -3.0, 0.01);
+            assert_almost_eq!(x.iter().std_dev(), 2.0, 0.02);
+            assert_almost_eq!(y.iter().std_dev(), 0.5, 0.005);
+            let corr = x.iter().covariance(y.iter()) / (x.iter().std_dev() * y.iter().std_dev());
+            assert_almost_eq!(corr, rho, 0.01);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    #[should_panic]
+    fn test_sample_bivariate_normal_bad_rho() {
+        let mut rng = rand::thread_rng();
+        sample_bivariate_normal(&mut rng, (0.0, 0.0), (1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_antithetic() {
+        use crate::statistics::Statistics;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let n = create_ok(3.0, 2.0);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let (a, b): (Vec<f64>, Vec<f64>) = (0..50_000).map(|_| n.sample_antithetic(&mut rng)).unzip();
+        for x in [&a, &b] {
+            assert_almost_eq!(x.mean(), 3.0, 0.05);
+            assert_almost_eq!(x.variance(), 4.0, 0.1);
+        }
+        // a symmetric distribution mirrors each draw about the mean
+        for (x, y) in a.iter().zip(&b) {
+            assert_almost_eq!(x + y, 6.0, 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_stratified() {
+        use crate::statistics::Statistics;
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let n = create_ok(3.0, 2.0);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let strata = 50;
+        let x = n.sample_stratified(&mut rng, strata).unwrap();
+        assert_eq!(x.len(), strata);
+        for (i, &x) in x.iter().enumerate() {
+            let p = n.cdf(x);
+            assert!(i as f64 / strata as f64 <= p && p <= (i + 1) as f64 / strata as f64);
+        }
+
+        // spread of the sample mean over repeated estimates
+        let estimates = 500;
+        let stratified = (0..estimates).map(|_| n.sample_stratified(&mut rng, strata).unwrap().mean()).variance();
+        let iid = (0..estimates).map(|_| (0..strata).map(|_| n.sample(&mut rng)).mean()).variance();
+        // i.i.d. sampling has variance σ² / n = 0.08
+        assert_almost_eq!(iid, 0.08, 0.02);
+        assert!(stratified < iid / 10.0);
+
+        assert_eq!(n.sample_stratified(&mut rng, 0), Err(crate::distribution::SampleStratifiedError::StrataInvalid));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_in_interval() {
+        use crate::statistics::Statistics;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let n = Normal::standard();
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 20_000;
+
+        // E[X | a <= X <= b] = (φ(a) - φ(b)) / (Φ(b) - Φ(a))
+        // the last intervals lie where the cdf rounds to one
+        for &(a, b) in &[(1.0, 2.0), (-0.5, 0.5), (4.0, 6.0), (9.0, 9.5), (10.0, f64::INFINITY)] {
+            let x: Vec<f64> = (0..n_samples).map(|_| n.sample_in_interval(&mut rng, a, b).unwrap()).collect();
+            assert!(x.iter().all(|&x| a <= x && x <= b));
+            let expected = (n.pdf(a) - n.pdf(b)) / (n.sf(a) - n.sf(b));
+            assert_almost_eq!(Statistics::mean(&x), expected, 0.01);
+        }
+
+        use crate::distribution::SampleIntervalError;
+        assert_eq!(n.sample_in_interval(&mut rng, 2.0, 1.0), Err(SampleIntervalError::IntervalInvalid));
+        assert_eq!(n.sample_in_interval(&mut rng, f64::NAN, 1.0), Err(SampleIntervalError::IntervalInvalid));
+        assert_eq!(n.sample_in_interval(&mut rng, 1.0, 1.0), Err(SampleIntervalError::IntervalEmpty));
+        assert_eq!(n.sample_in_interval(&mut rng, 50.0, 60.0), Err(SampleIntervalError::IntervalEmpty));
+    }
+
+    #[test]
+    fn test_ppf() {
+        let n = create_ok(3.0, 2.0);
+        for &p in &[0.0, 0.025, 0.5, 0.975, 1.0] {
+            assert_eq!(n.ppf(p), n.inverse_cdf(p));
+        }
+        assert_almost_eq!(Normal::standard().ppf(0.975), 1.959963984540054, 1e-12);
+    }
+
+    #[test]
+    fn test_prediction_interval() {
+        let (lo, hi) = Normal::standard().prediction_interval(0.95).unwrap();
+        assert_almost_eq!(lo, -1.959963984540054, 1e-12);
+        assert_almost_eq!(hi, 1.959963984540054, 1e-12);
+
+        let n = create_ok(3.0, 2.0);
+        let (lo, hi) = n.prediction_interval(0.5).unwrap();
+        assert_almost_eq!(n.cdf(hi) - n.cdf(lo), 0.5, 1e-12);
+        assert_almost_eq!(lo + hi, 6.0, 1e-12);
+        assert_eq!(n.prediction_interval(0.0), Some((3.0, 3.0)));
+        assert_eq!(n.prediction_interval(1.0), Some((f64::NEG_INFINITY, f64::INFINITY)));
+        assert_eq!(n.prediction_interval(1.5), None);
+        assert_eq!(n.prediction_interval(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_inverse_cdf_map() {
+        let n = create_ok(3.0, 2.0);
+        let ps = [0.05, 0.25, 0.5, 0.75, 0.95];
+        let quantiles = n.inverse_cdf_map(&ps).unwrap();
+        assert_eq!(quantiles.len(), ps.len());
+        for (q, &p) in quantiles.iter().zip(ps.iter()) {
+            assert_eq!(*q, n.inverse_cdf(p));
+        }
+        assert_eq!(n.inverse_cdf_map(&[]), Some(vec![]));
+        assert_eq!(n.inverse_cdf_map(&[0.5, -0.1]), None);
+        assert_eq!(n.inverse_cdf_map(&[f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_percentile_curve() {
+        let n = Normal::new(10.0, 2.0).unwrap();
+        let curve = n.percentile_curve(5.0).unwrap();
+        assert_eq!(curve.len(), 19);
+        assert_eq!(curve[0], (5.0, n.inverse_cdf(0.05)));
+        assert_eq!(curve[18], (95.0, n.inverse_cdf(0.95)));
+        assert_eq!(curve[9].0, 50.0);
+        assert_almost_eq!(curve[9].1, n.median(), 1e-12);
+
+        assert_eq!(n.percentile_curve(0.1).unwrap().len(), 999);
+        assert_eq!(n.percentile_curve(30.0).unwrap().len(), 2);
+        assert_eq!(n.percentile_curve(0.0), None);
+        assert_eq!(n.percentile_curve(50.0), None);
+        assert_eq!(n.percentile_curve(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_likelihood_ratio() {
+        // N(μ1, σ) / N(μ2, σ) = exp(((x - μ2)^2 - (x - μ1)^2) / (2σ^2))
+        let ratio = |mu1: f64, mu2: f64, sigma: f64, x: f64| {
+            (((x - mu2).powi(2) - (x - mu1).powi(2)) / (2.0 * sigma * sigma)).exp()
+        };
+        let (h0, h1) = (create_ok(0.0, 2.0), create_ok(1.0, 2.0));
+        for &x in &[-3.0, 0.0, 0.5, 2.0, 10.0] {
+            assert_almost_eq!(h1.likelihood_ratio(&h0, x), ratio(1.0, 0.0, 2.0, x), 1e-12 * ratio(1.0, 0.0, 2.0, x));
+            assert_almost_eq!(h0.likelihood_ratio(&h1, x), ratio(0.0, 1.0, 2.0, x), 1e-12 * ratio(0.0, 1.0, 2.0, x));
+        }
+        assert_eq!(h0.likelihood_ratio(&h0, 1.0), 1.0);
+
+        // both densities underflow, while the ratio is still representable
+        assert_eq!(h0.pdf(100.0), 0.0);
+        assert_almost_eq!(h1.likelihood_ratio(&h0, 100.0), ratio(1.0, 0.0, 2.0, 100.0), 1e-10 * ratio(1.0, 0.0, 2.0, 100.0));
+
+        // differing scales: N(0, 1) / N(0, 2) = 2 exp(-3x^2 / 8)
+        let x: f64 = 1.5;
+        assert_almost_eq!(create_ok(0.0, 1.0).likelihood_ratio(&h0, x), 2.0 * (-3.0 * x * x / 8.0).exp(), 1e-14);
+    }
+
+    #[test]
+    fn test_log_importance_weight() {
+        // ln N(x; μ1, σ1) - ln N(x; μ2, σ2)
+        let log_weight = |mu1: f64, s1: f64, mu2: f64, s2: f64, x: f64| {
+            (s2 / s1).ln() - (x - mu1).powi(2) / (2.0 * s1 * s1) + (x - mu2).powi(2) / (2.0 * s2 * s2)
+        };
+        let (target, proposal) = (create_ok(1.0, 0.5), create_ok(0.0, 2.0));
+        for &x in &[-3.0, 0.0, 0.5, 1.0, 4.0] {
+            assert_almost_eq!(target.log_importance_weight(&proposal, x), log_weight(1.0, 0.5, 0.0, 2.0, x), 1e-12);
+        }
+        assert_eq!(target.log_importance_weight(&target, 0.3), 0.0);
+
+        // both densities underflow, so the direct ratio would be 0 / 0
+        let x = 100.0;
+        assert_eq!(target.pdf(x), 0.0);
+        assert_eq!(proposal.pdf(x), 0.0);
+        assert!((target.pdf(x) / proposal.pdf(x)).is_nan());
+        assert_almost_eq!(target.log_importance_weight(&proposal, x), log_weight(1.0, 0.5, 0.0, 2.0, x), 1e-9);
+    }
+
+    #[test]
+    fn test_highest_density_interval() {
+        for &(mean, std_dev, prob) in &[(0.0, 1.0, 0.95), (3.0, 0.5, 0.5), (-2.0, 10.0, 0.99)] {
+            let n = create_ok(mean, std_dev);
+            let (a, b) = n.highest_density_interval(prob).unwrap();
+            let tail = (1.0 - prob) / 2.0;
+            assert_almost_eq!(a, n.inverse_cdf(tail), 1e-7 * std_dev);
+            assert_almost_eq!(b, n.inverse_cdf(1.0 - tail), 1e-7 * std_dev);
+        }
+        let n = create_ok(0.0, 1.0);
+        assert_eq!(n.highest_density_interval(0.0), None);
+        assert_eq!(n.highest_density_interval(1.0), None);
+        assert_eq!(n.highest_density_interval(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_entropy_reduction() {
+        let prior = create_ok(1.0, 3.0);
+        for &(mean, std_dev) in &[(1.0, 3.0), (0.0, 1.0), (-2.0, 0.01), (5.0, 10.0)] {
+            let posterior = create_ok(mean, std_dev);
+            let reduction = prior.entropy_reduction(&posterior);
+            assert_almost_eq!(reduction, (3.0 / std_dev).ln(), 1e-15);
+            assert_almost_eq!(reduction, prior.entropy().unwrap() - posterior.entropy().unwrap(), 1e-14);
+        }
+        assert_eq!(prior.entropy_reduction(&prior), 0.0);
+    }
+
+    #[test]
+    fn test_score_and_gradients() {
+        let h = 1e-6;
+        for &(mean, std_dev) in &[(0.0, 1.0), (-2.0, 0.5), (3.0, 4.0)] {
+            let n = create_ok(mean, std_dev);
+            for &x in &[-5.0, -1.0, 0.0, 0.7, 3.0, 12.0] {
+                let score = (n.ln_pdf(x + h) - n.ln_pdf(x - h)) / (2.0 * h);
+                let grad_mu = (create_ok(mean + h, std_dev).ln_pdf(x) - create_ok(mean - h, std_dev).ln_pdf(x)) / (2.0 * h);
+                let grad_sigma = (create_ok(mean, std_dev + h).ln_pdf(x) - create_ok(mean, std_dev - h).ln_pdf(x)) / (2.0 * h);
+                assert_almost_eq!(n.score(x), score, 1e-6 * score.abs().max(1.0));
+                assert_almost_eq!(n.grad_mu_ln_pdf(x), grad_mu, 1e-6 * grad_mu.abs().max(1.0));
+                assert_almost_eq!(n.grad_sigma_ln_pdf(x), grad_sigma, 1e-6 * grad_sigma.abs().max(1.0));
+            }
+            assert_eq!(n.score(mean), 0.0);
+            assert_eq!(n.grad_sigma_ln_pdf(mean + std_dev), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_fisher_information() {
+        assert_eq!(create_ok(0.0, 1.0).fisher_information(), [[1.0, 0.0], [0.0, 2.0]]);
+        assert_eq!(create_ok(-3.0, 0.5).fisher_information(), [[4.0, 0.0], [0.0, 8.0]]);
+        let info = create_ok(10.0, 3.0).fisher_information();
+        assert_almost_eq!(info[0][0], 1.0 / 9.0, 1e-16);
+        assert_almost_eq!(info[1][1], 2.0 / 9.0, 1e-16);
+        assert_eq!(info[0][1], 0.0);
+        assert_eq!(info[1][0], 0.0);
+
+        // the expected outer product of the score in (μ, σ), by quadrature
+        let n = create_ok(1.0, 2.0);
+        let expect = |f: &dyn Fn(f64) -> f64| crate::function::integration::adaptive(|x| f(x) * n.pdf(x), -40.0, 40.0, 1e-12);
+        let info = n.fisher_information();
+        assert_almost_eq!(expect(&|x| n.grad_mu_ln_pdf(x).powi(2)), info[0][0], 1e-9);
+        assert_almost_eq!(expect(&|x| n.grad_sigma_ln_pdf(x).powi(2)), info[1][1], 1e-9);
+        assert_almost_eq!(expect(&|x| n.grad_mu_ln_pdf(x) * n.grad_sigma_ln_pdf(x)), info[0][1], 1e-9);
+    }
+
+    #[test]
+    fn test_from_quantiles() {
+        for &(p1, x1, p2, x2) in &[(0.05, 10.0, 0.95, 20.0), (0.5, 3.0, 0.975, 7.0), (0.9, -1.0, 0.01, -30.0), (1e-6, 0.0, 0.2, 1e-3)] {
+            let n = Normal::from_quantiles(p1, x1, p2, x2).unwrap();
+            assert_almost_eq!(n.cdf(x1), p1, 1e-10 * p1);
+            assert_almost_eq!(n.cdf(x2), p2, 1e-10 * p2);
+        }
+        // a symmetric 95% interval of ±1.96σ
+        let n = Normal::from_quantiles(0.025, -1.959963984540054, 0.975, 1.959963984540054).unwrap();
+        assert_almost_eq!(n.mean().unwrap(), 0.0, 1e-12);
+        assert_almost_eq!(n.std_dev().unwrap(), 1.0, 1e-12);
+
+        let err = Err(NormalError::QuantilesInvalid);
+        assert_eq!(Normal::from_quantiles(0.5, 1.0, 0.5, 2.0), err);
+        assert_eq!(Normal::from_quantiles(0.1, 1.0, 0.9, 1.0), err);
+        assert_eq!(Normal::from_quantiles(0.1, 2.0, 0.9, 1.0), err);
+        assert_eq!(Normal::from_quantiles(0.0, 1.0, 0.9, 2.0), err);
+        assert_eq!(Normal::from_quantiles(0.1, 1.0, 1.0, 2.0), err);
+        assert_eq!(Normal::from_quantiles(0.1, f64::NAN, 0.9, 2.0), err);
+        assert_eq!(Normal::from_quantiles(0.1, 1.0, 0.9, f64::INFINITY), err);
+    }
+
+    #[test]
+    fn test_expected_shortfall() {
+        let std_normal = Normal::standard();
+        assert_almost_eq!(std_normal.expected_shortfall(0.05).unwrap(), -2.0627128075074260193, 1e-12);
+        assert_almost_eq!(create_ok(1.0, 2.0).expected_shortfall(0.01).unwrap(), -4.3304284406916096264, 1e-11);
+        assert_eq!(std_normal.expected_shortfall(1.0), Some(0.0));
+        assert_eq!(std_normal.expected_shortfall(0.0), None);
+        assert_eq!(std_normal.expected_shortfall(f64::NAN), None);
+        assert_eq!(std_normal.value_at_risk(0.05), std_normal.inverse_cdf(0.05));
+        assert!(std_normal.expected_shortfall(0.05).unwrap() < std_normal.value_at_risk(0.05));
+
+        // the numerical default, through the quantile function alone
+        let custom = crate::distribution::Custom::new(erf::probit).unwrap();
+        for &alpha in &[1e-4, 0.05, 0.5, 0.9, 1.0] {
+            assert_almost_eq!(custom.expected_shortfall(alpha).unwrap(), std_normal.expected_shortfall(alpha).unwrap(), 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_signal_to_noise_ratio() {
+        let snr = |x: Normal| x.signal_to_noise_ratio().unwrap();
+        test_exact(10.0, 2.0, 5.0, snr);
+        test_exact(-3.0, 4.0, -0.75, snr);
+        test_exact(0.0, 1.0, 0.0, snr);
+        for &(mean, std_dev) in &[(10.0, 2.0), (-3.0, 4.0), (0.1, 7.0), (1e6, 1e-3)] {
+            let n = create_ok(mean, std_dev);
+            assert_almost_eq!(n.signal_to_noise_ratio().unwrap(), 1.0 / n.coefficient_of_variation().unwrap(), 1e-15 * mean.abs() / std_dev);
+        }
+        assert_eq!(create_ok(0.0, 1.0).coefficient_of_variation(), Some(f64::INFINITY));
+        let dirac = crate::distribution::Dirac::new(3.0).unwrap();
+        assert_eq!(dirac.signal_to_noise_ratio(), Some(f64::INFINITY));
+        assert!(crate::distribution::Cauchy::new(0.0, 1.0).unwrap().signal_to_noise_ratio().is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::distribution::ValidationError;
+
+        assert_eq!(Normal::standard().validate(), Ok(()));
+        assert_eq!(create_ok(-1e6, 1e-6).validate(), Ok(()));
+        assert_eq!(create_ok(0.0, f64::INFINITY).validate(), Err(ValidationError::VarianceNotFinite));
+        assert_eq!(create_ok(f64::INFINITY, 1.0).validate(), Err(ValidationError::MeanNotFinite));
+        // a density off by a factor of two is caught by the integral
+        let wrong = crate::distribution::Custom::with_pdf(erf::probit, |x| 2.0 * Normal::standard().pdf(x)).unwrap();
+        assert_eq!(wrong.validate(), Err(ValidationError::MassNotNormalized));
+        // as is a density that is not finite
+        let nan = crate::distribution::Custom::with_pdf(erf::probit, |_| f64::NAN).unwrap();
+        assert_eq!(nan.validate(), Err(ValidationError::MassNotNormalized));
+        let spiky = crate::distribution::Custom::with_pdf(erf::probit, |x| if x > 1.0 { f64::NAN } else { Normal::standard().pdf(x) }).unwrap();
+        assert_eq!(spiky.validate(), Err(ValidationError::MassNotNormalized));
+    }
+
+    #[test]
+    fn test_positive() {
+        for &(mean, std_dev) in &[(0.0, 1.0), (-2.0, 0.5), (3.0, 2.0)] {
+            let n = create_ok(mean, std_dev);
+            let p = n.positive().unwrap();
+            assert!(p.mean().unwrap() > mean);
+            assert_eq!(p.min(), 0.0);
+            assert_eq!(p.cdf(0.0), 0.0);
+            assert_almost_eq!(p.pdf(1.0), n.pdf(1.0) / n.sf(0.0), 1e-14);
+        }
+        // half-normal mean σ sqrt(2 / π)
+        let p = create_ok(0.0, 2.0).positive().unwrap();
+        assert_almost_eq!(p.mean().unwrap(), 2.0 * (2.0 / f64::consts::PI).sqrt(), 1e-15);
+        assert!(create_ok(-100.0, 1.0).positive().is_err());
+    }
+
+    #[test]
+    fn test_builder() {
+        assert_eq!(Normal::builder().build().unwrap(), Normal::standard());
+        assert_eq!(Normal::builder().mean(5.0).build().unwrap(), create_ok(5.0, 1.0));
+        assert_eq!(Normal::builder().std_dev(3.0).build().unwrap(), create_ok(0.0, 3.0));
+        assert_eq!(NormalBuilder::new().mean(-2.0).std_dev(0.5).build().unwrap(), create_ok(-2.0, 0.5));
+        assert_eq!(Normal::builder().mean(f64::NAN).build(), Err(NormalError::MeanInvalid));
+        assert_eq!(Normal::builder().std_dev(-1.0).build(), Err(NormalError::StandardDeviationInvalid));
+    }
 }
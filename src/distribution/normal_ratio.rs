@@ -0,0 +1,220 @@
+use crate::distribution::{Continuous, Normal};
+use crate::function::erf;
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the distribution of the ratio `X / Y` of two independent
+/// normal random variables, with the density derived by
+/// [Hinkley (1969)](https://doi.org/10.1093/biomet/56.3.635).
+///
+/// The ratio is generally not normal: the denominator has mass around zero,
+/// so the tails decay like `1 / x^2` and none of its moments exist. With two
+/// standard normals it is the standard Cauchy distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ratio_distribution, Continuous, Normal};
+/// use statrs::prec;
+///
+/// let std = Normal::standard();
+/// let ratio = ratio_distribution(&std, &std);
+/// // standard Cauchy density at 1
+/// assert!(prec::almost_eq(ratio.pdf(1.0), 0.5 / std::f64::consts::PI, 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NormalRatio {
+    num: Normal,
+    den: Normal,
+}
+
+/// Returns the distribution of the ratio `X / Y` of independent
+/// `X ~ num` and `Y ~ den`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ratio_distribution, Normal};
+///
+/// let ratio = ratio_distribution(&Normal::new(1.0, 0.5).unwrap(), &Normal::new(3.0, 1.0).unwrap());
+/// assert_eq!(ratio.denominator(), Normal::new(3.0, 1.0).unwrap());
+/// ```
+pub fn ratio_distribution(num: &Normal, den: &Normal) -> NormalRatio {
+    NormalRatio {
+        num: *num,
+        den: *den,
+    }
+}
+
+impl NormalRatio {
+    /// Returns the distribution of the numerator `X`
+    pub fn numerator(&self) -> Normal {
+        self.num
+    }
+
+    /// Returns the distribution of the denominator `Y`
+    pub fn denominator(&self) -> Normal {
+        self.den
+    }
+}
+
+impl std::fmt::Display for NormalRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} / {}", self.num, self.den)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for NormalRatio {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.num.sample(rng) / self.den.sample(rng)
+    }
+}
+
+impl Min<f64> for NormalRatio {
+    /// Returns the minimum value in the domain of the normal ratio
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::NEG_INFINITY
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NormalRatio {
+    /// Returns the maximum value in the domain of the normal ratio
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// f64::INFINITY
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+/// None of the moments of a ratio of normals exist, so every method keeps
+/// its default of `None`
+impl Distribution<f64> for NormalRatio {}
+
+impl Continuous<f64, f64> for NormalRatio {
+    /// Calculates the probability density function for the ratio of two
+    /// normals at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b d erf(b / (a sqrt(2))) / (sqrt(2π) a^3 σ_x σ_y) + e^(-c / 2) / (π a^2 σ_x σ_y)
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    /// a = sqrt(x^2 / σ_x^2 + 1 / σ_y^2)
+    /// b = μ_x x / σ_x^2 + μ_y / σ_y^2
+    /// c = μ_x^2 / σ_x^2 + μ_y^2 / σ_y^2
+    /// d = e^((b^2 / a^2 - c) / 2)
+    /// ```
+    ///
+    /// and `μ_x`, `σ_x` and `μ_y`, `σ_y` are the means and standard
+    /// deviations of the numerator and denominator. By the Cauchy–Schwarz
+    /// inequality `b^2 <= a^2 c`, so `d` never overflows.
+    fn pdf(&self, x: f64) -> f64 {
+        let (mx, sx) = (self.num.mean().unwrap(), self.num.std_dev().unwrap());
+        let (my, sy) = (self.den.mean().unwrap(), self.den.std_dev().unwrap());
+        let (vx, vy) = (sx * sx, sy * sy);
+
+        let a2 = x * x / vx + 1.0 / vy;
+        let a = a2.sqrt();
+        let b = mx * x / vx + my / vy;
+        let c = mx * mx / vx + my * my / vy;
+        let d = ((b * b / a2 - c) / 2.0).min(0.0).exp();
+
+        b * d * erf::erf(b / (a * f64::consts::SQRT_2))
+            / (crate::consts::SQRT_2PI * a2 * a * sx * sy)
+            + (-c / 2.0).exp() / (f64::consts::PI * a2 * sx * sy)
+    }
+
+    /// Calculates the log probability density function for the ratio of two
+    /// normals at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(f(x))
+    /// ```
+    ///
+    /// where `f` is the [`pdf`](Self::pdf)
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Cauchy;
+    use crate::function::integration::adaptive;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_standard_cauchy() {
+        let std = Normal::standard();
+        let ratio = ratio_distribution(&std, &std);
+        let cauchy = Cauchy::new(0.0, 1.0).unwrap();
+        for i in -100..=100 {
+            let x = i as f64 / 4.0;
+            assert_almost_eq!(ratio.pdf(x), cauchy.pdf(x), 1e-15);
+            assert_almost_eq!(ratio.ln_pdf(x), cauchy.ln_pdf(x), 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_scaled_cauchy() {
+        // X / Y for centred normals is Cauchy with scale σ_x / σ_y
+        let ratio = ratio_distribution(&Normal::new(0.0, 3.0).unwrap(), &Normal::new(0.0, 0.5).unwrap());
+        let cauchy = Cauchy::new(0.0, 6.0).unwrap();
+        for x in [-40.0, -6.0, 0.0, 1.5, 12.0] {
+            assert_almost_eq!(ratio.pdf(x), cauchy.pdf(x), 1e-15);
+        }
+    }
+
+    // integrates the pdf over the real line after substituting x = tan(θ),
+    // which turns the 1 / x^2 tails into a bounded integrand, splitting at
+    // the peak so it is not missed
+    fn total_mass(ratio: &NormalRatio, peak: f64) -> f64 {
+        let f = |t: f64| ratio.pdf(t.tan()) / t.cos().powi(2);
+        let split = peak.atan();
+        adaptive(f, -FRAC_PI_2, split, 1e-10) + adaptive(f, split, FRAC_PI_2, 1e-10)
+    }
+
+    #[test]
+    fn test_integrates_to_one() {
+        let ratio = ratio_distribution(&Normal::new(1.0, 0.5).unwrap(), &Normal::new(3.0, 1.0).unwrap());
+        assert_almost_eq!(total_mass(&ratio, 1.0 / 3.0), 1.0, 1e-8);
+
+        // a denominator far from zero gives a narrow, nearly normal peak at
+        // μ_x / μ_y with the delta-method standard deviation
+        let ratio = ratio_distribution(&Normal::new(10.0, 0.1).unwrap(), &Normal::new(5.0, 0.1).unwrap());
+        assert_almost_eq!(total_mass(&ratio, 2.0), 1.0, 1e-8);
+        let approx = Normal::new(2.0, 2.0 * (1e-4f64 + 4e-4).sqrt()).unwrap();
+        assert_almost_eq!(ratio.pdf(2.0) / approx.pdf(2.0), 1.0, 0.01);
+    }
+
+    #[test]
+    fn test_no_moments() {
+        let ratio = ratio_distribution(&Normal::new(1.0, 0.5).unwrap(), &Normal::new(3.0, 1.0).unwrap());
+        assert_eq!(ratio.mean(), None);
+        assert_eq!(ratio.variance(), None);
+        assert!(!ratio.moment_exists(1));
+        assert!(ratio.moment_exists(0));
+    }
+}
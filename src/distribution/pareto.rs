@@ -186,6 +186,27 @@ impl ContinuousCDF<f64, f64> for Pareto {
             self.scale * (1.0 - p).powf(-1.0 / self.shape)
         }
     }
+
+    /// Returns the Gini coefficient of the Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `α < 1`, where the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 / (2α - 1)
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn gini_coefficient(&self) -> Option<f64> {
+        if self.shape < 1.0 {
+            None
+        } else {
+            Some(1.0 / (2.0 * self.shape - 1.0))
+        }
+    }
 }
 
 impl Min<f64> for Pareto {
@@ -275,6 +296,12 @@ impl Distribution<f64> for Pareto {
         Some(self.shape.ln() - self.scale.ln() - (1.0 / self.shape) - 1.0)
     }
 
+    /// Returns whether the moment of the given order exists, which is the
+    /// case for orders below the shape `α`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.shape
+    }
+
     /// Returns the skewness of the Pareto distribution
     ///
     /// # Panics
@@ -383,7 +410,6 @@ impl Continuous<f64, f64> for Pareto {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(scale: f64, shape: f64; Pareto; ParetoError);
 
@@ -437,6 +463,17 @@ mod tests {
         test_exact(1.0, 100.0, (707.0/485.0)*2f64.sqrt(), skewness);
     }
 
+    #[test]
+    fn test_moment_exists() {
+        test_exact(1.0, 1.0, true, |x| x.moment_exists(0));
+        test_exact(1.0, 1.0, false, |x| x.moment_exists(1));
+        test_exact(1.0, 1.5, true, |x| x.moment_exists(1));
+        test_exact(1.0, 3.0, true, |x| x.moment_exists(2));
+        test_exact(1.0, 3.0, false, |x| x.moment_exists(3));
+        test_exact(1.0, 100.0, true, |x| x.moment_exists(99));
+        test_exact(1.0, 100.0, false, |x| x.moment_exists(100));
+    }
+
     #[test]
     fn test_skewness_invalid_shape() {
         test_none(1.0, 3.0, |dist| dist.skewness());
@@ -508,6 +545,15 @@ mod tests {
         test_exact(1.0, 2.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_gini_coefficient() {
+        let gini = |x: Pareto| x.gini_coefficient();
+        test_exact(1.0, 1.0, Some(1.0), gini);
+        test_exact(2.0, 3.0, Some(0.2), gini);
+        assert_almost_eq!(create_ok(0.5, 1.16).gini_coefficient().unwrap(), 1.0 / 1.32, 1e-15);
+        test_exact(1.0, 0.9, None, gini);
+    }
+
     #[test]
     fn test_cdf() {
         let cdf = |arg: f64| move |x: Pareto| x.cdf(arg);
@@ -174,6 +174,12 @@ impl Max<u64> for Poisson {
 }
 
 impl Distribution<f64> for Poisson {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays faster than geometrically
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the poisson distribution
     ///
     /// # Formula
@@ -341,7 +347,6 @@ pub fn sample_unchecked<R: ::rand::Rng + ?Sized>(rng: &mut R, lambda: f64) -> f6
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(lambda: f64; Poisson; PoissonError);
 
@@ -0,0 +1,59 @@
+/// Simulates a random walk of `n` steps starting at `start`, where each
+/// increment is an independent draw from `step`.
+///
+/// Returns the `n + 1` positions of the path, beginning with `start`. With
+/// [`Normal`](crate::distribution::Normal) steps this is a discretized
+/// Brownian motion, whose position after `n` steps has variance `n σ²`.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use statrs::distribution::{random_walk, Normal};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let path = random_walk(&mut rng, &Normal::standard(), 10, 5.0);
+/// assert_eq!(path.len(), 11);
+/// assert_eq!(path[0], 5.0);
+/// ```
+pub fn random_walk<R, D>(r: &mut R, step: &D, n: usize, start: f64) -> Vec<f64>
+where
+    R: ::rand::Rng + ?Sized,
+    D: ::rand::distributions::Distribution<f64>,
+{
+    let mut path = Vec::with_capacity(n + 1);
+    let mut x = start;
+    path.push(x);
+    for _ in 0..n {
+        x += step.sample(r);
+        path.push(x);
+    }
+    path
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Dirac, Normal};
+    use crate::statistics::Statistics;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_endpoint_variance() {
+        let mut rng = StdRng::seed_from_u64(1004);
+        let step = Normal::new(0.0, 2.0).unwrap();
+        let (n, walks) = (100, 4000);
+        let ends: Vec<f64> = (0..walks).map(|_| *random_walk(&mut rng, &step, n, 1.0).last().unwrap()).collect();
+        assert_almost_eq!(Statistics::mean(&ends), 1.0, 1.0);
+        assert_almost_eq!(Statistics::variance(&ends), n as f64 * 4.0, 30.0);
+    }
+
+    #[test]
+    fn test_constant_steps() {
+        let mut rng = StdRng::seed_from_u64(1004);
+        let path = random_walk(&mut rng, &Dirac::new(0.5).unwrap(), 4, -1.0);
+        assert_eq!(path, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+        assert_eq!(random_walk(&mut rng, &Dirac::new(0.5).unwrap(), 0, 3.0), vec![3.0]);
+    }
+}
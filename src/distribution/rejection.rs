@@ -0,0 +1,89 @@
+use crate::distribution::Continuous;
+
+/// Draws a sample from the density `pdf` by accept-reject sampling against
+/// an `envelope` distribution scaled by the bound `m`.
+///
+/// A candidate `x` is drawn from `envelope` and accepted with probability
+/// `pdf(x) / (m * envelope.pdf(x))`. `pdf` need not be normalized; on
+/// average `m` divided by the total mass of `pdf` candidates are drawn per
+/// sample.
+///
+/// # Remarks
+///
+/// The result is only distributed according to `pdf` if
+/// `m * envelope.pdf(x) >= pdf(x)` for every `x`, so the envelope needs
+/// tails at least as heavy as the target. This is not checked. If the
+/// envelope has no mass where `pdf` is positive, this never returns.
+///
+/// # Panics
+///
+/// If `m` is not finite or `m <= 0.0`
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use statrs::distribution::{rejection_sample, Normal};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let envelope = Normal::new(0.0, 2.0).unwrap();
+/// // unnormalized half-normal target, bounded by 6 times the envelope
+/// let pdf = |x: f64| if x < 0.0 { 0.0 } else { (-x * x / 2.0).exp() };
+/// let x = rejection_sample(&mut rng, pdf, &envelope, 6.0);
+/// assert!(x >= 0.0);
+/// ```
+pub fn rejection_sample<R, F, E>(r: &mut R, pdf: F, envelope: &E, m: f64) -> f64
+where
+    R: ::rand::Rng + ?Sized,
+    F: Fn(f64) -> f64,
+    E: Continuous<f64, f64> + ::rand::distributions::Distribution<f64>,
+{
+    if !m.is_finite() || m <= 0.0 {
+        panic!("m must be finite and positive");
+    }
+    loop {
+        let x = envelope.sample(r);
+        let u: f64 = r.gen();
+        if u * m * envelope.pdf(x) <= pdf(x) {
+            return x;
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Normal;
+    use crate::statistics::Statistics;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_bimodal_target() {
+        // equal mixture of N(-2, 1) and N(2, 1), left unnormalized
+        let pdf = |x: f64| (-(x + 2.0).powi(2) / 2.0).exp() + (-(x - 2.0).powi(2) / 2.0).exp();
+        let envelope = Normal::new(0.0, 3.0).unwrap();
+        let m = 20.0;
+        for i in -200..=200 {
+            let x = i as f64 / 10.0;
+            assert!(m * envelope.pdf(x) >= pdf(x), "envelope does not bound target at {}", x);
+        }
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 50_000;
+        let x: Vec<f64> = (0..n_samples).map(|_| rejection_sample(&mut rng, pdf, &envelope, m)).collect();
+
+        let near_modes = x.iter().filter(|x| (x.abs() - 2.0).abs() < 1.0).count() as f64 / n_samples as f64;
+        assert_almost_eq!(Statistics::mean(&x), 0.0, 0.05);
+        assert_almost_eq!(Statistics::variance(&x), 5.0, 0.1);
+        // P(|X - μ_i| < 1) for the nearer component, plus a sliver of the other
+        assert_almost_eq!(near_modes, 0.6827 + 0.0013, 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bad_bound() {
+        let mut rng = StdRng::seed_from_u64(1600);
+        rejection_sample(&mut rng, |_| 1.0, &Normal::standard(), 0.0);
+    }
+}
@@ -1,8 +1,18 @@
 use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::{beta, gamma};
+use crate::function::{beta, erf, gamma};
 use crate::statistics::*;
 use std::f64;
 
+/// Degrees of freedom above which the cdf, sf and inverse cdf are evaluated
+/// with the normal limit instead of the regularized incomplete beta function.
+///
+/// The incomplete beta evaluation loses accuracy as `v` grows, returning
+/// nonsense from around `v = 1e12` and failing to converge in the inverse
+/// at `v = 1e8`, whereas the normal approximation is off by
+/// `O(1 / v)`. Around `1e8` both errors are a few parts in `1e9`, so the
+/// switch is continuous to that precision.
+const NORMAL_LIMIT_FREEDOM: f64 = 1e8;
+
 /// Implements the [Student's
 /// T](https://en.wikipedia.org/wiki/Student%27s_t-distribution) distribution
 ///
@@ -36,6 +46,10 @@ pub enum StudentsTError {
 
     /// The degrees of freedom are NaN, zero or less than zero.
     FreedomInvalid,
+
+    /// The data to fit has fewer than two values, a non-finite value, or
+    /// too little spread.
+    DataInvalid,
 }
 
 impl std::fmt::Display for StudentsTError {
@@ -47,6 +61,12 @@ impl std::fmt::Display for StudentsTError {
             StudentsTError::FreedomInvalid => {
                 write!(f, "Degrees of freedom are NaN, zero or less than zero")
             }
+            StudentsTError::DataInvalid => {
+                write!(
+                    f,
+                    "Data has fewer than two values, a non-finite value or too little spread"
+                )
+            }
         }
     }
 }
@@ -134,6 +154,131 @@ impl StudentsT {
     pub fn freedom(&self) -> f64 {
         self.freedom
     }
+
+    /// Fits the location, scale and degrees of freedom to `data` by maximum
+    /// likelihood
+    ///
+    /// Uses the ECME algorithm of Liu and Rubin: each iteration updates the
+    /// location and scale by an EM step, weighting each value by
+    /// `(ν + 1) / (ν + ((x - μ) / σ)^2)` so that outliers count for less,
+    /// then maximizes the likelihood over `ν` with the location and scale
+    /// held fixed. The degrees of freedom are kept in `[0.1, 1e6]`, so
+    /// normal data gives a fit near the upper end of that range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has fewer than two values, contains a
+    /// non-finite value, or the fitted scale collapses to zero. The latter
+    /// happens when all values are equal, and can happen when a large share
+    /// of them are, as the likelihood is then unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentsT;
+    ///
+    /// let data = [-1.2, -0.4, 0.1, 0.3, 0.8, 1.1, 25.0];
+    /// let n = StudentsT::from_data(&data).unwrap();
+    /// // the outlier barely moves the location
+    /// assert!(n.location() < 1.0);
+    /// ```
+    pub fn from_data(data: &[f64]) -> Result<StudentsT, StudentsTError> {
+        const MIN_FREEDOM: f64 = 0.1;
+        const MAX_FREEDOM: f64 = 1e6;
+        const MAX_ITER: usize = 1000;
+
+        if data.len() < 2 || data.iter().any(|x| !x.is_finite()) {
+            return Err(StudentsTError::DataInvalid);
+        }
+        let n = data.len() as f64;
+
+        // start from the median and the normal-consistent median absolute
+        // deviation, falling back to the standard deviation
+        let median = |x: &mut [f64]| {
+            x.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            let k = x.len() / 2;
+            if x.len() % 2 == 0 {
+                0.5 * (x[k - 1] + x[k])
+            } else {
+                x[k]
+            }
+        };
+        let mut location = median(&mut data.to_vec());
+        let mut deviations: Vec<f64> = data.iter().map(|x| (x - location).abs()).collect();
+        let mut scale = 1.482602218505602 * median(&mut deviations);
+        if scale == 0.0 {
+            let mean = data.iter().sum::<f64>() / n;
+            scale = (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+        }
+        if scale == 0.0 {
+            return Err(StudentsTError::DataInvalid);
+        }
+        let mut freedom = 10.0;
+
+        // derivative of the log-likelihood with respect to ν, for squared
+        // standardized residuals `d`
+        let score = |nu: f64, d: &[f64]| {
+            let sum = d.iter().fold(0.0, |acc, &d| {
+                acc - 0.5 * (d / nu).ln_1p() + 0.5 * (nu + 1.0) * d / (nu * (nu + d))
+            });
+            0.5 * n * (gamma::digamma(0.5 * (nu + 1.0)) - gamma::digamma(0.5 * nu) - 1.0 / nu) + sum
+        };
+
+        for _ in 0..MAX_ITER {
+            // E step and conditional maximization of location and scale
+            let weights: Vec<f64> = data
+                .iter()
+                .map(|x| (freedom + 1.0) / (freedom + ((x - location) / scale).powi(2)))
+                .collect();
+            let weight_sum: f64 = weights.iter().sum();
+            let new_location =
+                weights.iter().zip(data).map(|(w, x)| w * x).sum::<f64>() / weight_sum;
+            let new_scale = (weights
+                .iter()
+                .zip(data)
+                .map(|(w, x)| w * (x - new_location).powi(2))
+                .sum::<f64>()
+                / n)
+                .sqrt();
+            if new_scale == 0.0 {
+                return Err(StudentsTError::DataInvalid);
+            }
+
+            // maximization of the likelihood over ν, bisecting the score
+            // in log space
+            let d: Vec<f64> = data
+                .iter()
+                .map(|x| ((x - new_location) / new_scale).powi(2))
+                .collect();
+            let new_freedom = if score(MAX_FREEDOM, &d) >= 0.0 {
+                MAX_FREEDOM
+            } else if score(MIN_FREEDOM, &d) <= 0.0 {
+                MIN_FREEDOM
+            } else {
+                let (mut lo, mut hi) = (MIN_FREEDOM.ln(), MAX_FREEDOM.ln());
+                while hi - lo > 1e-12 {
+                    let mid = 0.5 * (lo + hi);
+                    if score(mid.exp(), &d) > 0.0 {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                (0.5 * (lo + hi)).exp()
+            };
+
+            let converged = (new_location - location).abs() <= 1e-12 * new_scale
+                && (new_scale - scale).abs() <= 1e-12 * new_scale
+                && (new_freedom - freedom).abs() <= 1e-9 * new_freedom;
+            location = new_location;
+            scale = new_scale;
+            freedom = new_freedom;
+            if converged {
+                break;
+            }
+        }
+        StudentsT::new(location, scale, freedom)
+    }
 }
 
 impl std::fmt::Display for StudentsT {
@@ -174,9 +319,11 @@ impl ContinuousCDF<f64, f64> for StudentsT {
     ///
     /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
     /// `σ` is the scale, `v` is the freedom, and `I` is the regularized
-    /// incomplete beta function
+    /// incomplete beta function. For `v > 1e8` the normal cdf with mean `μ`
+    /// and standard deviation `σ` is used instead, as the incomplete beta
+    /// function becomes unstable.
     fn cdf(&self, x: f64) -> f64 {
-        if self.freedom.is_infinite() {
+        if self.freedom > NORMAL_LIMIT_FREEDOM {
             super::normal::cdf_unchecked(x, self.location, self.scale)
         } else {
             let k = (x - self.location) / self.scale;
@@ -206,9 +353,11 @@ impl ContinuousCDF<f64, f64> for StudentsT {
     ///
     /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
     /// `σ` is the scale, `v` is the freedom, and `I` is the regularized
-    /// incomplete beta function
+    /// incomplete beta function. For `v > 1e8` the normal cdf with mean `μ`
+    /// and standard deviation `σ` is used instead, as the incomplete beta
+    /// function becomes unstable.
     fn sf(&self, x: f64) -> f64 {
-        if self.freedom.is_infinite() {
+        if self.freedom > NORMAL_LIMIT_FREEDOM {
             super::normal::sf_unchecked(x, self.location, self.scale)
         } else {
             let k = (x - self.location) / self.scale;
@@ -224,9 +373,15 @@ impl ContinuousCDF<f64, f64> for StudentsT {
 
     /// Calculates the inverse cumulative distribution function for the
     /// Student's T-distribution at `x`
+    ///
+    /// For freedom above `1e8` the inverse of the normal cdf with mean `μ`
+    /// and standard deviation `σ` is used instead.
     fn inverse_cdf(&self, x: f64) -> f64 {
         // first calculate inverse_cdf for normal Student's T
         assert!((0.0..=1.0).contains(&x));
+        if self.freedom > NORMAL_LIMIT_FREEDOM {
+            return self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x);
+        }
         let x1 = if x >= 0.5 { 1.0 - x } else { x };
         let a = 0.5 * self.freedom;
         let b = 0.5;
@@ -362,6 +517,12 @@ impl Distribution<f64> for StudentsT {
             Some(0.0)
         }
     }
+
+    /// Returns whether the moment of the given order exists, which is the
+    /// case for orders below the degrees of freedom `ν`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.freedom
+    }
 }
 
 impl Median<f64> for StudentsT {
@@ -459,7 +620,6 @@ mod tests {
     use super::*;
     use crate::consts::ACC;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(location: f64, scale: f64, freedom: f64; StudentsT; StudentsTError);
 
@@ -539,6 +699,18 @@ mod tests {
         test_none(1.0, 1.0, 1.0, |dist| dist.skewness());
     }
 
+    #[test]
+    fn test_moment_exists() {
+        test_exact(0.0, 1.0, 1.0, true, |x| x.moment_exists(0));
+        test_exact(0.0, 1.0, 1.0, false, |x| x.moment_exists(1));
+        test_exact(0.0, 1.0, 2.0, true, |x| x.moment_exists(1));
+        test_exact(0.0, 1.0, 2.0, false, |x| x.moment_exists(2));
+        test_exact(0.0, 1.0, 2.5, true, |x| x.moment_exists(2));
+        test_exact(0.0, 1.0, 4.0, true, |x| x.moment_exists(3));
+        test_exact(0.0, 1.0, 4.0, false, |x| x.moment_exists(4));
+        test_exact(0.0, 1.0, f64::INFINITY, true, |x| x.moment_exists(50));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: StudentsT| x.mode().unwrap();
@@ -659,6 +831,90 @@ mod tests {
         test_relative(0.0, 1.0, f64::INFINITY, 0.022750131947162, sf(2.0));
     }
 
+    #[test]
+    fn test_from_data_invalid() {
+        let err = Err(StudentsTError::DataInvalid);
+        assert_eq!(StudentsT::from_data(&[]), err);
+        assert_eq!(StudentsT::from_data(&[1.0]), err);
+        assert_eq!(StudentsT::from_data(&[1.0, 1.0, 1.0]), err);
+        assert_eq!(StudentsT::from_data(&[1.0, f64::NAN, 2.0]), err);
+        assert_eq!(StudentsT::from_data(&[1.0, f64::INFINITY, 2.0]), err);
+        // the likelihood is unbounded as the fit collapses onto repeated values
+        assert_eq!(StudentsT::from_data(&[1.0, 1.0, 1.0, 2.0]), err);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_from_data() {
+        use crate::distribution::Normal;
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1600);
+
+        // clean normal data has no heavy tails to explain
+        let normal: Vec<f64> = Normal::new(3.0, 2.0)
+            .unwrap()
+            .sample_iter(&mut rng)
+            .take(5000)
+            .collect();
+        let fit = StudentsT::from_data(&normal).unwrap();
+        assert!(fit.freedom() > 50.0, "{}", fit);
+        assert_almost_eq!(fit.location(), 3.0, 0.1);
+        assert_almost_eq!(fit.scale(), 2.0, 0.1);
+
+        // heavy-tailed data
+        let t: Vec<f64> = StudentsT::new(-1.0, 0.5, 2.0)
+            .unwrap()
+            .sample_iter(&mut rng)
+            .take(5000)
+            .collect();
+        let fit = StudentsT::from_data(&t).unwrap();
+        assert!(fit.freedom() > 1.5 && fit.freedom() < 2.7, "{}", fit);
+        assert_almost_eq!(fit.location(), -1.0, 0.05);
+        assert_almost_eq!(fit.scale(), 0.5, 0.05);
+
+        // Cauchy data
+        let t: Vec<f64> = StudentsT::new(0.0, 1.0, 1.0)
+            .unwrap()
+            .sample_iter(&mut rng)
+            .take(5000)
+            .collect();
+        let fit = StudentsT::from_data(&t).unwrap();
+        assert!(fit.freedom() < 1.3, "{}", fit);
+    }
+
+    #[test]
+    fn test_large_freedom() {
+        // exact values of the standard t cdf at -1, 2 and -5 for v = 1e8,
+        // the normal limit is within 1.5e-9 of these
+        let exact = [
+            (-1.0, 0.15865525514131067),
+            (2.0, 0.97724986670204662),
+            (-5.0, 2.866520550633643e-7),
+        ];
+        let below = create_ok(0.0, 1.0, 1e8);
+        let above = create_ok(0.0, 1.0, 1e8 * (1.0 + 1e-12));
+        for &(x, p) in &exact {
+            assert_almost_eq!(below.cdf(x), p, 5e-9);
+            assert_almost_eq!(above.cdf(x), p, 5e-9);
+            assert_almost_eq!(above.cdf(x), below.cdf(x), 1e-8);
+            assert_almost_eq!(above.sf(x), below.sf(x), 1e-8);
+        }
+
+        // the incomplete beta evaluation returns 0.5 everywhere for such v
+        let normal = crate::distribution::Normal::new(1.0, 2.0).unwrap();
+        for &freedom in &[1e12, 1e20, 1e300] {
+            let t = create_ok(1.0, 2.0, freedom);
+            for &x in &[-9.0, -1.0, 0.5, 3.0, 20.0] {
+                assert_eq!(t.cdf(x), normal.cdf(x));
+                assert_eq!(t.sf(x), normal.sf(x));
+            }
+            for &p in &[1e-7, 0.1, 0.975] {
+                assert_eq!(t.inverse_cdf(p), normal.inverse_cdf(p));
+            }
+        }
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&create_ok(0.0, 1.0, 3.0), -30.0, 30.0);
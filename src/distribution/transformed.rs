@@ -0,0 +1,96 @@
+use crate::distribution::ContinuousCDF;
+
+/// A distribution that draws samples from `from` and maps them onto `to`
+/// through the probability integral transform, i.e. each sample `x` becomes
+/// `to.inverse_cdf(from.cdf(x))`.
+///
+/// The resulting samples are distributed as `to` whenever `from` is
+/// continuous, which makes this the building block for copulas and for
+/// recycling variates drawn from one distribution into another.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+/// use statrs::distribution::{Exp, Normal, Transformed};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let t = Transformed::new(Normal::standard(), Exp::new(2.0).unwrap());
+/// assert!(t.sample(&mut rng) >= 0.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Transformed<From, To> {
+    from: From,
+    to: To,
+}
+
+impl<From, To> Transformed<From, To> {
+    /// Constructs a new transformed distribution mapping samples drawn
+    /// from `from` onto the distribution `to`.
+    pub fn new(from: From, to: To) -> Self {
+        Transformed { from, to }
+    }
+
+    /// Returns a reference to the distribution samples are drawn from
+    pub fn source(&self) -> &From {
+        &self.from
+    }
+
+    /// Returns a reference to the distribution samples are mapped onto
+    pub fn target(&self) -> &To {
+        &self.to
+    }
+}
+
+impl<From, To> ::rand::distributions::Distribution<f64> for Transformed<From, To>
+where
+    From: ::rand::distributions::Distribution<f64> + ContinuousCDF<f64, f64>,
+    To: ContinuousCDF<f64, f64>,
+{
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = self.from.sample(rng);
+        self.to.inverse_cdf(self.from.cdf(x))
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::Transformed;
+    use crate::distribution::{Exp, Normal, Uniform};
+    use crate::statistics::{Distribution, Statistics};
+    use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_normal_to_exponential_moments() {
+        let exp = Exp::new(2.0).unwrap();
+        let t = Transformed::new(Normal::new(3.0, 0.5).unwrap(), exp);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let samples: Vec<f64> = t.sample_iter(&mut rng).take(200_000).collect();
+
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let (mean, var) = (Statistics::mean(&samples), Statistics::variance(&samples));
+        assert_almost_eq!(mean, exp.mean().unwrap(), 0.01);
+        assert_almost_eq!(var, exp.variance().unwrap(), 0.01);
+    }
+
+    #[test]
+    fn test_same_distribution_is_identity() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let t = Transformed::new(n, n);
+        let mut r1 = StdRng::seed_from_u64(7);
+        let mut r2 = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert_almost_eq!(t.sample(&mut r1), n.sample(&mut r2), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let u = Uniform::standard();
+        let e = Exp::new(1.0).unwrap();
+        let t = Transformed::new(u, e);
+        assert_eq!(*t.source(), u);
+        assert_eq!(*t.target(), e);
+    }
+}
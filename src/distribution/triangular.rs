@@ -237,6 +237,12 @@ impl Max<f64> for Triangular {
 }
 
 impl Distribution<f64> for Triangular {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its bounded support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the triangular distribution
     ///
     /// # Formula
@@ -399,7 +405,6 @@ fn sample_unchecked<R: ::rand::Rng + ?Sized>(rng: &mut R, min: f64, max: f64, mo
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(min: f64, max: f64, mode: f64; Triangular; TriangularError);
 
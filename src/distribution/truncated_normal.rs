@@ -0,0 +1,643 @@
+use crate::consts;
+use crate::distribution::normal;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::erf;
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the [truncated normal](https://en.wikipedia.org/wiki/Truncated_normal_distribution)
+/// distribution, i.e. a normal distribution conditioned on lying in
+/// `[lower, upper]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{TruncatedNormal, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = TruncatedNormal::new(0.0, 1.0, 0.0, f64::INFINITY).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), (2.0 / std::f64::consts::PI).sqrt(), 1e-15));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.48394144903828669960, 1e-15));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TruncatedNormal {
+    mu: f64,
+    sigma: f64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+    beta: f64,
+    mass: f64,
+}
+
+/// Represents the errors that can occur when creating a [`TruncatedNormal`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum TruncatedNormalError {
+    /// The location is NaN or infinite.
+    LocationInvalid,
+
+    /// The scale is NaN, infinite, zero or less than zero.
+    ScaleInvalid,
+
+    /// A bound is NaN, the lower bound is not less than the upper bound, or
+    /// the interval carries no probability representable as a double.
+    BoundsInvalid,
+}
+
+impl std::fmt::Display for TruncatedNormalError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TruncatedNormalError::LocationInvalid => write!(f, "Location is NaN or infinite"),
+            TruncatedNormalError::ScaleInvalid => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+            TruncatedNormalError::BoundsInvalid => write!(
+                f,
+                "Bounds are NaN, not increasing, or enclose no probability"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TruncatedNormalError {}
+
+impl TruncatedNormal {
+    /// Constructs a new normal distribution with location `mu` and scale
+    /// `sigma`, truncated to the interval `[lower, upper]`. Either bound may
+    /// be infinite.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mu` is `NaN` or infinite, if `sigma` is `NaN`,
+    /// infinite or `sigma <= 0.0`, if either bound is `NaN` or
+    /// `lower >= upper`, or if the untruncated normal assigns the interval
+    /// a probability that underflows to zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let mut result = TruncatedNormal::new(0.0, 1.0, -1.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = TruncatedNormal::new(0.0, 1.0, 1.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        mu: f64,
+        sigma: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<TruncatedNormal, TruncatedNormalError> {
+        if !mu.is_finite() {
+            return Err(TruncatedNormalError::LocationInvalid);
+        }
+
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(TruncatedNormalError::ScaleInvalid);
+        }
+
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(TruncatedNormalError::BoundsInvalid);
+        }
+
+        let alpha = (lower - mu) / sigma;
+        let beta = (upper - mu) / sigma;
+        // work in whichever tail keeps the difference accurate
+        let mass = if alpha > 0.0 {
+            std_sf(alpha) - std_sf(beta)
+        } else {
+            std_cdf(beta) - std_cdf(alpha)
+        };
+        if mass <= 0.0 {
+            return Err(TruncatedNormalError::BoundsInvalid);
+        }
+
+        Ok(TruncatedNormal {
+            mu,
+            sigma,
+            lower,
+            upper,
+            alpha,
+            beta,
+            mass,
+        })
+    }
+
+    /// Returns the location of the untruncated normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, -1.0, 3.0).unwrap();
+    /// assert_eq!(n.mu(), 1.0);
+    /// ```
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Returns the scale of the untruncated normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, -1.0, 3.0).unwrap();
+    /// assert_eq!(n.sigma(), 2.0);
+    /// ```
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Returns the lower bound of the truncation interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, -1.0, 3.0).unwrap();
+    /// assert_eq!(n.lower(), -1.0);
+    /// ```
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Returns the upper bound of the truncation interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, -1.0, 3.0).unwrap();
+    /// assert_eq!(n.upper(), 3.0);
+    /// ```
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Returns `(φ(α) - φ(β)) / Z` and `(αφ(α) - βφ(β)) / Z`, the terms
+    /// shared by the moments and the entropy
+    fn pdf_terms(&self) -> (f64, f64) {
+        let (pa, pb) = (std_pdf(self.alpha), std_pdf(self.beta));
+        // z * φ(z) vanishes at infinite bounds, where the product is NaN
+        let z_pdf = |z: f64, p: f64| if z.is_infinite() { 0.0 } else { z * p };
+        (
+            (pa - pb) / self.mass,
+            (z_pdf(self.alpha, pa) - z_pdf(self.beta, pb)) / self.mass,
+        )
+    }
+}
+
+fn std_cdf(z: f64) -> f64 {
+    normal::cdf_unchecked(z, 0.0, 1.0)
+}
+
+fn std_sf(z: f64) -> f64 {
+    normal::sf_unchecked(z, 0.0, 1.0)
+}
+
+fn std_pdf(z: f64) -> f64 {
+    normal::pdf_unchecked(z, 0.0, 1.0)
+}
+
+impl std::fmt::Display for TruncatedNormal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TruncatedNormal({},{},[{},{}])",
+            self.mu, self.sigma, self.lower, self.upper
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for TruncatedNormal {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.gen();
+        self.inverse_cdf(u)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for TruncatedNormal {
+    /// Calculates the cumulative distribution function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (Φ((x - μ) / σ) - Φ(α)) / Z
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α = (a - μ) / σ` and
+    /// `β = (b - μ) / σ` are the standardized bounds, `Φ` is the standard
+    /// normal cdf and `Z = Φ(β) - Φ(α)`
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            0.0
+        } else if x >= self.upper {
+            1.0
+        } else {
+            let xi = (x - self.mu) / self.sigma;
+            if self.alpha > 0.0 {
+                (std_sf(self.alpha) - std_sf(xi)) / self.mass
+            } else {
+                (std_cdf(xi) - std_cdf(self.alpha)) / self.mass
+            }
+        }
+    }
+
+    /// Calculates the survival function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (Φ(β) - Φ((x - μ) / σ)) / Z
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α = (a - μ) / σ` and
+    /// `β = (b - μ) / σ` are the standardized bounds, `Φ` is the standard
+    /// normal cdf and `Z = Φ(β) - Φ(α)`
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            1.0
+        } else if x >= self.upper {
+            0.0
+        } else {
+            let xi = (x - self.mu) / self.sigma;
+            if self.beta < 0.0 {
+                (std_cdf(self.beta) - std_cdf(xi)) / self.mass
+            } else {
+                (std_sf(xi) - std_sf(self.beta)) / self.mass
+            }
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated normal distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ Φ^-1(Φ(α) + p Z)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α = (a - μ) / σ` and
+    /// `β = (b - μ) / σ` are the standardized bounds, `Φ` is the standard
+    /// normal cdf and `Z = Φ(β) - Φ(α)`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("x must be in [0, 1]");
+        }
+        if p == 0.0 {
+            return self.lower;
+        }
+        if p == 1.0 {
+            return self.upper;
+        }
+        let xi = if self.alpha > 0.0 {
+            // clamped as rounding may step just outside the domain of erfc_inv
+            let q = (std_sf(self.alpha) - p * self.mass).max(0.0);
+            f64::consts::SQRT_2 * erf::erfc_inv(2.0 * q)
+        } else {
+            let c = (std_cdf(self.alpha) + p * self.mass).min(1.0);
+            -f64::consts::SQRT_2 * erf::erfc_inv(2.0 * c)
+        };
+        (self.mu + self.sigma * xi).max(self.lower).min(self.upper)
+    }
+}
+
+impl Min<f64> for TruncatedNormal {
+    /// Returns the minimum value in the domain of the truncated normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// a
+    /// ```
+    ///
+    /// where `a` is the lower bound
+    fn min(&self) -> f64 {
+        self.lower
+    }
+}
+
+impl Max<f64> for TruncatedNormal {
+    /// Returns the maximum value in the domain of the truncated normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// b
+    /// ```
+    ///
+    /// where `b` is the upper bound
+    fn max(&self) -> f64 {
+        self.upper
+    }
+}
+
+impl Distribution<f64> for TruncatedNormal {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tails are no heavier than the normal's
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
+    /// Returns the mean of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ (φ(α) - φ(β)) / Z
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds, `φ` is the standard normal pdf and `Z` is the
+    /// probability of `[a, b]` under the untruncated normal
+    fn mean(&self) -> Option<f64> {
+        let (d, _) = self.pdf_terms();
+        Some(self.mu + self.sigma * d)
+    }
+
+    /// Returns the variance of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// σ^2 (1 + (αφ(α) - βφ(β)) / Z - ((φ(α) - φ(β)) / Z)^2)
+    /// ```
+    ///
+    /// where `σ` is the scale, `α` and `β` are the standardized bounds, `φ`
+    /// is the standard normal pdf and `Z` is the probability of `[a, b]`
+    /// under the untruncated normal
+    fn variance(&self) -> Option<f64> {
+        let (d, zd) = self.pdf_terms();
+        Some(self.sigma * self.sigma * (1.0 + zd - d * d))
+    }
+
+    /// Returns the entropy of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(sqrt(2πe) σ Z) + (αφ(α) - βφ(β)) / (2Z)
+    /// ```
+    ///
+    /// where `σ` is the scale, `α` and `β` are the standardized bounds, `φ`
+    /// is the standard normal pdf and `Z` is the probability of `[a, b]`
+    /// under the untruncated normal
+    fn entropy(&self) -> Option<f64> {
+        let (_, zd) = self.pdf_terms();
+        Some(consts::LN_SQRT_2PIE + (self.sigma * self.mass).ln() + zd / 2.0)
+    }
+}
+
+impl Median<f64> for TruncatedNormal {
+    /// Returns the median of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ + σ Φ^-1((Φ(α) + Φ(β)) / 2)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds and `Φ` is the standard normal cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for TruncatedNormal {
+    /// Returns the mode of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// min(max(μ, a), b)
+    /// ```
+    ///
+    /// where `μ` is the location, `a` is the lower bound and `b` is the
+    /// upper bound
+    fn mode(&self) -> Option<f64> {
+        Some(self.mu.max(self.lower).min(self.upper))
+    }
+}
+
+impl Continuous<f64, f64> for TruncatedNormal {
+    /// Calculates the probability density function for the truncated normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// φ((x - μ) / σ) / (σ Z)
+    /// ```
+    ///
+    /// for `a <= x <= b` and `0` otherwise, where `μ` is the location, `σ`
+    /// is the scale, `φ` is the standard normal pdf and `Z` is the
+    /// probability of `[a, b]` under the untruncated normal
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            0.0
+        } else {
+            normal::pdf_unchecked(x, self.mu, self.sigma) / self.mass
+        }
+    }
+
+    /// Calculates the log probability density function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(φ((x - μ) / σ) / (σ Z))
+    /// ```
+    ///
+    /// for `a <= x <= b` and `-∞` otherwise, where `μ` is the location, `σ`
+    /// is the scale, `φ` is the standard normal pdf and `Z` is the
+    /// probability of `[a, b]` under the untruncated normal
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            f64::NEG_INFINITY
+        } else {
+            normal::ln_pdf_unchecked(x, self.mu, self.sigma) - self.mass.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+
+    testing_boiler!(mu: f64, sigma: f64, lower: f64, upper: f64; TruncatedNormal; TruncatedNormalError);
+
+    const INF: f64 = f64::INFINITY;
+
+    #[test]
+    fn test_create() {
+        create_ok(0.0, 1.0, 0.0, INF);
+        create_ok(1.0, 2.0, -1.0, 3.0);
+        create_ok(0.0, 1.0, 3.0, 5.0);
+        create_ok(2.0, 0.5, -INF, 1.5);
+        create_ok(0.0, 1.0, -INF, INF);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(f64::NAN, 1.0, 0.0, 1.0, TruncatedNormalError::LocationInvalid);
+        test_create_err(INF, 1.0, 0.0, 1.0, TruncatedNormalError::LocationInvalid);
+        test_create_err(0.0, 0.0, 0.0, 1.0, TruncatedNormalError::ScaleInvalid);
+        test_create_err(0.0, INF, 0.0, 1.0, TruncatedNormalError::ScaleInvalid);
+        test_create_err(0.0, 1.0, 1.0, 1.0, TruncatedNormalError::BoundsInvalid);
+        test_create_err(0.0, 1.0, 1.0, 0.0, TruncatedNormalError::BoundsInvalid);
+        test_create_err(0.0, 1.0, f64::NAN, 0.0, TruncatedNormalError::BoundsInvalid);
+        test_create_err(0.0, 1.0, 50.0, 60.0, TruncatedNormalError::BoundsInvalid);
+    }
+
+    // the error function is accurate to about 1e-10, which bounds the
+    // precision of the tail probabilities below
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: TruncatedNormal| x.mean().unwrap();
+        test_relative(0.0, 1.0, 0.0, INF, 0.797884560802865355879892119869, mean);
+        test_absolute(1.0, 2.0, -1.0, 3.0, 1.0, 1e-15, mean);
+        test_relative(0.0, 1.0, 3.0, 5.0, 3.28269437994229845059687675432, mean);
+        test_relative(2.0, 0.5, -INF, 1.5, 1.2374323619195093954554547318, mean);
+        test_exact(1.0, 2.0, -INF, INF, 1.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: TruncatedNormal| x.variance().unwrap();
+        test_relative(0.0, 1.0, 0.0, INF, 0.36338022763241865692446494651, variance);
+        test_relative(1.0, 2.0, -1.0, 3.0, 1.16450037909117284476404198699, variance);
+        test_absolute(0.0, 1.0, 3.0, 5.0, 0.0697975660704449299123145510664, 1e-9, variance);
+        test_absolute(2.0, 0.5, -INF, 1.5, 0.0497744163925871978883419947742, 1e-9, variance);
+        test_exact(1.0, 2.0, -INF, INF, 4.0, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: TruncatedNormal| x.entropy().unwrap();
+        test_relative(0.0, 1.0, 0.0, INF, 0.725791352644727432363097614947, entropy);
+        test_relative(1.0, 2.0, -1.0, 3.0, 1.37593311484888858451888409873, entropy);
+        test_absolute(0.0, 1.0, 3.0, 5.0, -0.266060082304622073331135567752, 1e-9, entropy);
+        test_relative(2.0, 0.5, -INF, 1.5, -0.35266265428404546886314019009, entropy);
+        test_relative(0.0, 1.0, -INF, INF, consts::LN_SQRT_2PIE, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: TruncatedNormal| x.median();
+        test_relative(0.0, 1.0, 0.0, INF, 0.674489750196081743202227014541, median);
+        test_absolute(1.0, 2.0, -1.0, 3.0, 1.0, 1e-15, median);
+        test_relative(0.0, 1.0, 3.0, 5.0, 3.20509380820997925254026246594, median);
+        test_relative(2.0, 0.5, -INF, 1.5, 1.29519564535327272335401189525, median);
+        let mode = |x: TruncatedNormal| x.mode().unwrap();
+        test_exact(0.0, 1.0, 3.0, 5.0, 3.0, mode);
+        test_exact(2.0, 0.5, -INF, 1.5, 1.5, mode);
+        test_exact(1.0, 2.0, -1.0, 3.0, 1.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: TruncatedNormal| x.min();
+        let max = |x: TruncatedNormal| x.max();
+        test_exact(0.0, 1.0, 3.0, 5.0, 3.0, min);
+        test_exact(0.0, 1.0, 3.0, 5.0, 5.0, max);
+        test_exact(0.0, 1.0, 0.0, INF, INF, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: TruncatedNormal| x.pdf(arg);
+        test_relative(0.0, 1.0, 0.0, INF, 0.704130653528598955549360883193, pdf(0.5));
+        test_relative(0.0, 1.0, 0.0, INF, 0.000267660451529770703548148976881, pdf(4.0));
+        test_relative(1.0, 2.0, -1.0, 3.0, 0.292184283628408322285504051452, pdf(1.0));
+        test_relative(0.0, 1.0, 3.0, 5.0, 0.64661776563273866082782775627, pdf(3.5));
+        test_relative(2.0, 0.5, -INF, 1.5, 1.39826235729169177265054811014, pdf(1.2));
+        test_exact(0.0, 1.0, 0.0, INF, 0.0, pdf(-0.5));
+        test_exact(0.0, 1.0, 3.0, 5.0, 0.0, pdf(5.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: TruncatedNormal| x.ln_pdf(arg);
+        test_relative(0.0, 1.0, 0.0, INF, 0.704130653528598955549360883193f64.ln(), ln_pdf(0.5));
+        test_relative(0.0, 1.0, 3.0, 5.0, 0.0991620460099503608738183990911f64.ln(), ln_pdf(4.0));
+        test_exact(0.0, 1.0, 0.0, INF, f64::NEG_INFINITY, ln_pdf(-0.5));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: TruncatedNormal| x.cdf(arg);
+        test_relative(0.0, 1.0, 0.0, INF, 0.382924922548026207275409221217, cdf(0.5));
+        test_relative(0.0, 1.0, 0.0, INF, 0.999936657516333760157492458486, cdf(4.0));
+        test_relative(1.0, 2.0, -1.0, 3.0, 0.780453212594001554333161946827, cdf(2.0));
+        test_relative(0.0, 1.0, 3.0, 5.0, 0.82784494047936689866577762776, cdf(3.5));
+        test_absolute(2.0, 0.5, -INF, 1.5, 0.0085083727023202362988303357375, 1e-11, cdf(0.5));
+        test_exact(0.0, 1.0, 3.0, 5.0, 0.0, cdf(2.0));
+        test_exact(0.0, 1.0, 3.0, 5.0, 1.0, cdf(6.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: TruncatedNormal| x.sf(arg);
+        test_relative(0.0, 1.0, 0.0, INF, 1.0 - 0.382924922548026207275409221217, sf(0.5));
+        test_relative(0.0, 1.0, 0.0, INF, 0.000063342483666239842507541514, sf(4.0));
+        test_absolute(0.0, 1.0, 3.0, 5.0, 1.0 - 0.82784494047936689866577762776, 1e-10, sf(3.5));
+        test_relative(2.0, 0.5, -INF, 1.5, 1.0 - 0.143393498698806535641432608791, sf(1.0));
+        test_exact(0.0, 1.0, 3.0, 5.0, 1.0, sf(2.0));
+        test_exact(0.0, 1.0, 3.0, 5.0, 0.0, sf(6.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: TruncatedNormal| x.inverse_cdf(arg);
+        test_exact(0.0, 1.0, 3.0, 5.0, 3.0, inverse_cdf(0.0));
+        test_exact(0.0, 1.0, 3.0, 5.0, 5.0, inverse_cdf(1.0));
+        for &(mu, sigma, lower, upper) in &[(0.0, 1.0, 0.0, INF), (1.0, 2.0, -1.0, 3.0), (0.0, 1.0, 3.0, 5.0), (2.0, 0.5, -INF, 1.5)] {
+            let n = create_ok(mu, sigma, lower, upper);
+            for &p in &[0.01, 0.2, 0.5, 0.8, 0.99] {
+                assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_far_tail() {
+        // the interval carries ~1e-89 of the untruncated mass
+        let n = create_ok(0.0, 1.0, 20.0, 21.0);
+        let mean = n.mean().unwrap();
+        assert!(mean > 20.0 && mean < 20.1);
+        assert_almost_eq!(n.cdf(n.inverse_cdf(0.5)), 0.5, 1e-9);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&create_ok(0.0, 1.0, 0.0, INF), 0.0, 10.0);
+        test::check_continuous_distribution(&create_ok(1.0, 2.0, -1.0, 3.0), -1.0, 3.0);
+        test::check_continuous_distribution(&create_ok(2.0, 0.5, -INF, 1.5), -5.0, 1.5);
+    }
+}
@@ -193,6 +193,12 @@ impl Max<f64> for Uniform {
 }
 
 impl Distribution<f64> for Uniform {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case on its bounded support
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean for the continuous uniform distribution
     ///
     /// # Formula
@@ -236,6 +242,17 @@ impl Distribution<f64> for Uniform {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+
+    /// Returns the excess kurtosis of the continuous uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// -6 / 5
+    /// ```
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(-1.2)
+    }
 }
 
 impl Median<f64> for Uniform {
@@ -317,7 +334,6 @@ impl Continuous<f64, f64> for Uniform {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(min: f64, max: f64; Uniform; UniformError);
 
@@ -357,7 +373,9 @@ mod tests {
     #[test]
     fn test_entropy() {
         let entropy = |x: Uniform| x.entropy().unwrap();
+        #[allow(clippy::approx_constant)]
         test_exact(-0.0, 2.0, 0.6931471805599453094172, entropy);
+        #[allow(clippy::approx_constant)]
         test_exact(0.0, 2.0, 0.6931471805599453094172, entropy);
         test_absolute(0.1, 4.0, 1.360976553135600743431, 1e-15, entropy);
         test_exact(1.0, 10.0, 2.19722457733621938279, entropy);
@@ -374,6 +392,23 @@ mod tests {
         test_exact(10.0, 11.0, 0.0, skewness);
     }
 
+    #[test]
+    fn test_tail_classification() {
+        let tail = |x: Uniform| x.tail_classification();
+        test_exact(0.0, 2.0, Some(TailType::Platykurtic), tail);
+        test_exact(10.0, 11.0, Some(TailType::Platykurtic), tail);
+    }
+
+    #[test]
+    fn test_gini_coefficient() {
+        let gini = |x: Uniform| x.gini_coefficient().unwrap();
+        // (b - a) / (3 (a + b))
+        test_absolute(0.0, 1.0, 1.0 / 3.0, 1e-10, gini);
+        test_absolute(1.0, 3.0, 1.0 / 6.0, 1e-10, gini);
+        test_absolute(5.0, 5.5, 0.5 / 31.5, 1e-10, gini);
+        assert_eq!(Uniform::new(-1.0, 1.0).unwrap().gini_coefficient(), None);
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Uniform| x.mode().unwrap();
@@ -417,13 +452,16 @@ mod tests {
     fn test_ln_pdf() {
         let ln_pdf = |arg: f64| move |x: Uniform| x.ln_pdf(arg);
         test_exact(0.0, 0.1, f64::NEG_INFINITY, ln_pdf(-5.0));
+        #[allow(clippy::approx_constant)]
         test_absolute(0.0, 0.1, 2.302585092994045684018, 1e-15, ln_pdf(0.05));
         test_exact(0.0, 0.1, f64::NEG_INFINITY, ln_pdf(5.0));
         test_exact(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-5.0));
         test_exact(0.0, 1.0, 0.0, ln_pdf(0.5));
         test_exact(0.0, 0.1, f64::NEG_INFINITY, ln_pdf(5.0));
         test_exact(0.0, 10.0, f64::NEG_INFINITY, ln_pdf(-5.0));
+        #[allow(clippy::approx_constant)]
         test_exact(0.0, 10.0, -2.302585092994045684018, ln_pdf(1.0));
+        #[allow(clippy::approx_constant)]
         test_exact(0.0, 10.0, -2.302585092994045684018, ln_pdf(5.0));
         test_exact(0.0, 10.0, f64::NEG_INFINITY, ln_pdf(11.0));
         test_exact(-5.0, 100.0, f64::NEG_INFINITY, ln_pdf(-10.0));
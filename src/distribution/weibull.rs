@@ -214,6 +214,12 @@ impl Max<f64> for Weibull {
 }
 
 impl Distribution<f64> for Weibull {
+    /// Returns whether the moment of the given order exists, which is always
+    /// the case as the tail decays like `e^(-(x/λ)^k)`, faster than any power
+    fn moment_exists(&self, _order: u32) -> bool {
+        true
+    }
+
     /// Returns the mean of the weibull distribution
     ///
     /// # Formula
@@ -379,7 +385,6 @@ impl Continuous<f64, f64> for Weibull {
 mod tests {
     use super::*;
     use crate::distribution::internal::*;
-    use crate::testing_boiler;
 
     testing_boiler!(shape: f64, scale: f64; Weibull; WeibullError);
 
@@ -444,6 +449,7 @@ mod tests {
     fn test_median() {
         let median = |x: Weibull| x.median();
         test_exact(1.0, 0.1, 0.069314718055994530941723212145817656807550013436026, median);
+        #[allow(clippy::approx_constant)]
         test_exact(1.0, 1.0, 0.69314718055994530941723212145817656807550013436026, median);
         test_exact(10.0, 10.0, 9.6401223546778973665856033763604752124634905617583, median);
         test_exact(10.0, 1.0, 0.96401223546778973665856033763604752124634905617583, median);
@@ -486,6 +492,7 @@ mod tests {
     #[test]
     fn test_ln_pdf() {
         let ln_pdf = |arg: f64| move |x: Weibull| x.ln_pdf(arg);
+        #[allow(clippy::approx_constant)]
         test_absolute(1.0, 0.1, 2.3025850929940456840179914546843642076011014886288, 1e-15, ln_pdf(0.0));
         test_absolute(1.0, 0.1, -7.6974149070059543159820085453156357923988985113712, 1e-15, ln_pdf(1.0));
         test_exact(1.0, 0.1, -97.697414907005954315982008545315635792398898511371, ln_pdf(10.0));
@@ -0,0 +1,381 @@
+use crate::distribution::Continuous;
+use crate::statistics::*;
+use std::f64::{self, consts::TAU};
+
+/// Implements the [Wrapped
+/// Normal](https://en.wikipedia.org/wiki/Wrapped_normal_distribution)
+/// distribution, the distribution of `X mod 2π` where `X ~ N(μ, σ)`.
+///
+/// The support is the circle, represented by angles in `[0, 2π)`. Angles
+/// given in another range, e.g. `(-π, π]`, must be reduced first.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{WrappedNormal, Continuous};
+///
+/// let n = WrappedNormal::new(1.0, 0.5).unwrap();
+/// assert_eq!(n.mean_direction(), 1.0);
+/// assert!((n.pdf(1.3) - 0.6664492057835992).abs() < 1e-15);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WrappedNormal {
+    mu: f64,
+    sigma: f64,
+}
+
+/// Represents the errors that can occur when creating a [`WrappedNormal`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum WrappedNormalError {
+    /// The mean direction is NaN or infinite.
+    MeanInvalid,
+
+    /// The standard deviation is NaN, infinite, zero or less than zero.
+    StdDevInvalid,
+}
+
+impl std::fmt::Display for WrappedNormalError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WrappedNormalError::MeanInvalid => write!(f, "Mean is NaN or infinite"),
+            WrappedNormalError::StdDevInvalid => {
+                write!(
+                    f,
+                    "Standard deviation is NaN, infinite, zero or less than zero"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for WrappedNormalError {}
+
+/// Reduces the angle `x` to `[0, 2π)`
+fn wrap(x: f64) -> f64 {
+    let r = x.rem_euclid(TAU);
+    // rem_euclid rounds tiny negative inputs up to exactly 2π
+    if r >= TAU {
+        0.0
+    } else {
+        r
+    }
+}
+
+impl WrappedNormal {
+    /// Constructs a new wrapped normal distribution from the mean `mu` and
+    /// standard deviation `sigma` of the underlying normal distribution.
+    /// `mu` is reduced to `[0, 2π)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mu` or `sigma` are `NaN` or infinite, or if
+    /// `sigma <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let mut result = WrappedNormal::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = WrappedNormal::new(0.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(mu: f64, sigma: f64) -> Result<WrappedNormal, WrappedNormalError> {
+        if !mu.is_finite() {
+            return Err(WrappedNormalError::MeanInvalid);
+        }
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(WrappedNormalError::StdDevInvalid);
+        }
+        Ok(WrappedNormal {
+            mu: wrap(mu),
+            sigma,
+        })
+    }
+
+    /// Returns the standard deviation of the underlying normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(0.0, 1.5).unwrap();
+    /// assert_eq!(n.sigma(), 1.5);
+    /// ```
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Returns the mean direction, in `[0, 2π)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(-1.0, 1.0).unwrap();
+    /// assert_eq!(n.mean_direction(), 2.0 * std::f64::consts::PI - 1.0);
+    /// ```
+    pub fn mean_direction(&self) -> f64 {
+        self.mu
+    }
+
+    /// Returns the mean resultant length, the length of the first
+    /// trigonometric moment `E[e^(iθ)]`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// e^(-σ^2 / 2)
+    /// ```
+    ///
+    /// where `σ` is the standard deviation of the underlying normal
+    pub fn mean_resultant_length(&self) -> f64 {
+        (-0.5 * self.sigma * self.sigma).exp()
+    }
+
+    /// Returns the circular variance
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - e^(-σ^2 / 2)
+    /// ```
+    ///
+    /// where `σ` is the standard deviation of the underlying normal
+    pub fn circular_variance(&self) -> f64 {
+        -(-0.5 * self.sigma * self.sigma).exp_m1()
+    }
+}
+
+impl std::fmt::Display for WrappedNormal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WrappedNormal({}, {})", self.mu, self.sigma)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl ::rand::distributions::Distribution<f64> for WrappedNormal {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        wrap(super::normal::sample_unchecked(rng, self.mu, self.sigma))
+    }
+}
+
+impl Min<f64> for WrappedNormal {
+    /// Returns the minimum value in the domain of the wrapped normal
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for WrappedNormal {
+    /// Returns the supremum of the domain of the wrapped normal
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2π
+    /// ```
+    fn max(&self) -> f64 {
+        TAU
+    }
+}
+
+impl Mode<Option<f64>> for WrappedNormal {
+    /// Returns the mode of the wrapped normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// μ
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        Some(self.mu)
+    }
+}
+
+impl Continuous<f64, f64> for WrappedNormal {
+    /// Calculates the probability density function for the wrapped normal
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is outside `[0, 2π)`. For `σ <= 2` the wrapped
+    /// sum below converges within a few terms; for larger `σ` the equivalent
+    /// Fourier series `(1 + 2 Σ_{n>=1} ρ^(n^2) cos(n(x - μ))) / 2π`, with
+    /// `ρ = e^(-σ^2 / 2)`, is used instead. Both are truncated once the
+    /// remaining terms are negligible.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// Σ_k φ((x - μ + 2πk) / σ) / σ
+    /// ```
+    ///
+    /// where `φ` is the standard normal density, `μ` is the mean direction
+    /// and `σ` is the standard deviation of the underlying normal
+    fn pdf(&self, x: f64) -> f64 {
+        if !(0.0..TAU).contains(&x) {
+            return if x.is_nan() { f64::NAN } else { 0.0 };
+        }
+        // offset from the mean direction, in [-π, π)
+        let d = wrap(x - self.mu + f64::consts::PI) - f64::consts::PI;
+        if self.sigma <= 2.0 {
+            let term = |k: f64| {
+                let z = (d + TAU * k) / self.sigma;
+                (-0.5 * z * z).exp()
+            };
+            let mut sum = term(0.0);
+            let mut k = 1.0;
+            loop {
+                let t = term(k) + term(-k);
+                sum += t;
+                if t <= sum * f64::EPSILON {
+                    break;
+                }
+                k += 1.0;
+            }
+            sum / (self.sigma * (TAU).sqrt())
+        } else {
+            let rho = self.mean_resultant_length();
+            let mut sum = 0.0;
+            let mut n = 1.0;
+            loop {
+                let t = rho.powf(n * n);
+                if t < f64::EPSILON {
+                    break;
+                }
+                sum += t * (n * d).cos();
+                n += 1.0;
+            }
+            (1.0 + 2.0 * sum) / TAU
+        }
+    }
+
+    /// Calculates the log probability density function for the wrapped
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(Σ_k φ((x - μ + 2πk) / σ) / σ)
+    /// ```
+    ///
+    /// where `φ` is the standard normal density, `μ` is the mean direction
+    /// and `σ` is the standard deviation of the underlying normal
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::function::integration;
+
+    testing_boiler!(mu: f64, sigma: f64; WrappedNormal; WrappedNormalError);
+
+    #[test]
+    fn test_create() {
+        create_ok(0.0, 1.0);
+        create_ok(10.0, 0.1);
+        create_ok(-3.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert_eq!(create_err(f64::NAN, 1.0), WrappedNormalError::MeanInvalid);
+        assert_eq!(create_err(f64::INFINITY, 1.0), WrappedNormalError::MeanInvalid);
+        assert_eq!(create_err(0.0, 0.0), WrappedNormalError::StdDevInvalid);
+        assert_eq!(create_err(0.0, -1.0), WrappedNormalError::StdDevInvalid);
+        assert_eq!(create_err(0.0, f64::NAN), WrappedNormalError::StdDevInvalid);
+        assert_eq!(create_err(0.0, f64::INFINITY), WrappedNormalError::StdDevInvalid);
+    }
+
+    #[test]
+    fn test_mean_direction() {
+        let mean_direction = |x: WrappedNormal| x.mean_direction();
+        test_exact(1.0, 1.0, 1.0, mean_direction);
+        test_exact(TAU, 1.0, 0.0, mean_direction);
+        test_absolute(7.0, 1.0, 7.0 - TAU, 1e-15, mean_direction);
+    }
+
+    #[test]
+    fn test_circular_moments() {
+        let n = create_ok(0.5, 2.0);
+        assert_almost_eq!(n.mean_resultant_length(), (-2f64).exp(), 1e-16);
+        assert_almost_eq!(n.circular_variance(), 1.0 - (-2f64).exp(), 1e-16);
+
+        // first trigonometric moment of the density
+        let c = integration::adaptive(|x| n.pdf(x) * x.cos(), 0.0, TAU, 1e-13);
+        let s = integration::adaptive(|x| n.pdf(x) * x.sin(), 0.0, TAU, 1e-13);
+        assert_almost_eq!(c.hypot(s), n.mean_resultant_length(), 1e-10);
+        assert_almost_eq!(s.atan2(c), n.mean_direction(), 1e-8);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: WrappedNormal| x.pdf(arg);
+        // reference values from summing the wrapped series with mpmath
+        test_absolute(1.0, 0.5, 0.6664492057835992, 1e-15, pdf(1.3));
+        test_absolute(1.0, 2.0, 0.13098138550603852, 1e-15, pdf(5.0));
+        test_absolute(1.0, 3.0, 0.15768340166964912, 1e-15, pdf(3.0));
+        test_absolute(1.0, 4.0, 0.15921263715072754, 1e-15, pdf(0.0));
+        test_absolute(0.1, 0.3, 1.1036328057968938, 1e-14, pdf(6.2));
+        test_exact(0.0, 1.0, 0.0, pdf(-0.1));
+        test_exact(0.0, 1.0, 0.0, pdf(TAU));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: WrappedNormal| x.ln_pdf(arg);
+        test_absolute(1.0, 0.5, 0.6664492057835992f64.ln(), 1e-15, ln_pdf(1.3));
+        test_exact(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-0.1));
+    }
+
+    #[test]
+    fn test_pdf_integrates_to_one() {
+        for &(mu, sigma) in &[(0.0, 0.05), (1.0, 0.5), (3.0, 1.9), (5.0, 2.1), (2.0, 10.0)] {
+            let n = create_ok(mu, sigma);
+            let mass = integration::adaptive(|x| n.pdf(x), 0.0, TAU, 1e-12);
+            assert_almost_eq!(mass, 1.0, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pdf_continuous_across_branches() {
+        let below = create_ok(1.0, 2.0);
+        let above = create_ok(1.0, 2.0 + 1e-12);
+        for &x in &[0.0, 1.0, 2.5, 4.0, 6.0] {
+            assert_almost_eq!(below.pdf(x), above.pdf(x), 1e-12);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_in_support() {
+        use ::rand::distributions::Distribution;
+        use ::rand::{rngs::StdRng, SeedableRng};
+
+        let n = create_ok(6.0, 1.0);
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let x = n.sample(&mut rng);
+            assert!((0.0..TAU).contains(&x));
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use crate::distribution::{Discrete, DiscreteCDF};
+use crate::function::beta;
+use crate::statistics::*;
+use std::f64;
+
+/// Implements the
+/// [Yule–Simon](https://en.wikipedia.org/wiki/Yule%E2%80%93Simon_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{YuleSimon, Discrete};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = YuleSimon::new(3.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.5);
+/// assert!(prec::almost_eq(n.pmf(1), 0.75, 1e-14));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+/// Represents the errors that can occur when creating a [`YuleSimon`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum YuleSimonError {
+    /// The shape is NaN, infinite, zero or less than zero.
+    ShapeInvalid,
+}
+
+impl std::fmt::Display for YuleSimonError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            YuleSimonError::ShapeInvalid => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for YuleSimonError {}
+
+impl YuleSimon {
+    /// Constructs a new Yule–Simon distribution with a shape of `rho`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rho` is `NaN`, infinite or not greater than `0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::YuleSimon;
+    ///
+    /// let mut result = YuleSimon::new(2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = YuleSimon::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(rho: f64) -> Result<YuleSimon, YuleSimonError> {
+        if rho.is_finite() && rho > 0.0 {
+            Ok(YuleSimon { rho })
+        } else {
+            Err(YuleSimonError::ShapeInvalid)
+        }
+    }
+
+    /// Returns the shape `rho` of the Yule–Simon distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::YuleSimon;
+    ///
+    /// let n = YuleSimon::new(2.0).unwrap();
+    /// assert_eq!(n.rho(), 2.0);
+    /// ```
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+}
+
+impl std::fmt::Display for YuleSimon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "YuleSimon({})", self.rho)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<u64> for YuleSimon {
+    /// Generates a sample as a geometric variate with success probability
+    /// `e^-W`, where `W` is exponentially distributed with rate `rho`
+    fn sample<R: ::rand::Rng + ?Sized>(&self, r: &mut R) -> u64 {
+        let w = -(1.0 - r.gen::<f64>()).ln() / self.rho;
+        // ln(1 - e^-w), keeping precision for both small and large w
+        let ln_fail = if w < f64::consts::LN_2 {
+            (-(-w).exp_m1()).ln()
+        } else {
+            (-(-w).exp()).ln_1p()
+        };
+        let u = 1.0 - r.gen::<f64>();
+        // saturates to u64::MAX for huge draws
+        ((u.ln() / ln_fail).floor() as u64).saturating_add(1)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl ::rand::distributions::Distribution<f64> for YuleSimon {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        r.sample::<u64, _>(self) as f64
+    }
+}
+
+impl DiscreteCDF<u64, f64> for YuleSimon {
+    /// Calculates the cumulative distribution function for the Yule–Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1 - x * B(x, ρ + 1)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn cdf(&self, x: u64) -> f64 {
+        1.0 - self.sf(x)
+    }
+
+    /// Calculates the survival function for the Yule–Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// x * B(x, ρ + 1)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            1.0
+        } else {
+            let x = x as f64;
+            (x.ln() + beta::ln_beta(x, self.rho + 1.0)).exp()
+        }
+    }
+}
+
+impl Min<u64> for YuleSimon {
+    /// Returns the minimum value in the domain of the
+    /// Yule–Simon distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1
+    /// ```
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for YuleSimon {
+    /// Returns the maximum value in the domain of the
+    /// Yule–Simon distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 2^64 - 1
+    /// ```
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Distribution<f64> for YuleSimon {
+    /// Returns whether the moment of the given order exists, which is the case
+    /// for orders below the shape `ρ`
+    fn moment_exists(&self, order: u32) -> bool {
+        (order as f64) < self.rho
+    }
+
+    /// Returns the mean of the Yule–Simon distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ρ <= 1`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ρ / (ρ - 1)
+    /// ```
+    fn mean(&self) -> Option<f64> {
+        if self.rho <= 1.0 {
+            None
+        } else {
+            Some(self.rho / (self.rho - 1.0))
+        }
+    }
+
+    /// Returns the variance of the Yule–Simon distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ρ <= 2`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ρ^2 / ((ρ - 1)^2 * (ρ - 2))
+    /// ```
+    fn variance(&self) -> Option<f64> {
+        if self.rho <= 2.0 {
+            None
+        } else {
+            let a = self.rho / (self.rho - 1.0);
+            Some(a * a / (self.rho - 2.0))
+        }
+    }
+
+    /// Returns the skewness of the Yule–Simon distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ρ <= 3`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// (ρ + 1)^2 * sqrt(ρ - 2) / ((ρ - 3) * ρ)
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        if self.rho <= 3.0 {
+            None
+        } else {
+            let rho = self.rho;
+            Some((rho + 1.0) * (rho + 1.0) * (rho - 2.0).sqrt() / ((rho - 3.0) * rho))
+        }
+    }
+}
+
+impl Mode<Option<u64>> for YuleSimon {
+    /// Returns the mode of the Yule–Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// 1
+    /// ```
+    fn mode(&self) -> Option<u64> {
+        Some(1)
+    }
+}
+
+impl Discrete<u64, f64> for YuleSimon {
+    /// Calculates the probability mass function for the Yule–Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ρ * B(x, ρ + 1)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.ln_pmf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability mass function for the Yule–Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// ln(ρ * B(x, ρ + 1))
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.rho.ln() + beta::ln_beta(x as f64, self.rho + 1.0)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::internal::*;
+
+    testing_boiler!(rho: f64; YuleSimon; YuleSimonError);
+
+    #[test]
+    fn test_create() {
+        create_ok(0.1);
+        create_ok(1.0);
+        create_ok(25.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        test_create_err(f64::NAN, YuleSimonError::ShapeInvalid);
+        test_create_err(0.0, YuleSimonError::ShapeInvalid);
+        test_create_err(-1.0, YuleSimonError::ShapeInvalid);
+        test_create_err(f64::INFINITY, YuleSimonError::ShapeInvalid);
+    }
+
+    #[test]
+    fn test_mean() {
+        test_exact(0.5, None, |x| x.mean());
+        test_exact(1.0, None, |x| x.mean());
+        let mean = |x: YuleSimon| x.mean().unwrap();
+        test_relative(2.5, 1.666666666666666666666667, mean);
+        test_relative(5.0, 1.25, mean);
+        test_relative(10.0, 1.111111111111111111111111, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: YuleSimon| x.variance();
+        test_exact(0.5, None, variance);
+        test_exact(1.0, None, variance);
+        test_exact(1.5, None, variance);
+        test_exact(2.0, None, variance);
+        let variance = |x: YuleSimon| x.variance().unwrap();
+        test_relative(2.5, 5.555555555555555555555556, variance);
+        test_relative(5.0, 0.5208333333333333333333333, variance);
+        test_relative(10.0, 0.154320987654320987654321, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: YuleSimon| x.skewness();
+        test_exact(2.5, None, skewness);
+        test_exact(3.0, None, skewness);
+        let skewness = |x: YuleSimon| x.skewness().unwrap();
+        test_relative(4.0, 8.838834764831844055010555, skewness);
+        test_relative(5.0, 6.235382907247958256698807, skewness);
+        test_relative(10.0, 4.889138315632700025857267, skewness);
+    }
+
+    #[test]
+    fn test_mode() {
+        test_exact(0.5, Some(1), |x| x.mode());
+        test_exact(5.0, Some(1), |x| x.mode());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_exact(2.0, 1, |x| x.min());
+        test_exact(2.0, u64::MAX, |x| x.max());
+    }
+
+    #[test]
+    fn test_pmf() {
+        let pmf = |arg: u64| move |x: YuleSimon| x.pmf(arg);
+        test_exact(2.0, 0.0, pmf(0));
+        test_relative(0.5, 0.3333333333333333333333333, pmf(1));
+        test_relative(0.5, 0.07619047619047619047619048, pmf(3));
+        test_relative(0.5, 0.01351300917864385356645419, pmf(10));
+        test_relative(1.0, 0.5, pmf(1));
+        test_relative(1.0, 0.009090909090909090909090909, pmf(10));
+        test_relative(2.5, 0.05772005772005772005772006, pmf(3));
+        test_relative(5.0, 0.0001665001665001665001665002, pmf(10));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let ln_pmf = |arg: u64| move |x: YuleSimon| x.ln_pmf(arg);
+        test_exact(2.0, f64::NEG_INFINITY, ln_pmf(0));
+        test_relative(0.5, 0.07619047619047619047619048f64.ln(), ln_pmf(3));
+        test_relative(2.5, 0.001762566414605720030407068f64.ln(), ln_pmf(10));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: u64| move |x: YuleSimon| x.cdf(arg);
+        test_exact(2.0, 0.0, cdf(0));
+        test_relative(0.5, 1.0 - 0.4571428571428571428571429, cdf(3));
+        test_relative(1.0, 0.5, cdf(1));
+        test_relative(2.5, 1.0 - 0.06926406926406926406926407, cdf(3));
+        test_relative(5.0, 1.0 - 0.0003330003330003330003330003, cdf(10));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: YuleSimon| x.sf(arg);
+        test_exact(2.0, 1.0, sf(0));
+        test_relative(0.5, 0.6666666666666666666666667, sf(1));
+        test_relative(0.5, 0.2702601835728770713290837, sf(10));
+        test_relative(1.0, 0.25, sf(3));
+        test_relative(2.5, 0.007050265658422880121628271, sf(10));
+        test_relative(5.0, 0.01785714285714285714285714, sf(3));
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        // the tail beyond `k` is `k * B(k, ρ + 1) ~ Γ(ρ + 1) k^-ρ`
+        for &(rho, tol) in &[(2.5, 1e-9), (5.0, 1e-12), (10.0, 1e-12)] {
+            let n = create_ok(rho);
+            let total: f64 = (1..100_000).map(|k| n.pmf(k)).sum();
+            assert_almost_eq!(total, 1.0, tol);
+        }
+        let n = create_ok(1.0);
+        let total: f64 = (1..100_000).map(|k| n.pmf(k)).sum();
+        assert_almost_eq!(total + n.sf(99_999), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_discrete() {
+        test::check_discrete_distribution(&create_ok(2.5), 100);
+        test::check_discrete_distribution(&create_ok(5.0), 20);
+        test::check_discrete_distribution(&create_ok(10.0), 10);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let dist = create_ok(4.0);
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n_samples = 200_000;
+
+        let samples: Vec<u64> = dist.sample_iter(&mut rng).take(n_samples).collect();
+        assert!(samples.iter().all(|&x| x >= 1));
+        let x: Vec<f64> = samples.iter().map(|&x| x as f64).collect();
+        let ones = samples.iter().filter(|&&x| x == 1).count() as f64 / n_samples as f64;
+        let twos = samples.iter().filter(|&&x| x == 2).count() as f64 / n_samples as f64;
+
+        assert_almost_eq!(Statistics::mean(&x), dist.mean().unwrap(), 0.02);
+        assert_almost_eq!(Statistics::variance(&x), dist.variance().unwrap(), 0.1);
+        assert_almost_eq!(ones, dist.pmf(1), 0.01);
+        assert_almost_eq!(twos, dist.pmf(2), 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_small_rho() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        // most of the mass sits in a tail reaching far beyond 1e12
+        let mut rng = StdRng::seed_from_u64(1600);
+        for &rho in &[0.05, 0.1] {
+            let dist = create_ok(rho);
+            let n_samples = 100_000;
+            let samples: Vec<u64> = dist.sample_iter(&mut rng).take(n_samples).collect();
+            let ones = samples.iter().filter(|&&x| x == 1).count() as f64 / n_samples as f64;
+            let huge = samples.iter().filter(|&&x| x > 1_000_000_000_000).count() as f64 / n_samples as f64;
+
+            assert_almost_eq!(ones, dist.pmf(1), 0.005);
+            assert_almost_eq!(huge, dist.sf(1_000_000_000_000), 0.01);
+        }
+    }
+}
@@ -427,6 +427,7 @@ pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
 mod tests {
     use super::*;
 
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_ln_beta() {
         assert_almost_eq!(super::ln_beta(0.5, 0.5), 1.144729885849400174144, 1e-15);
@@ -484,6 +485,7 @@ mod tests {
         assert!(super::checked_beta(0.5, 0.0).is_err());
     }
 
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_beta() {
         assert_almost_eq!(super::beta(0.5, 0.5), 3.141592653589793238463, 1e-15);
@@ -497,6 +499,7 @@ mod tests {
         assert_almost_eq!(super::beta(2.5, 2.5), 0.073631077818510779026, 1e-15);
     }
 
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_beta_inc() {
         assert_almost_eq!(super::beta_inc(0.5, 0.5, 0.5), 1.570796326794896619231, 1e-14);
@@ -563,6 +566,7 @@ mod tests {
         assert!(super::checked_beta_inc(1.0, 1.0, 2.0).is_err());
     }
 
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_beta_reg() {
         assert_almost_eq!(super::beta_reg(0.5, 0.5, 0.5), 0.5, 1e-15);
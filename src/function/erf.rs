@@ -21,12 +21,19 @@ pub fn erf(x: f64) -> f64 {
 
 /// `erf_inv` calculates the inverse error function
 /// at `x`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN` or outside `[-1, 1]`, and `±INF` at
+/// `x = ±1`.
 pub fn erf_inv(x: f64) -> f64 {
-    if x == 0.0 {
+    if x.is_nan() || !(-1.0..=1.0).contains(&x) {
+        f64::NAN
+    } else if x == 0.0 {
         0.0
-    } else if x >= 1.0 {
+    } else if x == 1.0 {
         f64::INFINITY
-    } else if x <= -1.0 {
+    } else if x == -1.0 {
         f64::NEG_INFINITY
     } else if x < 0.0 {
         erf_inv_impl(-x, 1.0 + x, -1.0)
@@ -51,10 +58,17 @@ pub fn erfc(x: f64) -> f64 {
 
 /// `erfc_inv` calculates the complementary inverse
 /// error function at `x`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN` or outside `[0, 2]`, `INF` at `x = 0`
+/// and `-INF` at `x = 2`.
 pub fn erfc_inv(x: f64) -> f64 {
-    if x <= 0.0 {
+    if x.is_nan() || !(0.0..=2.0).contains(&x) {
+        f64::NAN
+    } else if x == 0.0 {
         f64::INFINITY
-    } else if x >= 2.0 {
+    } else if x == 2.0 {
         f64::NEG_INFINITY
     } else if x > 1.0 {
         erf_inv_impl(-1.0 + x, 2.0 - x, -1.0)
@@ -63,6 +77,35 @@ pub fn erfc_inv(x: f64) -> f64 {
     }
 }
 
+/// `probit` calculates the inverse of the standard normal cumulative
+/// distribution function at `p`, also known as the percent-point function.
+///
+/// # Panics
+///
+/// If `p < 0.0` or `p > 1.0`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::erf::probit;
+///
+/// assert!((probit(0.975) - 1.959963984540054).abs() < 1e-12);
+/// ```
+pub fn probit(p: f64) -> f64 {
+    checked_probit(p).unwrap()
+}
+
+/// `checked_probit` calculates the inverse of the standard normal
+/// cumulative distribution function at `p`, returning `None` if `p < 0.0`
+/// or `p > 1.0`.
+pub fn checked_probit(p: f64) -> Option<f64> {
+    if (0.0..=1.0).contains(&p) {
+        Some(-f64::consts::SQRT_2 * erfc_inv(2.0 * p))
+    } else {
+        None
+    }
+}
+
 // **********************************************************
 // ********** Coefficients for erf_impl polynomial **********
 // **********************************************************
@@ -791,6 +834,27 @@ mod tests {
         assert_eq!(super::erfc(f64::NEG_INFINITY), 2.0);
     }
 
+    #[test]
+    fn test_erf_erfc_limits() {
+        // the normal cdf/sf tails are computed directly from these limits
+        assert!(super::erfc(f64::NAN).is_nan());
+        assert_eq!(super::erf(f64::INFINITY), 1.0);
+        assert_eq!(super::erf(f64::NEG_INFINITY), -1.0);
+        assert!(super::erf(f64::NAN).is_nan());
+
+        // large finite arguments saturate to the same limits
+        assert_eq!(super::erfc(1e3), 0.0);
+        assert_eq!(super::erfc(-1e3), 2.0);
+        assert_eq!(super::erf(1e3), 1.0);
+        assert_eq!(super::erf(-1e3), -1.0);
+        assert_eq!(super::erfc(f64::MAX), 0.0);
+        assert_eq!(super::erfc(f64::MIN), 2.0);
+
+        for &x in &[-30.0, -5.0, -1.0, -0.1, 0.0, 0.1, 1.0, 5.0, 30.0] {
+            assert_almost_eq!(super::erf(x), 1.0 - super::erfc(x), 1e-15);
+        }
+    }
+
     #[test]
     fn test_erf_inv() {
         assert!(super::erf_inv(f64::NAN).is_nan());
@@ -802,9 +866,15 @@ mod tests {
         assert_eq!(super::erf_inv(0.3), 0.272462714726754355622);
         assert_eq!(super::erf_inv(0.4), 0.3708071585935579290582);
         assert_eq!(super::erf_inv(0.5), 0.4769362762044698733814);
+        assert_almost_eq!(super::erf_inv(0.9), 1.16308715367667408672625426056, 1e-14);
+        assert_almost_eq!(super::erf_inv(-0.7), -0.732869077959216852218817461058, 1e-14);
+        assert_almost_eq!(super::erf_inv(0.999), 2.32675376551352467056022020098, 1e-13);
+        assert_almost_eq!(super::erf_inv(0.999999), 3.45891073727950002215092763106, 1e-11);
         assert_eq!(super::erf_inv(1.0), f64::INFINITY);
-        assert_eq!(super::erf_inv(f64::INFINITY), f64::INFINITY);
-        assert_eq!(super::erf_inv(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(super::erf_inv(1.0 + 1e-15).is_nan());
+        assert!(super::erf_inv(-1.5).is_nan());
+        assert!(super::erf_inv(f64::INFINITY).is_nan());
+        assert!(super::erf_inv(f64::NEG_INFINITY).is_nan());
     }
 
     #[test]
@@ -820,6 +890,38 @@ mod tests {
         assert_eq!(super::erfc_inv(0.5), 0.47693627620446987338141835364313055980896974905947083);
         assert_eq!(super::erfc_inv(1.0), 0.0);
         assert_eq!(super::erfc_inv(1.5), -0.47693627620446987338141835364313055980896974905947083);
+        assert_almost_eq!(super::erfc_inv(1.9), -1.16308715367667408672625426056, 1e-14);
         assert_eq!(super::erfc_inv(2.0), f64::NEG_INFINITY);
+        assert!(super::erfc_inv(-1e-300).is_nan());
+        assert!(super::erfc_inv(2.5).is_nan());
+        assert!(super::erfc_inv(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_probit() {
+        use crate::distribution::{ContinuousCDF, Normal};
+
+        assert_almost_eq!(super::probit(0.975), 1.959963984540054, 1e-12);
+        assert_almost_eq!(super::probit(0.025), -1.959963984540054, 1e-12);
+        assert_eq!(super::probit(0.5), 0.0);
+        assert_eq!(super::probit(0.0), f64::NEG_INFINITY);
+        assert_eq!(super::probit(1.0), f64::INFINITY);
+        let n = Normal::standard();
+        for &p in &[1e-10, 0.01, 0.3, 0.7, 0.99] {
+            assert_eq!(super::probit(p), n.inverse_cdf(p));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_probit_p_gt_1() {
+        super::probit(1.5);
+    }
+
+    #[test]
+    fn test_checked_probit() {
+        assert!(super::checked_probit(-0.1).is_none());
+        assert!(super::checked_probit(1.1).is_none());
+        assert!(super::checked_probit(f64::NAN).is_none());
     }
 }
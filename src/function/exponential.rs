@@ -32,7 +32,7 @@ pub fn integral(x: f64, n: u64) -> Option<f64> {
 
     // special cases
     if n == 0 {
-        return Some((-1.0 * x).exp() / x);
+        return Some((-x).exp() / x);
     }
     if x == 0.0 {
         return Some(1.0 / (nf64 - 1.0));
@@ -44,7 +44,7 @@ pub fn integral(x: f64, n: u64) -> Option<f64> {
         let mut d = 1.0 / b;
         let mut h = d;
         for i in 1..max_iter + 1 {
-            let a = -1.0 * i as f64 * (nf64 - 1.0 + i as f64);
+            let a = -(i as f64) * (nf64 - 1.0 + i as f64);
             b += 2.0;
             d = 1.0 / (a * d + b);
             c = b + a / c;
@@ -60,18 +60,18 @@ pub fn integral(x: f64, n: u64) -> Option<f64> {
         let mut result = if n - 1 != 0 {
             1.0 / (nf64 - 1.0)
         } else {
-            -1.0 * x.ln() - consts::EULER_MASCHERONI
+            -x.ln() - consts::EULER_MASCHERONI
         };
         for i in 1..max_iter + 1 {
-            factorial *= -1.0 * x / i as f64;
+            factorial *= -x / i as f64;
             let del = if i != n - 1 {
                 -factorial / (i as f64 - nf64 + 1.0)
             } else {
-                let mut psi = -1.0 * consts::EULER_MASCHERONI;
+                let mut psi = -consts::EULER_MASCHERONI;
                 for ii in 1..n {
                     psi += 1.0 / ii as f64;
                 }
-                factorial * (-1.0 * x.ln() + psi)
+                factorial * (-x.ln() + psi)
             };
             result += del;
             if del.abs() < result.abs() * eps {
@@ -82,6 +82,30 @@ pub fn integral(x: f64, n: u64) -> Option<f64> {
     }
 }
 
+/// Computes `ln(sum(exp(x_i)))` over `values` without overflow
+///
+/// Returns `f64::NEG_INFINITY` if `values` is empty or every element is
+/// `f64::NEG_INFINITY`, and `NaN` if any element is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::exponential::log_sum_exp;
+///
+/// let x = log_sum_exp(&[1000.0, 1000.0]);
+/// assert!((x - (1000.0 + 2f64.ln())).abs() < 1e-12);
+/// ```
+pub fn log_sum_exp(values: &[f64]) -> f64 {
+    let max = values.iter().fold(f64::NEG_INFINITY, |m, &v| m.max(v));
+    if values.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    if max.is_infinite() {
+        return max;
+    }
+    max + values.iter().map(|&v| (v - max).exp()).sum::<f64>().ln()
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -104,4 +128,15 @@ mod tests {
         assert_eq!(super::integral(2.0, 0).unwrap(), 0.06766764161830635);
         assert_eq!(super::integral(10.0, 0).unwrap(), 4.539992976248485e-06);
     }
+
+    #[test]
+    fn test_log_sum_exp() {
+        assert_eq!(super::log_sum_exp(&[]), f64::NEG_INFINITY);
+        assert_eq!(super::log_sum_exp(&[f64::NEG_INFINITY, f64::NEG_INFINITY]), f64::NEG_INFINITY);
+        assert_eq!(super::log_sum_exp(&[1.0, f64::INFINITY]), f64::INFINITY);
+        assert!(super::log_sum_exp(&[1.0, f64::NAN]).is_nan());
+        assert_almost_eq!(super::log_sum_exp(&[0.0, 0.0]), 2f64.ln(), 1e-15);
+        assert_almost_eq!(super::log_sum_exp(&[1.0, 2.0, 3.0]), (1f64.exp() + 2f64.exp() + 3f64.exp()).ln(), 1e-14);
+        assert_almost_eq!(super::log_sum_exp(&[1000.0, 1000.0]), 1000.0 + 2f64.ln(), 1e-12);
+    }
 }
@@ -480,6 +480,7 @@ mod tests {
         assert_almost_eq!(super::gamma(150.0 + 1.0e-12), 3.8089226376496421386707466577615064443807882167327097140e+260, 1e248);
     }
 
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_ln_gamma() {
         assert!(super::ln_gamma(f64::NAN).is_nan());
@@ -0,0 +1,218 @@
+//! Provides numerical integration (quadrature) routines for functions of a
+//! single variable
+
+/// Maximum recursion depth of [`adaptive`] before a subinterval estimate is
+/// accepted regardless of the tolerance
+const MAX_DEPTH: usize = 50;
+
+/// Minimum recursion depth of [`adaptive`], guarding against narrow
+/// features being missed by the initial sample points
+const MIN_DEPTH: usize = 4;
+
+/// Integrates `f` over `[a, b]` using the composite Simpson's rule with `n`
+/// subintervals.
+///
+/// # Remarks
+///
+/// `n` is rounded up to the next even number, and at least two subintervals
+/// are always used. Returns `0` if `a == b` and the negated integral if
+/// `a > b`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::integration::simpson;
+///
+/// let area = simpson(|x| x * x, 0.0, 3.0, 10);
+/// assert!((area - 9.0).abs() < 1e-12);
+/// ```
+pub fn simpson<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> f64 {
+    let n = (n.max(2) + 1) & !1;
+    let h = (b - a) / n as f64;
+    let interior = (1..n).fold(0.0, |acc, i| {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        acc + weight * f(a + i as f64 * h)
+    });
+    h / 3.0 * (f(a) + interior + f(b))
+}
+
+/// Integrates `f` over `[a, b]` using adaptive Simpson quadrature, refining
+/// subintervals until the estimated absolute error is below `tol`.
+///
+/// # Remarks
+///
+/// The interval is always subdivided a few times before the error estimate
+/// is trusted, but integrands concentrated on a region much narrower than
+/// `(b - a) / 16` may still be missed and should be split by the caller.
+///
+/// Returns a non-finite value, without further refinement, as soon as `f`
+/// evaluates to something non-finite or the bounds are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::integration::adaptive;
+///
+/// let area = adaptive(f64::sin, 0.0, std::f64::consts::PI, 1e-12);
+/// assert!((area - 2.0).abs() < 1e-10);
+/// ```
+pub fn adaptive<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, tol: f64) -> f64 {
+    let fa = f(a);
+    let fb = f(b);
+    let m = (a + b) / 2.0;
+    let fm = f(m);
+    let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+    adaptive_step(&f, a, b, fa, fm, fb, whole, tol, 0)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn adaptive_step<F: Fn(f64) -> f64>(
+    f: &F,
+    a: f64,
+    b: f64,
+    fa: f64,
+    fm: f64,
+    fb: f64,
+    whole: f64,
+    tol: f64,
+    depth: usize,
+) -> f64 {
+    let m = (a + b) / 2.0;
+    let lm = (a + m) / 2.0;
+    let rm = (m + b) / 2.0;
+    let flm = f(lm);
+    let frm = f(rm);
+    let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+    let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+    let delta = left + right - whole;
+
+    if !delta.is_finite() {
+        // refining cannot make a NaN or infinite estimate finite
+        return left + right + delta;
+    }
+    if depth >= MAX_DEPTH || (depth >= MIN_DEPTH && delta.abs() <= 15.0 * tol) {
+        // Richardson extrapolation of the two estimates
+        left + right + delta / 15.0
+    } else {
+        adaptive_step(f, a, m, fa, flm, fm, left, tol / 2.0, depth + 1)
+            + adaptive_step(f, m, b, fm, frm, fb, right, tol / 2.0, depth + 1)
+    }
+}
+
+/// Integrates `f` over `(-∞, ∞)` using [`adaptive`] after the substitution
+/// `x = t / (1 - t^2)` for `t` in `(-1, 1)`.
+///
+/// # Remarks
+///
+/// `f` must decay to zero at both infinities. The substitution maps the
+/// region around the origin onto most of `(-1, 1)`, so integrands
+/// concentrated far from the origin should be shifted first.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::integration::adaptive_infinite;
+///
+/// let area = adaptive_infinite(|x| (-x * x).exp(), 1e-12);
+/// assert!((area - std::f64::consts::PI.sqrt()).abs() < 1e-10);
+/// ```
+pub fn adaptive_infinite<F: Fn(f64) -> f64>(f: F, tol: f64) -> f64 {
+    let g = |t: f64| {
+        if t <= -1.0 || t >= 1.0 {
+            return 0.0;
+        }
+        let denom = 1.0 - t * t;
+        f(t / denom) * (1.0 + t * t) / (denom * denom)
+    };
+    adaptive(g, -1.0, 1.0, tol)
+}
+
+/// Integrates `f` over `(a, ∞)` using [`adaptive`] after the substitution
+/// `x = a + t / (1 - t)` for `t` in `(0, 1)`.
+///
+/// # Remarks
+///
+/// `f` must decay to zero at infinity.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::integration::adaptive_semi_infinite;
+///
+/// let area = adaptive_semi_infinite(|x| (-x).exp(), 0.0, 1e-12);
+/// assert!((area - 1.0).abs() < 1e-10);
+/// ```
+pub fn adaptive_semi_infinite<F: Fn(f64) -> f64>(f: F, a: f64, tol: f64) -> f64 {
+    let g = |t: f64| {
+        if t >= 1.0 {
+            return 0.0;
+        }
+        let denom = 1.0 - t;
+        f(a + t / denom) / (denom * denom)
+    };
+    adaptive(g, 0.0, 1.0, tol)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Continuous, Exp, Normal};
+
+    #[test]
+    fn test_simpson() {
+        assert_eq!(simpson(|_| 1.0, 0.0, 2.0, 2), 2.0);
+        // exact for cubics
+        assert_almost_eq!(simpson(|x| x * x * x - x, -1.0, 2.0, 2), 2.25, 1e-14);
+        assert_almost_eq!(simpson(|x| x * x, 3.0, 0.0, 7), -9.0, 1e-13);
+        assert_eq!(simpson(|x| x, 1.0, 1.0, 10), 0.0);
+        assert_almost_eq!(simpson(f64::exp, 0.0, 1.0, 100), std::f64::consts::E - 1.0, 1e-9);
+    }
+
+    #[test]
+    fn test_simpson_normal_pdf() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(simpson(|x| n.pdf(x), -19.0, 21.0, 1000), 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_adaptive() {
+        assert_almost_eq!(adaptive(f64::exp, 0.0, 1.0, 1e-12), std::f64::consts::E - 1.0, 1e-11);
+        assert_almost_eq!(adaptive(|x| x.sqrt(), 0.0, 1.0, 1e-10), 2.0 / 3.0, 1e-8);
+        assert_almost_eq!(adaptive(f64::cos, 0.0, 10.0, 1e-12), 10f64.sin(), 1e-10);
+    }
+
+    #[test]
+    fn test_adaptive_non_finite() {
+        assert!(adaptive(|_| f64::NAN, 0.0, 1.0, 1e-10).is_nan());
+        assert!(adaptive(|x| if x > 0.3 { f64::NAN } else { x }, 0.0, 1.0, 1e-10).is_nan());
+        assert!(adaptive(|_| 1.0, f64::NAN, 1.0, 1e-10).is_nan());
+        assert!(adaptive(|_| 1.0, 0.0, f64::NAN, 1e-10).is_nan());
+        assert!(!adaptive(|x| 1.0 / x, 0.0, 1.0, 1e-10).is_finite());
+        assert!(!adaptive(|_| 1.0, 0.0, f64::INFINITY, 1e-10).is_finite());
+    }
+
+    #[test]
+    fn test_adaptive_normal_pdf() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(adaptive(|x| n.pdf(x), -19.0, 21.0, 1e-12), 1.0, 1e-10);
+        let n = Normal::new(0.0, 0.1).unwrap();
+        assert_almost_eq!(adaptive(|x| n.pdf(x), -5.0, 5.0, 1e-12), 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_adaptive_infinite() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(adaptive_infinite(|x| n.pdf(x), 1e-12), 1.0, 1e-10);
+        assert_almost_eq!(adaptive_infinite(|x| x * n.pdf(x), 1e-12), 1.0, 1e-9);
+        assert_almost_eq!(adaptive_infinite(|x| 1.0 / (1.0 + x * x), 1e-12), std::f64::consts::PI, 1e-8);
+    }
+
+    #[test]
+    fn test_adaptive_semi_infinite() {
+        let n = Exp::new(0.5).unwrap();
+        assert_almost_eq!(adaptive_semi_infinite(|x| n.pdf(x), 0.0, 1e-12), 1.0, 1e-10);
+        assert_almost_eq!(adaptive_semi_infinite(|x| x * n.pdf(x), 0.0, 1e-12), 2.0, 1e-9);
+        assert_almost_eq!(adaptive_semi_infinite(|x| n.pdf(x), 2.0, 1e-12), (-1f64).exp(), 1e-10);
+    }
+}
@@ -74,5 +74,7 @@ pub mod euclid;
 pub mod function;
 pub mod generate;
 pub mod prec;
+pub mod quasi;
+pub mod rng;
 pub mod statistics;
 pub mod stats_tests;
@@ -0,0 +1,169 @@
+//! Provides helpers for deriving reproducible, independent random number
+//! generator seeds, e.g. one per worker in a parallel simulation
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Applies the SplitMix64 output function to `z`
+fn mix64(z: u64) -> u64 {
+    let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Derives a stream of child seeds from a single master seed using
+/// [SplitMix64](https://prng.di.unimi.it/splitmix64.c).
+///
+/// The `i`-th child is the `i`-th output of a SplitMix64 generator seeded
+/// with the master seed. Since SplitMix64 is counter based, any child can be
+/// computed directly with [`child`](SeedStream::child) without generating
+/// the ones before it, so workers can derive their own seed from their index
+/// alone. Distinct children are statistically independent, and the same
+/// master seed always reproduces the same children.
+///
+/// Iterating over a `SeedStream` yields the children in order.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::rng::SeedStream;
+///
+/// let seeds = SeedStream::new(42);
+/// let first: Vec<u64> = seeds.take(3).collect();
+/// assert_eq!(first, [seeds.child(0), seeds.child(1), seeds.child(2)]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SeedStream {
+    master: u64,
+    index: u64,
+}
+
+impl SeedStream {
+    /// Constructs a new seed stream from the master seed `master`
+    pub fn new(master: u64) -> SeedStream {
+        SeedStream { master, index: 0 }
+    }
+
+    /// Returns the master seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::rng::SeedStream;
+    ///
+    /// assert_eq!(SeedStream::new(7).master(), 7);
+    /// ```
+    pub fn master(&self) -> u64 {
+        self.master
+    }
+
+    /// Returns the child seed at position `index`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::rng::SeedStream;
+    ///
+    /// let a = SeedStream::new(1);
+    /// let b = SeedStream::new(1);
+    /// assert_eq!(a.child(5), b.child(5));
+    /// assert_ne!(a.child(5), a.child(6));
+    /// ```
+    pub fn child(&self, index: u64) -> u64 {
+        let state = index
+            .wrapping_add(1)
+            .wrapping_mul(GOLDEN_GAMMA)
+            .wrapping_add(self.master);
+        mix64(state)
+    }
+
+    /// Returns a random number generator seeded with the child seed at
+    /// position `index`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, Rng};
+    /// use statrs::rng::SeedStream;
+    ///
+    /// let seeds = SeedStream::new(2024);
+    /// let mut a: StdRng = seeds.child_rng(0);
+    /// let mut b: StdRng = seeds.child_rng(0);
+    /// assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn child_rng<R: ::rand::SeedableRng>(&self, index: u64) -> R {
+        R::seed_from_u64(self.child(index))
+    }
+}
+
+impl Iterator for SeedStream {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let seed = self.child(self.index);
+        self.index = self.index.wrapping_add(1);
+        Some(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistics::Statistics;
+
+    fn to_unit(x: u64) -> f64 {
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn correlation(x: &[f64], y: &[f64]) -> f64 {
+        x.covariance(y) / (x.std_dev() * y.std_dev())
+    }
+
+    #[test]
+    fn test_reference_values() {
+        // first outputs of the reference SplitMix64 implementation seeded with 0
+        let seeds: Vec<u64> = SeedStream::new(0).take(3).collect();
+        assert_eq!(
+            seeds,
+            [
+                0xe220_a839_7b1d_cdaf,
+                0x6e78_9e6a_a1b9_65f4,
+                0x06c4_5d18_8009_454f
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let a: Vec<u64> = SeedStream::new(12345).take(100).collect();
+        let b: Vec<u64> = SeedStream::new(12345).take(100).collect();
+        assert_eq!(a, b);
+        let c: Vec<u64> = SeedStream::new(12346).take(100).collect();
+        assert!(a.iter().zip(&c).all(|(x, y)| x != y));
+    }
+
+    #[test]
+    fn test_children_uncorrelated() {
+        let seeds = SeedStream::new(99);
+        let x: Vec<f64> = (0..10_000).map(|i| to_unit(seeds.child(2 * i))).collect();
+        let y: Vec<f64> = (0..10_000)
+            .map(|i| to_unit(seeds.child(2 * i + 1)))
+            .collect();
+        assert!(correlation(&x, &y).abs() < 0.05);
+        assert!((x.mean() - 0.5).abs() < 0.01);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_child_rngs_uncorrelated() {
+        use rand::{rngs::StdRng, Rng};
+
+        let seeds = SeedStream::new(7);
+        let mut a: StdRng = seeds.child_rng(0);
+        let mut b: StdRng = seeds.child_rng(1);
+        let x: Vec<f64> = (0..10_000).map(|_| a.gen()).collect();
+        let y: Vec<f64> = (0..10_000).map(|_| b.gen()).collect();
+        assert!(correlation(&x, &y).abs() < 0.05);
+    }
+}
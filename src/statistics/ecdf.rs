@@ -0,0 +1,86 @@
+/// Returns the empirical cumulative distribution function of `data`, the
+/// fraction of the values less than or equal to its argument.
+///
+/// The closure holds a sorted copy of `data` and evaluates in
+/// `O(log n)`. For a full distribution that also supports updates,
+/// sampling and quantiles, see
+/// [`Empirical`](crate::distribution::Empirical).
+///
+/// # Remarks
+///
+/// The closure returns `NaN` if `data` is empty or its argument is `NaN`.
+///
+/// # Panics
+///
+/// If `data` contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::ecdf;
+///
+/// let f = ecdf(&[3.0, 1.0, 2.0, 2.0]);
+/// assert_eq!(f(0.5), 0.0);
+/// assert_eq!(f(2.0), 0.75);
+/// assert_eq!(f(2.5), 0.75);
+/// assert_eq!(f(3.0), 1.0);
+/// ```
+pub fn ecdf(data: &[f64]) -> impl Fn(f64) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).expect("data must not contain NaN"));
+    let n = sorted.len() as f64;
+    move |x: f64| {
+        if sorted.is_empty() || x.is_nan() {
+            return f64::NAN;
+        }
+        sorted.partition_point(|&v| v <= x) as f64 / n
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_at_sample_points() {
+        let data = [0.5, -2.0, 7.0, 3.0, 3.0, 1.0, 3.0, -0.25];
+        let f = ecdf(&data);
+        let n = data.len() as f64;
+        for &x in &data {
+            let expected = data.iter().filter(|&&v| v <= x).count() as f64 / n;
+            assert_eq!(f(x), expected);
+        }
+        assert_eq!(f(-2.0), 1.0 / 8.0);
+        assert_eq!(f(3.0), 7.0 / 8.0);
+        // right-continuous, constant between the points
+        assert_eq!(f(2.999), 4.0 / 8.0);
+        assert_eq!(f(-2.0 - 1e-12), 0.0);
+        assert_eq!(f(f64::NEG_INFINITY), 0.0);
+        assert_eq!(f(f64::INFINITY), 1.0);
+        assert!(f(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_matches_empirical() {
+        use crate::distribution::{ContinuousCDF, Empirical};
+
+        let data = [4.0, 1.5, 2.25, 9.0, 1.5, 0.0];
+        let f = ecdf(&data);
+        let empirical: Empirical = data.iter().copied().collect();
+        for &x in &[-1.0, 0.0, 1.0, 1.5, 2.0, 4.0, 8.0, 9.0, 10.0] {
+            assert_eq!(f(x), empirical.cdf(x));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(ecdf(&[])(1.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan_data() {
+        let _ = ecdf(&[1.0, f64::NAN]);
+    }
+}
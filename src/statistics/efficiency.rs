@@ -0,0 +1,73 @@
+use crate::distribution::Continuous;
+use crate::statistics::{Distribution, Median};
+
+/// Returns the asymptotic relative efficiency of the sample median versus
+/// the sample mean as estimators of the location of `dist`, the ratio of
+/// their asymptotic variances.
+///
+/// Values below one favour the mean and values above one favour the
+/// median, which wins for heavy-tailed distributions.
+///
+/// # Remarks
+///
+/// Returns `f64::INFINITY` if the variance of `dist` does not exist, since
+/// the sample mean then does not converge at the usual rate, and `0` if the
+/// density vanishes at the median.
+///
+/// # Formula
+///
+/// ```text
+/// 4 f(m)^2 σ^2
+/// ```
+///
+/// where `f` is the density, `m` the median and `σ^2` the variance of
+/// `dist`, from the asymptotic variances `σ^2 / n` of the mean and
+/// `1 / (4 n f(m)^2)` of the median
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::median_relative_efficiency;
+///
+/// let are = median_relative_efficiency(&Normal::new(3.0, 2.0).unwrap());
+/// assert!((are - 2.0 / std::f64::consts::PI).abs() < 1e-15);
+/// ```
+pub fn median_relative_efficiency<D>(dist: &D) -> f64
+where
+    D: Continuous<f64, f64> + Median<f64> + Distribution<f64>,
+{
+    match dist.variance() {
+        Some(variance) if variance.is_finite() => {
+            let density = dist.pdf(dist.median());
+            4.0 * density * density * variance
+        }
+        _ => f64::INFINITY,
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Cauchy, Laplace, Normal, StudentsT, Uniform};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_normal() {
+        for (mean, sd) in [(0.0, 1.0), (-4.0, 0.1), (10.0, 25.0)] {
+            let are = median_relative_efficiency(&Normal::new(mean, sd).unwrap());
+            assert_almost_eq!(are, 2.0 / PI, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_heavy_tails_favour_median() {
+        assert_almost_eq!(median_relative_efficiency(&Laplace::new(1.0, 3.0).unwrap()), 2.0, 1e-14);
+        // f(0)^2 = 4 / (3π^2) and σ^2 = 3 for three degrees of freedom
+        assert_almost_eq!(median_relative_efficiency(&StudentsT::new(0.0, 1.0, 3.0).unwrap()), 16.0 / (PI * PI), 1e-14);
+        assert_eq!(median_relative_efficiency(&Cauchy::new(0.0, 1.0).unwrap()), f64::INFINITY);
+        // light tails favour the mean
+        assert_almost_eq!(median_relative_efficiency(&Uniform::new(0.0, 2.0).unwrap()), 1.0 / 3.0, 1e-15);
+    }
+}
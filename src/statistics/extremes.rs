@@ -0,0 +1,141 @@
+use crate::distribution::GeneralizedPareto;
+
+/// Represents the errors that can occur when fitting a
+/// [`GeneralizedPareto`] distribution to exceedances
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum GeneralizedParetoFitError {
+    /// There are fewer than two exceedances.
+    SampleTooSmall,
+
+    /// An exceedance is negative, NaN or infinite.
+    DataInvalid,
+
+    /// The exceedances are all equal, so the scale cannot be estimated.
+    NoSpread,
+}
+
+impl std::fmt::Display for GeneralizedParetoFitError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GeneralizedParetoFitError::SampleTooSmall => {
+                write!(f, "at least 2 exceedances are required")
+            }
+            GeneralizedParetoFitError::DataInvalid => {
+                write!(f, "exceedances must be finite and nonnegative")
+            }
+            GeneralizedParetoFitError::NoSpread => write!(f, "exceedances are all equal"),
+        }
+    }
+}
+
+impl std::error::Error for GeneralizedParetoFitError {}
+
+/// Fits a [`GeneralizedPareto`] distribution with location zero to the
+/// `exceedances` over a threshold, i.e. the amounts `x - u` by which the
+/// observations `x` above the threshold `u` exceed it.
+///
+/// The scale and shape are estimated by the method of probability-weighted
+/// moments (Hosking & Wallis, 1987), which unlike the ordinary method of
+/// moments is defined for all shapes `ξ < 1` and is reliable for the small
+/// samples typical of tail modelling.
+///
+/// # Formula
+///
+/// ```text
+/// ξ = 2 - a0 / (a0 - 2 a1)
+/// σ = 2 a0 a1 / (a0 - 2 a1)
+/// ```
+///
+/// where `a0` is the sample mean and
+/// `a1 = (1 / n) Σ x_(i) (n - i) / (n - 1)` with `x_(i)` the `i`-th smallest
+/// exceedance.
+///
+/// # Errors
+///
+/// If there are fewer than two exceedances, any is negative, `NaN` or
+/// infinite, or they are all equal
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::generalized_pareto_fit;
+///
+/// let gpd = generalized_pareto_fit(&[0.2, 1.5, 0.7, 3.1, 0.1, 0.9, 2.2, 0.4]).unwrap();
+/// assert_eq!(gpd.location(), 0.0);
+/// assert!(gpd.scale() > 0.0);
+/// ```
+pub fn generalized_pareto_fit(
+    exceedances: &[f64],
+) -> Result<GeneralizedPareto, GeneralizedParetoFitError> {
+    if exceedances.len() < 2 {
+        return Err(GeneralizedParetoFitError::SampleTooSmall);
+    }
+    if exceedances.iter().any(|x| !x.is_finite() || *x < 0.0) {
+        return Err(GeneralizedParetoFitError::DataInvalid);
+    }
+    let mut sorted = exceedances.to_vec();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    // checked directly, as rounding keeps `denom` below from being exactly
+    // zero for equal exceedances
+    if sorted[0] == sorted[sorted.len() - 1] {
+        return Err(GeneralizedParetoFitError::NoSpread);
+    }
+    let n = sorted.len() as f64;
+    let a0 = sorted.iter().sum::<f64>() / n;
+    let a1 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x * (n - 1.0 - i as f64) / (n - 1.0))
+        .sum::<f64>()
+        / n;
+    let denom = a0 - 2.0 * a1;
+    if denom <= 0.0 {
+        return Err(GeneralizedParetoFitError::NoSpread);
+    }
+    GeneralizedPareto::new(0.0, 2.0 * a0 * a1 / denom, 2.0 - a0 / denom)
+        .map_err(|_| GeneralizedParetoFitError::NoSpread)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_recovers_parameters() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        for &(scale, shape) in &[(2.0, 0.3), (1.0, 0.0), (0.5, -0.25), (3.0, 0.6)] {
+            let truth = GeneralizedPareto::new(0.0, scale, shape).unwrap();
+            let data: Vec<f64> = truth.sample_iter(StdRng::seed_from_u64(1002)).take(20_000).collect();
+            let fit = generalized_pareto_fit(&data).unwrap();
+            assert_almost_eq!(fit.shape(), shape, 0.05);
+            assert_almost_eq!(fit.scale() / scale, 1.0, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_exponential_quantiles() {
+        // evenly spaced exponential quantiles fit a shape near zero
+        let n = 2000;
+        let data: Vec<f64> = (0..n).map(|i| -(1.0 - (i as f64 + 0.5) / n as f64).ln()).collect();
+        let fit = generalized_pareto_fit(&data).unwrap();
+        assert_almost_eq!(fit.shape(), 0.0, 0.01);
+        assert_almost_eq!(fit.scale(), 1.0, 0.01);
+    }
+
+    #[test]
+    fn test_bad_data() {
+        assert_eq!(generalized_pareto_fit(&[]), Err(GeneralizedParetoFitError::SampleTooSmall));
+        assert_eq!(generalized_pareto_fit(&[1.0]), Err(GeneralizedParetoFitError::SampleTooSmall));
+        assert_eq!(generalized_pareto_fit(&[1.0, -0.5]), Err(GeneralizedParetoFitError::DataInvalid));
+        assert_eq!(generalized_pareto_fit(&[1.0, f64::NAN]), Err(GeneralizedParetoFitError::DataInvalid));
+        assert_eq!(generalized_pareto_fit(&[1.0, f64::INFINITY]), Err(GeneralizedParetoFitError::DataInvalid));
+        assert_eq!(generalized_pareto_fit(&[2.0, 2.0, 2.0]), Err(GeneralizedParetoFitError::NoSpread));
+        assert_eq!(generalized_pareto_fit(&[0.0, 0.0]), Err(GeneralizedParetoFitError::NoSpread));
+        assert_eq!(generalized_pareto_fit(&[0.7; 7]), Err(GeneralizedParetoFitError::NoSpread));
+    }
+}
@@ -0,0 +1,105 @@
+//! Provides measures of how well a distribution fits a sample
+
+use crate::distribution::ContinuousCDF;
+use crate::statistics::Statistics;
+
+/// Computes the Q–Q correlation coefficient, the Pearson correlation between
+/// the sorted `data` and the quantiles of `dist` at the Filliben plotting
+/// positions. It is the correlation of the points of a Q–Q plot, so values
+/// close to one indicate a good fit up to location and scale.
+///
+/// With a [`Normal`](crate::distribution::Normal) `dist` this is Filliben's
+/// probability plot correlation coefficient, a normality measure closely
+/// related to the Shapiro–Francia statistic.
+///
+/// Returns `None` if `data` has fewer than two values, contains `NaN`, or
+/// has no spread.
+///
+/// # Formula
+///
+/// ```text
+/// m_n = 0.5^(1 / n),  m_1 = 1 - m_n,  m_i = (i - 0.3175) / (n + 0.365)
+/// ```
+///
+/// are the plotting positions of the `n` sorted values, mapped through
+/// `inverse_cdf`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::gof::qq_correlation;
+///
+/// let data = [-1.2, 0.3, -0.4, 1.5, 0.1, 0.8, -0.9, 0.0];
+/// let r = qq_correlation(&data, &Normal::standard()).unwrap();
+/// assert!(r > 0.98);
+/// ```
+pub fn qq_correlation<D: ContinuousCDF<f64, f64>>(data: &[f64], dist: &D) -> Option<f64> {
+    if data.len() < 2 || data.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+    let last = 0.5f64.powf(1.0 / n as f64);
+    let quantiles: Vec<f64> = (1..=n)
+        .map(|i| {
+            let p = if i == 1 {
+                1.0 - last
+            } else if i == n {
+                last
+            } else {
+                (i as f64 - 0.3175) / (n as f64 + 0.365)
+            };
+            dist.inverse_cdf(p)
+        })
+        .collect();
+    let r = (&sorted).pearson(&quantiles);
+    if r.is_nan() {
+        None
+    } else {
+        Some(r)
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Normal;
+
+    #[test]
+    fn test_exact_quantiles() {
+        // data lying exactly on a line in the Q-Q plot, after location and scale
+        let n = Normal::standard();
+        let base: Vec<f64> = (0..50).map(|i| 3.0 + 2.0 * n.inverse_cdf((i as f64 + 0.5) / 50.0)).collect();
+        assert!(qq_correlation(&base, &n).unwrap() > 0.999);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_normal_vs_skewed() {
+        use crate::distribution::Exp;
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let std_normal = Normal::standard();
+        let normal: Vec<f64> = Normal::new(10.0, 3.0).unwrap().sample_iter(StdRng::seed_from_u64(1003)).take(500).collect();
+        let skewed: Vec<f64> = Exp::new(1.0).unwrap().sample_iter(StdRng::seed_from_u64(1003)).take(500).collect();
+
+        let r_normal = qq_correlation(&normal, &std_normal).unwrap();
+        let r_skewed = qq_correlation(&skewed, &std_normal).unwrap();
+        assert!(r_normal > 0.995);
+        assert!(r_skewed < 0.95);
+        // the exponential data does fit an exponential
+        assert!(qq_correlation(&skewed, &Exp::new(1.0).unwrap()).unwrap() > 0.99);
+    }
+
+    #[test]
+    fn test_bad_data() {
+        let n = Normal::standard();
+        assert_eq!(qq_correlation(&[], &n), None);
+        assert_eq!(qq_correlation(&[1.0], &n), None);
+        assert_eq!(qq_correlation(&[1.0, f64::NAN, 2.0], &n), None);
+        assert_eq!(qq_correlation(&[2.0, 2.0, 2.0], &n), None);
+    }
+}
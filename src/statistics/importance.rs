@@ -0,0 +1,98 @@
+use crate::distribution::Continuous;
+use crate::function::exponential::log_sum_exp;
+
+/// Estimates `E[f(X)]` for `X` distributed as `target` by self-normalized
+/// importance sampling with `n` draws from `proposal`.
+///
+/// # Remarks
+///
+/// Each draw `x_i` is weighted by
+/// [`log_importance_weight`](Continuous::log_importance_weight) and the
+/// weights are normalized in log space with [`log_sum_exp`], so the
+/// normalizing constants of both densities cancel and the estimate stays
+/// finite even when individual densities underflow. Returns `f64::NAN` if
+/// `n` is zero or no draw has positive weight under `target`.
+///
+/// # Formula
+///
+/// ```text
+/// Σ w_i * f(x_i) / Σ w_i,  w_i = p(x_i) / q(x_i)
+/// ```
+///
+/// where `p` is the density of `target` and `q` that of `proposal`
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::self_normalized_is;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let target = Normal::new(1.0, 1.0).unwrap();
+/// let proposal = Normal::new(0.0, 3.0).unwrap();
+/// let mean = self_normalized_is(&target, &proposal, &mut rng, 10_000, |x| x);
+/// assert!((mean - 1.0).abs() < 0.1);
+/// ```
+pub fn self_normalized_is<T, P, R, F>(target: &T, proposal: &P, r: &mut R, n: usize, f: F) -> f64
+where
+    T: Continuous<f64, f64>,
+    P: Continuous<f64, f64> + ::rand::distributions::Distribution<f64>,
+    R: ::rand::Rng + ?Sized,
+    F: Fn(f64) -> f64,
+{
+    let samples: Vec<f64> = (0..n).map(|_| proposal.sample(r)).collect();
+    let log_weights: Vec<f64> = samples
+        .iter()
+        .map(|&x| target.log_importance_weight(proposal, x))
+        .collect();
+    let log_total = log_sum_exp(&log_weights);
+    if !log_total.is_finite() {
+        return f64::NAN;
+    }
+    samples
+        .into_iter()
+        .zip(log_weights)
+        .filter(|&(_, lw)| lw > f64::NEG_INFINITY)
+        .map(|(x, lw)| (lw - log_total).exp() * f(x))
+        .sum()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::self_normalized_is;
+    use crate::distribution::{Exp, Normal, Uniform};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_normal_target_mean() {
+        let mut rng = StdRng::seed_from_u64(1600);
+        let target = Normal::new(2.0, 0.5).unwrap();
+        let proposal = Normal::new(0.0, 4.0).unwrap();
+        let mean = self_normalized_is(&target, &proposal, &mut rng, 100_000, |x| x);
+        assert_almost_eq!(mean, 2.0, 0.02);
+        let second = self_normalized_is(&target, &proposal, &mut rng, 100_000, |x| x * x);
+        assert_almost_eq!(second, 4.25, 0.05);
+    }
+
+    #[test]
+    fn test_matching_proposal_is_plain_average() {
+        let n = Normal::new(-1.0, 2.0).unwrap();
+        let mut r1 = StdRng::seed_from_u64(7);
+        let mut r2 = StdRng::seed_from_u64(7);
+        let estimate = self_normalized_is(&n, &n, &mut r1, 1000, |x| x);
+        let plain = (0..1000).map(|_| rand::distributions::Distribution::sample(&n, &mut r2)).sum::<f64>() / 1000.0;
+        assert_almost_eq!(estimate, plain, 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let target = Exp::new(1.0).unwrap();
+        assert!(self_normalized_is(&target, &target, &mut rng, 0, |x| x).is_nan());
+        // the proposal never lands in the support of the target
+        let proposal = Uniform::new(-2.0, -1.0).unwrap();
+        assert!(self_normalized_is(&target, &proposal, &mut rng, 100, |x| x).is_nan());
+    }
+}
@@ -0,0 +1,141 @@
+/// Returns `data` sorted in increasing order along with its sum, or `None`
+/// if it is empty, has a negative or `NaN` entry, or sums to zero
+fn sorted_shares(data: &[f64]) -> Option<(Vec<f64>, f64)> {
+    if data.iter().any(|x| x.is_nan() || *x < 0.0) {
+        return None;
+    }
+    let total: f64 = data.iter().sum();
+    if !(total > 0.0 && total.is_finite()) {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    Some((sorted, total))
+}
+
+/// Returns the sample Gini coefficient of the nonnegative values in `data`,
+/// from `0` when all values are equal up to `(n - 1) / n` when a single
+/// value holds the entire total.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `data` is empty, contains a negative or `NaN`
+/// entry, or sums to zero.
+///
+/// # Formula
+///
+/// ```text
+/// Σ (2i - n - 1) x_(i) / (n Σ x_i)
+/// ```
+///
+/// where `x_(i)` is the `i`-th smallest of the `n` values
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::gini;
+///
+/// assert_eq!(gini(&[2.0, 2.0, 2.0]), 0.0);
+/// assert_eq!(gini(&[0.0, 0.0, 0.0, 5.0]), 0.75);
+/// assert!(gini(&[1.0, -1.0]).is_nan());
+/// ```
+pub fn gini(data: &[f64]) -> f64 {
+    let (sorted, total) = match sorted_shares(data) {
+        Some(shares) => shares,
+        None => return f64::NAN,
+    };
+    let n = sorted.len() as f64;
+    let weighted: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (2.0 * (i + 1) as f64 - n - 1.0) * x)
+        .sum();
+    weighted / (n * total)
+}
+
+/// Returns the points of the empirical Lorenz curve of the nonnegative
+/// values in `data`, as `(population share, cumulative value share)` pairs
+/// running from `(0, 0)` to `(1, 1)` with one point per value.
+///
+/// Returns an empty vector if `data` is empty, contains a negative or `NaN`
+/// entry, or sums to zero.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::lorenz_curve;
+///
+/// let curve = lorenz_curve(&[3.0, 1.0]);
+/// assert_eq!(curve, [(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)]);
+/// ```
+pub fn lorenz_curve(data: &[f64]) -> Vec<(f64, f64)> {
+    let (sorted, total) = match sorted_shares(data) {
+        Some(shares) => shares,
+        None => return Vec::new(),
+    };
+    let n = sorted.len() as f64;
+    let mut cumulative = 0.0;
+    let mut curve = Vec::with_capacity(sorted.len() + 1);
+    curve.push((0.0, 0.0));
+    for (i, x) in sorted.iter().enumerate() {
+        cumulative += x;
+        curve.push(((i + 1) as f64 / n, cumulative / total));
+    }
+    // avoid rounding leaving the last share just short of one
+    if let Some(last) = curve.last_mut() {
+        last.1 = 1.0;
+    }
+    curve
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gini_equal() {
+        assert_eq!(gini(&[4.5; 10]), 0.0);
+        assert_eq!(gini(&[1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_gini_maximally_unequal() {
+        let mut data = vec![0.0; 99];
+        data.push(12.0);
+        assert_almost_eq!(gini(&data), 0.99, 1e-15);
+        assert_almost_eq!(gini(&[0.0, 7.0]), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn test_gini_matches_lorenz_area() {
+        let data = [3.0, 0.5, 8.0, 1.0, 1.0, 6.5, 2.0];
+        let curve = lorenz_curve(&data);
+        // 1 - 2 × (trapezoidal area under the Lorenz curve)
+        let area: f64 = curve.windows(2).map(|w| 0.5 * (w[1].0 - w[0].0) * (w[0].1 + w[1].1)).sum();
+        assert_almost_eq!(gini(&data), 1.0 - 2.0 * area, 1e-14);
+        // independent of the order of the data
+        let reversed: Vec<f64> = data.iter().rev().copied().collect();
+        assert_eq!(gini(&reversed), gini(&data));
+    }
+
+    #[test]
+    fn test_lorenz_curve() {
+        let curve = lorenz_curve(&[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(curve.len(), 5);
+        assert_eq!(curve[0], (0.0, 0.0));
+        assert_eq!(curve[4], (1.0, 1.0));
+        assert_almost_eq!(curve[2].1, 0.3, 1e-15);
+        assert!(curve.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        assert_eq!(lorenz_curve(&[5.0; 4]), [(0.0, 0.0), (0.25, 0.25), (0.5, 0.5), (0.75, 0.75), (1.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        for data in [&[][..], &[0.0, 0.0], &[1.0, -0.5], &[1.0, f64::NAN], &[1.0, f64::INFINITY]] {
+            assert!(gini(data).is_nan());
+            assert!(lorenz_curve(data).is_empty());
+        }
+    }
+}
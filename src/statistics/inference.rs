@@ -0,0 +1,967 @@
+//! Provides utilities for planning and interpreting statistical inference,
+//! such as power and sample-size calculations, multiple-testing corrections
+//! or sequential tests
+
+use crate::distribution::{ChiSquared, Continuous, ContinuousCDF, Normal, StudentsT};
+use crate::statistics::Statistics;
+
+/// Computes the power of a two-sided one-sample z-test, i.e. the probability
+/// of rejecting the null hypothesis at significance level `alpha` when the
+/// true standardized effect is `effect_size` and `n` observations are used.
+///
+/// # Formula
+///
+/// ```text
+/// Φ(d * sqrt(n) - z) + Φ(-d * sqrt(n) - z)
+/// ```
+///
+/// where `d` is the effect size, `Φ` is the standard normal cdf and
+/// `z = Φ^-1(1 - α / 2)` is the critical value.
+///
+/// # Panics
+///
+/// If `alpha` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::power_z_test;
+///
+/// let power = power_z_test(0.5, 32, 0.05);
+/// assert!((power - 0.8074).abs() < 1e-4);
+/// ```
+pub fn power_z_test(effect_size: f64, n: usize, alpha: f64) -> f64 {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        panic!("alpha must be in (0, 1)");
+    }
+    let std_normal = Normal::standard();
+    let z = std_normal.inverse_cdf(1.0 - alpha / 2.0);
+    let shift = effect_size * (n as f64).sqrt();
+    std_normal.cdf(shift - z) + std_normal.cdf(-shift - z)
+}
+
+/// Computes the smallest number of observations for which a two-sided
+/// one-sample z-test at significance level `alpha` detects a standardized
+/// effect of `effect_size` with probability at least `power`.
+///
+/// # Formula
+///
+/// ```text
+/// ceil(((z_(1 - α / 2) + z_power) / d)^2)
+/// ```
+///
+/// where `d` is the effect size and `z_p = Φ^-1(p)` are standard normal
+/// quantiles. The result is then adjusted so that
+/// [`power_z_test`] at the returned size is at least `power`.
+///
+/// # Panics
+///
+/// If `alpha` or `power` are not in `(0, 1)`, or if `effect_size` is zero
+/// or not finite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::sample_size_z_test;
+///
+/// assert_eq!(sample_size_z_test(0.5, 0.8, 0.05), 32);
+/// ```
+pub fn sample_size_z_test(effect_size: f64, power: f64, alpha: f64) -> usize {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        panic!("alpha must be in (0, 1)");
+    }
+    if !(power > 0.0 && power < 1.0) {
+        panic!("power must be in (0, 1)");
+    }
+    if effect_size == 0.0 || !effect_size.is_finite() {
+        panic!("effect_size must be finite and nonzero");
+    }
+    let std_normal = Normal::standard();
+    let z_alpha = std_normal.inverse_cdf(1.0 - alpha / 2.0);
+    let z_power = std_normal.inverse_cdf(power);
+    let mut n = ((z_alpha + z_power) / effect_size).powi(2).ceil().max(1.0) as usize;
+
+    // the closed form ignores the opposite tail, so it can only overestimate
+    // the required size by a small amount
+    while n > 1 && power_z_test(effect_size, n - 1, alpha) >= power {
+        n -= 1;
+    }
+    n
+}
+
+/// Applies the Benjamini–Hochberg procedure to `p_values`, returning for
+/// each hypothesis, in the original order, whether it is rejected while
+/// controlling the false discovery rate at `fdr`.
+///
+/// The p-values are sorted in increasing order and all hypotheses up to the
+/// largest rank `k` with `p_(k) <= k * fdr / m` are rejected, where `m` is
+/// the number of hypotheses. This controls the false discovery rate for
+/// independent or positively dependent tests.
+///
+/// # Panics
+///
+/// If `fdr` is not in `(0, 1)` or any p-value is not in `[0, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::benjamini_hochberg;
+///
+/// let rejected = benjamini_hochberg(&[0.01, 0.04, 0.03, 0.005, 0.2, 0.045], 0.05);
+/// assert_eq!(rejected, [true, false, false, true, false, false]);
+/// ```
+pub fn benjamini_hochberg(p_values: &[f64], fdr: f64) -> Vec<bool> {
+    if !(fdr > 0.0 && fdr < 1.0) {
+        panic!("fdr must be in (0, 1)");
+    }
+    check_p_values(p_values);
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&i, &j| p_values[i].total_cmp(&p_values[j]));
+
+    let n_rejected = order
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(rank, &i)| p_values[i] <= (rank + 1) as f64 * fdr / m as f64)
+        .map_or(0, |(rank, _)| rank + 1);
+    let mut rejected = vec![false; m];
+    for &i in &order[..n_rejected] {
+        rejected[i] = true;
+    }
+    rejected
+}
+
+/// Applies the Bonferroni correction to `p_values`, returning for each
+/// hypothesis, in the original order, whether it is rejected while
+/// controlling the family-wise error rate at `alpha`.
+///
+/// A hypothesis is rejected if its p-value is at most `alpha / m`, where `m`
+/// is the number of hypotheses. This is more conservative than
+/// [`benjamini_hochberg`], but holds under any dependence.
+///
+/// # Panics
+///
+/// If `alpha` is not in `(0, 1)` or any p-value is not in `[0, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::bonferroni;
+///
+/// let rejected = bonferroni(&[0.01, 0.04, 0.03, 0.005, 0.2, 0.045], 0.05);
+/// assert_eq!(rejected, [false, false, false, true, false, false]);
+/// ```
+pub fn bonferroni(p_values: &[f64], alpha: f64) -> Vec<bool> {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        panic!("alpha must be in (0, 1)");
+    }
+    check_p_values(p_values);
+    let threshold = alpha / p_values.len() as f64;
+    p_values.iter().map(|&p| p <= threshold).collect()
+}
+
+fn check_p_values(p_values: &[f64]) {
+    if !p_values.iter().all(|p| (0.0..=1.0).contains(p)) {
+        panic!("p-values must be in [0, 1]");
+    }
+}
+
+/// Represents the errors that can occur when computing a confidence interval
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ConfidenceIntervalError {
+    /// The sample contains fewer than two observations.
+    SampleTooSmall,
+
+    /// The confidence level is NaN or not in `(0, 1)`.
+    ConfidenceInvalid,
+
+    /// The population coverage is NaN or not in `(0, 1)`.
+    CoverageInvalid,
+}
+
+impl std::fmt::Display for ConfidenceIntervalError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConfidenceIntervalError::SampleTooSmall => {
+                write!(f, "sample must contain at least 2 observations")
+            }
+            ConfidenceIntervalError::ConfidenceInvalid => {
+                write!(f, "confidence must be in (0, 1)")
+            }
+            ConfidenceIntervalError::CoverageInvalid => {
+                write!(f, "coverage must be in (0, 1)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfidenceIntervalError {}
+
+/// Computes the two-sided confidence interval for the variance of a normal
+/// population from the sample `data` at the given `confidence` level.
+///
+/// # Formula
+///
+/// ```text
+/// ((n - 1) * s^2 / χ²_(1 - α / 2), (n - 1) * s^2 / χ²_(α / 2))
+/// ```
+///
+/// where `s^2` is the sample variance, `α = 1 - confidence` and `χ²_p` is the
+/// `p` quantile of the chi-squared distribution with `n - 1` degrees of
+/// freedom.
+///
+/// # Errors
+///
+/// If `data` has fewer than two observations or `confidence` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::variance_confidence_interval;
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+/// let (lower, upper) = variance_confidence_interval(&data, 0.95).unwrap();
+/// assert!(lower < 55.0 / 6.0 && 55.0 / 6.0 < upper);
+/// ```
+pub fn variance_confidence_interval(
+    data: &[f64],
+    confidence: f64,
+) -> Result<(f64, f64), ConfidenceIntervalError> {
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(ConfidenceIntervalError::ConfidenceInvalid);
+    }
+    if data.len() < 2 {
+        return Err(ConfidenceIntervalError::SampleTooSmall);
+    }
+    let freedom = (data.len() - 1) as f64;
+    let chi_squared = ChiSquared::new(freedom).expect("freedom is positive");
+    let alpha = 1.0 - confidence;
+    let scaled = freedom * data.variance();
+    Ok((
+        scaled / chi_squared.inverse_cdf(1.0 - alpha / 2.0),
+        scaled / chi_squared.inverse_cdf(alpha / 2.0),
+    ))
+}
+
+/// Computes the two-sided prediction interval for a single future
+/// observation from a normal population whose mean and variance are
+/// estimated from the sample `data`, at the given `confidence` level.
+///
+/// It is wider than [`Normal::prediction_interval`] with the estimates
+/// plugged in, since it also accounts for the error in those estimates,
+/// and approaches it as the sample grows.
+///
+/// # Formula
+///
+/// ```text
+/// x̄ ± t_(1 - α / 2) * s * sqrt(1 + 1 / n)
+/// ```
+///
+/// where `x̄` and `s` are the sample mean and standard deviation,
+/// `α = 1 - confidence` and `t_q` is the `q` quantile of the Student's t
+/// distribution with `n - 1` degrees of freedom.
+///
+/// # Errors
+///
+/// If `data` has fewer than two observations or `confidence` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::prediction_interval_from_data;
+///
+/// let data = [9.8, 10.1, 10.0, 9.7, 10.4, 10.2, 9.9, 10.0, 10.3, 9.6];
+/// let (lower, upper) = prediction_interval_from_data(&data, 0.95).unwrap();
+/// assert!(lower < 9.6 && 10.4 < upper);
+/// ```
+pub fn prediction_interval_from_data(
+    data: &[f64],
+    confidence: f64,
+) -> Result<(f64, f64), ConfidenceIntervalError> {
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(ConfidenceIntervalError::ConfidenceInvalid);
+    }
+    if data.len() < 2 {
+        return Err(ConfidenceIntervalError::SampleTooSmall);
+    }
+    let n = data.len() as f64;
+    let students_t = StudentsT::new(0.0, 1.0, n - 1.0).expect("freedom is positive");
+    let t = students_t.inverse_cdf(0.5 * (1.0 + confidence));
+    let mean = data.mean();
+    let half_width = t * data.std_dev() * (1.0 + 1.0 / n).sqrt();
+    Ok((mean - half_width, mean + half_width))
+}
+
+/// Computes the two-sided tolerance interval of a normal population from
+/// the sample `data`, an interval that contains at least the fraction
+/// `coverage` of the population with the given `confidence`.
+///
+/// Unlike a confidence interval, which brackets a parameter such as the
+/// mean, or a prediction interval, which brackets a single future
+/// observation, a tolerance interval brackets a fixed share of all future
+/// observations.
+///
+/// # Formula
+///
+/// ```text
+/// x̄ ± k s,  k = z_((1 + p) / 2) * sqrt((n - 1) (1 + 1 / n) / χ²_(1 - γ))
+/// ```
+///
+/// where `x̄` and `s` are the sample mean and standard deviation, `p` is the
+/// coverage, `γ` is the confidence, `z_q` is the `q` quantile of the
+/// standard normal distribution and `χ²_q` is the `q` quantile of the
+/// chi-squared distribution with `n - 1` degrees of freedom. This is Howe's
+/// approximation to the exact factor, accurate to within about 1% even for
+/// small samples.
+///
+/// # Errors
+///
+/// If `data` has fewer than two observations, or `coverage` or `confidence`
+/// is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::tolerance_interval;
+///
+/// let data = [9.8, 10.1, 10.0, 9.7, 10.4, 10.2, 9.9, 10.0, 10.3, 9.6];
+/// let (lower, upper) = tolerance_interval(&data, 0.9, 0.95).unwrap();
+/// assert!(lower < 9.6 && 10.4 < upper);
+/// ```
+pub fn tolerance_interval(
+    data: &[f64],
+    coverage: f64,
+    confidence: f64,
+) -> Result<(f64, f64), ConfidenceIntervalError> {
+    if !(coverage > 0.0 && coverage < 1.0) {
+        return Err(ConfidenceIntervalError::CoverageInvalid);
+    }
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(ConfidenceIntervalError::ConfidenceInvalid);
+    }
+    if data.len() < 2 {
+        return Err(ConfidenceIntervalError::SampleTooSmall);
+    }
+    let n = data.len() as f64;
+    let chi_squared = ChiSquared::new(n - 1.0).expect("freedom is positive");
+    let z = Normal::standard().inverse_cdf(0.5 * (1.0 + coverage));
+    let k = z * ((n - 1.0) * (1.0 + 1.0 / n) / chi_squared.inverse_cdf(1.0 - confidence)).sqrt();
+    let mean = data.mean();
+    let half_width = k * data.std_dev();
+    Ok((mean - half_width, mean + half_width))
+}
+
+/// Represents the errors that can occur when computing an effect size
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum EffectSizeError {
+    /// A sample contains fewer than two observations.
+    SampleTooSmall,
+
+    /// The pooled standard deviation is zero or NaN.
+    PooledStdDevInvalid,
+}
+
+impl std::fmt::Display for EffectSizeError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EffectSizeError::SampleTooSmall => {
+                write!(f, "each sample must contain at least 2 observations")
+            }
+            EffectSizeError::PooledStdDevInvalid => {
+                write!(f, "pooled standard deviation is zero or NaN")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EffectSizeError {}
+
+/// Computes Cohen's d, the difference of the means of `a` and `b` in units
+/// of their pooled standard deviation.
+///
+/// # Formula
+///
+/// ```text
+/// (mean(a) - mean(b)) / sqrt(((n_a - 1) s_a^2 + (n_b - 1) s_b^2) / (n_a + n_b - 2))
+/// ```
+///
+/// where `s^2` is the sample variance
+///
+/// # Errors
+///
+/// If either sample has fewer than two observations, or the pooled
+/// standard deviation is zero or `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::cohens_d;
+///
+/// let d = cohens_d(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0, 6.0, 7.0]).unwrap();
+/// assert!((d + 2.0 / 2.5f64.sqrt()).abs() < 1e-15);
+/// ```
+pub fn cohens_d(a: &[f64], b: &[f64]) -> Result<f64, EffectSizeError> {
+    if a.len() < 2 || b.len() < 2 {
+        return Err(EffectSizeError::SampleTooSmall);
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let pooled_var = ((n_a - 1.0) * a.variance() + (n_b - 1.0) * b.variance()) / (n_a + n_b - 2.0);
+    if pooled_var.is_nan() || pooled_var <= 0.0 {
+        return Err(EffectSizeError::PooledStdDevInvalid);
+    }
+    Ok((a.mean() - b.mean()) / pooled_var.sqrt())
+}
+
+/// Computes Hedges' g, [Cohen's d](cohens_d) multiplied by a correction
+/// for its upward bias in small samples.
+///
+/// # Formula
+///
+/// ```text
+/// d * (1 - 3 / (4 (n_a + n_b) - 9))
+/// ```
+///
+/// # Errors
+///
+/// If either sample has fewer than two observations, or the pooled
+/// standard deviation is zero or `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::inference::{cohens_d, hedges_g};
+///
+/// let (a, b) = ([1.0, 2.0, 3.0, 4.0, 5.0], [3.0, 4.0, 5.0, 6.0, 7.0]);
+/// let g = hedges_g(&a, &b).unwrap();
+/// assert!(g.abs() < cohens_d(&a, &b).unwrap().abs());
+/// ```
+pub fn hedges_g(a: &[f64], b: &[f64]) -> Result<f64, EffectSizeError> {
+    let d = cohens_d(a, b)?;
+    let n = (a.len() + b.len()) as f64;
+    Ok(d * (1.0 - 3.0 / (4.0 * n - 9.0)))
+}
+
+/// The outcome of a [`Sprt`] after an observation
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum SprtDecision {
+    /// Neither boundary has been crossed, more observations are needed
+    Continue,
+    /// The lower boundary has been crossed, the null hypothesis is accepted
+    AcceptH0,
+    /// The upper boundary has been crossed, the alternative hypothesis is
+    /// accepted
+    AcceptH1,
+}
+
+/// Implements Wald's
+/// [sequential probability ratio test](https://en.wikipedia.org/wiki/Sequential_probability_ratio_test)
+/// between two simple hypotheses given as continuous distributions.
+///
+/// Observations are fed one at a time with [`Sprt::observe`], which
+/// accumulates the log-likelihood ratio `ln(h1.pdf(x) / h0.pdf(x))` and
+/// compares it against the boundaries `ln(β / (1 - α))` and
+/// `ln((1 - β) / α)`. Sampling should stop at the first decision other than
+/// [`SprtDecision::Continue`].
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::inference::{Sprt, SprtDecision};
+///
+/// let h0 = Normal::new(0.0, 1.0).unwrap();
+/// let h1 = Normal::new(1.0, 1.0).unwrap();
+/// let mut sprt = Sprt::new(h0, h1, 0.05, 0.05);
+/// assert_eq!(sprt.observe(1.2), SprtDecision::Continue);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Sprt<H0, H1> {
+    h0: H0,
+    h1: H1,
+    lower: f64,
+    upper: f64,
+    llr: f64,
+    n: usize,
+}
+
+impl<H0, H1> Sprt<H0, H1>
+where
+    H0: Continuous<f64, f64>,
+    H1: Continuous<f64, f64>,
+{
+    /// Constructs a new sequential probability ratio test of `h0` against
+    /// `h1` with type I error rate `alpha` and type II error rate `beta`
+    ///
+    /// # Panics
+    ///
+    /// If `alpha` or `beta` are not in `(0, 1)`, or if `alpha + beta >= 1`
+    pub fn new(h0: H0, h1: H1, alpha: f64, beta: f64) -> Self {
+        if !(alpha > 0.0 && alpha < 1.0) {
+            panic!("alpha must be in (0, 1)");
+        }
+        if !(beta > 0.0 && beta < 1.0) {
+            panic!("beta must be in (0, 1)");
+        }
+        if alpha + beta >= 1.0 {
+            panic!("alpha + beta must be less than 1");
+        }
+        Sprt {
+            h0,
+            h1,
+            lower: (beta / (1.0 - alpha)).ln(),
+            upper: ((1.0 - beta) / alpha).ln(),
+            llr: 0.0,
+            n: 0,
+        }
+    }
+
+    /// Adds the observation `x` to the test and returns the resulting
+    /// decision
+    ///
+    /// # Remarks
+    ///
+    /// An observation outside the support of both hypotheses carries no
+    /// evidence either way; it is counted but leaves the log-likelihood
+    /// ratio unchanged instead of turning it into `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::inference::{Sprt, SprtDecision};
+    ///
+    /// let h0 = Normal::new(0.0, 1.0).unwrap();
+    /// let h1 = Normal::new(1.0, 1.0).unwrap();
+    /// let mut sprt = Sprt::new(h0, h1, 0.05, 0.05);
+    /// let decision = [1.5, 0.8, 2.1, 1.1, 1.9]
+    ///     .iter()
+    ///     .map(|&x| sprt.observe(x))
+    ///     .find(|&d| d != SprtDecision::Continue);
+    /// assert_eq!(decision, Some(SprtDecision::AcceptH1));
+    /// ```
+    pub fn observe(&mut self, x: f64) -> SprtDecision {
+        let (ln_h1, ln_h0) = (self.h1.ln_pdf(x), self.h0.ln_pdf(x));
+        if ln_h1 != f64::NEG_INFINITY || ln_h0 != f64::NEG_INFINITY {
+            self.llr += ln_h1 - ln_h0;
+        }
+        self.n += 1;
+        self.decision()
+    }
+
+    /// Returns the decision given the observations so far
+    pub fn decision(&self) -> SprtDecision {
+        if self.llr >= self.upper {
+            SprtDecision::AcceptH1
+        } else if self.llr <= self.lower {
+            SprtDecision::AcceptH0
+        } else {
+            SprtDecision::Continue
+        }
+    }
+
+    /// Returns the accumulated log-likelihood ratio of `h1` against `h0`
+    pub fn log_likelihood_ratio(&self) -> f64 {
+        self.llr
+    }
+
+    /// Returns the number of observations made so far
+    pub fn n_observations(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_z_test() {
+        assert_almost_eq!(
+            power_z_test(0.5, 32, 0.05),
+            0.807430419432557181186689948944,
+            1e-9
+        );
+        assert_almost_eq!(
+            power_z_test(0.2, 100, 0.05),
+            0.516005273976174932925472569696,
+            1e-9
+        );
+        assert_almost_eq!(
+            power_z_test(0.8, 10, 0.01),
+            0.481652430235309976361523659522,
+            1e-9
+        );
+        assert_almost_eq!(
+            power_z_test(-0.5, 32, 0.05),
+            0.807430419432557181186689948944,
+            1e-9
+        );
+        assert_almost_eq!(power_z_test(0.5, 0, 0.05), 0.05, 1e-9);
+        assert_almost_eq!(power_z_test(0.0, 50, 0.05), 0.05, 1e-9);
+    }
+
+    #[test]
+    fn test_sample_size_z_test() {
+        // textbook two-sided z-test sizes for 80% power at α = 0.05
+        assert_eq!(sample_size_z_test(0.5, 0.8, 0.05), 32);
+        assert_eq!(sample_size_z_test(0.3, 0.8, 0.05), 88);
+        assert_eq!(sample_size_z_test(0.2, 0.8, 0.05), 197);
+        assert_eq!(sample_size_z_test(-0.2, 0.8, 0.05), 197);
+        // 90% power at α = 0.05 and 80% power at α = 0.01
+        assert_eq!(sample_size_z_test(0.5, 0.9, 0.05), 43);
+        assert_eq!(sample_size_z_test(0.5, 0.8, 0.01), 47);
+    }
+
+    #[test]
+    fn test_sample_size_attains_power() {
+        for &d in &[0.1, 0.25, 0.5, 1.0, 2.0] {
+            for &power in &[0.5, 0.8, 0.95] {
+                let n = sample_size_z_test(d, power, 0.05);
+                assert!(power_z_test(d, n, 0.05) >= power);
+                if n > 1 {
+                    assert!(power_z_test(d, n - 1, 0.05) < power);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_power_z_test_bad_alpha() {
+        power_z_test(0.5, 10, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_size_z_test_zero_effect() {
+        sample_size_z_test(0.0, 0.8, 0.05);
+    }
+
+    #[test]
+    fn test_benjamini_hochberg() {
+        // sorted: 0.005, 0.01, 0.03, 0.04, 0.045, 0.2 against thresholds
+        // k * 0.05 / 6 = 0.0083, 0.0167, 0.025, 0.0333, 0.0417, 0.05, so
+        // only the two smallest are rejected
+        let p = [0.01, 0.04, 0.03, 0.005, 0.2, 0.045];
+        assert_eq!(
+            benjamini_hochberg(&p, 0.05),
+            [true, false, false, true, false, false]
+        );
+        // at 0.1 the thresholds double and 0.045 <= 5 * 0.1 / 6 passes
+        assert_eq!(
+            benjamini_hochberg(&p, 0.1),
+            [true, true, true, true, false, true]
+        );
+
+        // step-up: the smallest p-value misses its own threshold 0.05 / 3,
+        // but is rejected along with the second, which meets 2 * 0.05 / 3
+        assert_eq!(
+            benjamini_hochberg(&[0.032, 0.8, 0.03], 0.05),
+            [true, false, true]
+        );
+        assert_eq!(benjamini_hochberg(&[0.5, 0.9], 0.05), [false, false]);
+        assert_eq!(benjamini_hochberg(&[0.01, 0.02, 0.03], 0.05), [true; 3]);
+        assert!(benjamini_hochberg(&[], 0.05).is_empty());
+    }
+
+    #[test]
+    fn test_bonferroni() {
+        let p = [0.01, 0.04, 0.03, 0.005, 0.2, 0.045];
+        assert_eq!(
+            bonferroni(&p, 0.05),
+            [false, false, false, true, false, false]
+        );
+        assert_eq!(
+            bonferroni(&p, 0.06),
+            [true, false, false, true, false, false]
+        );
+        assert_eq!(bonferroni(&[0.05], 0.05), [true]);
+        // never rejects more than Benjamini–Hochberg
+        for alpha in [0.01, 0.05, 0.1, 0.3] {
+            let bh = benjamini_hochberg(&p, alpha);
+            for (b, bh) in bonferroni(&p, alpha).into_iter().zip(bh) {
+                assert!(!b || bh);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_benjamini_hochberg_bad_p_value() {
+        benjamini_hochberg(&[0.01, f64::NAN], 0.05);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bonferroni_bad_alpha() {
+        bonferroni(&[0.01], 0.0);
+    }
+
+    #[test]
+    fn test_variance_confidence_interval() {
+        // n = 10, s^2 = 55 / 6, with χ²_9 quantiles 2.700389 and 19.022768
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let (lower, upper) = variance_confidence_interval(&data, 0.95).unwrap();
+        assert_almost_eq!(lower, 82.5 / 19.022768, 1e-5);
+        assert_almost_eq!(upper, 82.5 / 2.700389, 1e-4);
+
+        // a wider confidence level gives a wider interval
+        let (lower_99, upper_99) = variance_confidence_interval(&data, 0.99).unwrap();
+        assert!(lower_99 < lower && upper < upper_99);
+
+        // two observations: 1 degree of freedom
+        let (lower, upper) = variance_confidence_interval(&[0.0, 2.0], 0.9).unwrap();
+        let chi = ChiSquared::new(1.0).unwrap();
+        assert_almost_eq!(lower, 2.0 / chi.inverse_cdf(0.95), 1e-12);
+        assert_almost_eq!(upper, 2.0 / chi.inverse_cdf(0.05), 1e-9);
+    }
+
+    #[test]
+    fn test_variance_confidence_interval_bad_input() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(
+            variance_confidence_interval(&[1.0], 0.95),
+            Err(ConfidenceIntervalError::SampleTooSmall)
+        );
+        assert_eq!(
+            variance_confidence_interval(&[], 0.95),
+            Err(ConfidenceIntervalError::SampleTooSmall)
+        );
+        for confidence in [0.0, 1.0, -0.5, f64::NAN] {
+            assert_eq!(
+                variance_confidence_interval(&data, confidence),
+                Err(ConfidenceIntervalError::ConfidenceInvalid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_prediction_interval_from_data() {
+        // n = 10, mean 5.5, s^2 = 55 / 6, with t_9 quantile 2.2621571627409915
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let (lower, upper) = prediction_interval_from_data(&data, 0.95).unwrap();
+        let half_width = 2.2621571627409915 * (55.0f64 / 6.0 * 1.1).sqrt();
+        assert_almost_eq!(lower, 5.5 - half_width, 1e-9);
+        assert_almost_eq!(upper, 5.5 + half_width, 1e-9);
+
+        // wider than the interval with the estimates taken as known, and
+        // narrowing toward it as n grows
+        let mut prev_ratio = f64::INFINITY;
+        for n in [3, 10, 100, 10_000] {
+            let data: Vec<f64> = (0..n).map(|i| (i as f64 * 0.37).sin()).collect();
+            let plug_in = Normal::new(data.clone().mean(), data.clone().std_dev()).unwrap();
+            let (known_lower, known_upper) = plug_in.prediction_interval(0.9).unwrap();
+            let (lower, upper) = prediction_interval_from_data(&data, 0.9).unwrap();
+            let ratio = (upper - lower) / (known_upper - known_lower);
+            assert!(
+                ratio > 1.0 && ratio < prev_ratio,
+                "n = {}: ratio {}",
+                n,
+                ratio
+            );
+            prev_ratio = ratio;
+        }
+        assert!(prev_ratio < 1.001);
+    }
+
+    #[test]
+    fn test_prediction_interval_from_data_bad_input() {
+        assert_eq!(
+            prediction_interval_from_data(&[1.0], 0.95),
+            Err(ConfidenceIntervalError::SampleTooSmall)
+        );
+        for confidence in [0.0, 1.0, -0.5, f64::NAN] {
+            assert_eq!(
+                prediction_interval_from_data(&[1.0, 2.0, 3.0], confidence),
+                Err(ConfidenceIntervalError::ConfidenceInvalid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_tolerance_interval() {
+        // a sample with mean 0 and standard deviation 1, so the half-width is k
+        let k_factor = |n: usize, coverage: f64, confidence: f64| {
+            let raw: Vec<f64> = (0..n).map(|i| i as f64).collect();
+            let (mean, std_dev) = (raw.clone().mean(), raw.clone().std_dev());
+            let data: Vec<f64> = raw.iter().map(|x| (x - mean) / std_dev).collect();
+            let (lower, upper) = tolerance_interval(&data, coverage, confidence).unwrap();
+            assert_almost_eq!(lower, -upper, 1e-12);
+            upper
+        };
+
+        // Howe's factor, computed with mpmath
+        assert_almost_eq!(k_factor(10, 0.9, 0.95), 2.8381912702366788, 1e-6);
+        assert_almost_eq!(k_factor(20, 0.99, 0.95), 3.6171154794289655, 1e-6);
+
+        // published exact two-sided factors at 95% confidence
+        for &(n, coverage, exact) in &[
+            (10, 0.90, 2.856),
+            (10, 0.95, 3.379),
+            (10, 0.99, 4.433),
+            (20, 0.90, 2.310),
+            (20, 0.95, 2.752),
+            (20, 0.99, 3.615),
+        ] {
+            let k = k_factor(n, coverage, 0.95);
+            assert!(
+                (k - exact).abs() / exact < 0.01,
+                "n = {}, p = {}: {} vs {}",
+                n,
+                coverage,
+                k,
+                exact
+            );
+        }
+
+        // more coverage or confidence widens the interval
+        assert!(k_factor(15, 0.95, 0.95) < k_factor(15, 0.99, 0.95));
+        assert!(k_factor(15, 0.95, 0.90) < k_factor(15, 0.95, 0.99));
+    }
+
+    #[test]
+    fn test_tolerance_interval_bad_input() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(
+            tolerance_interval(&[1.0], 0.9, 0.95),
+            Err(ConfidenceIntervalError::SampleTooSmall)
+        );
+        for bad in [0.0, 1.0, -0.5, f64::NAN] {
+            assert_eq!(
+                tolerance_interval(&data, bad, 0.95),
+                Err(ConfidenceIntervalError::CoverageInvalid)
+            );
+            assert_eq!(
+                tolerance_interval(&data, 0.9, bad),
+                Err(ConfidenceIntervalError::ConfidenceInvalid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cohens_d_and_hedges_g() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [3.0, 4.0, 5.0, 6.0, 7.0];
+        // both variances are 2.5, so the pooled standard deviation is sqrt(2.5)
+        assert_almost_eq!(cohens_d(&a, &b).unwrap(), -1.2649110640673518, 1e-15);
+        assert_almost_eq!(cohens_d(&b, &a).unwrap(), 1.2649110640673518, 1e-15);
+        assert_almost_eq!(
+            hedges_g(&a, &b).unwrap(),
+            -1.2649110640673518 * 28.0 / 31.0,
+            1e-15
+        );
+
+        // unequal sizes and variances: s_a^2 = 1, s_b^2 = 7, pooled = 4
+        let a = [2.0, 3.0, 4.0];
+        let b = [0.0, 2.0, 4.0, 6.0, 1.0, 5.0, 3.0];
+        assert_almost_eq!(b.variance(), 14.0 / 3.0, 1e-14);
+        let pooled = ((2.0 * 1.0 + 6.0 * 14.0 / 3.0) / 8.0f64).sqrt();
+        assert_almost_eq!(cohens_d(&a, &b).unwrap(), 0.0, 1e-15);
+        let a = [5.0, 6.0, 7.0];
+        let d = cohens_d(&a, &b).unwrap();
+        assert_almost_eq!(d, 3.0 / pooled, 1e-14);
+
+        // the correction shrinks towards zero, less so for larger samples
+        let g = hedges_g(&a, &b).unwrap();
+        assert!(g > 0.0 && g < d);
+        let big_a: Vec<f64> = (0..200).map(|i| (i % 7) as f64 + 3.0).collect();
+        let big_b: Vec<f64> = (0..200).map(|i| (i % 7) as f64).collect();
+        let (d, g) = (
+            cohens_d(&big_a, &big_b).unwrap(),
+            hedges_g(&big_a, &big_b).unwrap(),
+        );
+        assert!(g < d && d - g < 0.01);
+    }
+
+    #[test]
+    fn test_effect_size_bad_input() {
+        assert_eq!(
+            cohens_d(&[1.0], &[1.0, 2.0]),
+            Err(EffectSizeError::SampleTooSmall)
+        );
+        assert_eq!(
+            hedges_g(&[1.0, 2.0], &[]),
+            Err(EffectSizeError::SampleTooSmall)
+        );
+        assert_eq!(
+            cohens_d(&[1.0, 1.0], &[2.0, 2.0]),
+            Err(EffectSizeError::PooledStdDevInvalid)
+        );
+        assert_eq!(
+            cohens_d(&[1.0, f64::NAN], &[2.0, 3.0]),
+            Err(EffectSizeError::PooledStdDevInvalid)
+        );
+    }
+
+    #[test]
+    fn test_sprt_boundaries() {
+        let h0 = Normal::new(0.0, 1.0).unwrap();
+        let h1 = Normal::new(1.0, 1.0).unwrap();
+        let mut sprt = Sprt::new(h0, h1, 0.05, 0.05);
+        // each observation at x = 1 adds 0.5 to the log-likelihood ratio,
+        // crossing ln(19) ≈ 2.944 on the sixth
+        for i in 1..6 {
+            assert_eq!(sprt.observe(1.0), SprtDecision::Continue);
+            assert_almost_eq!(sprt.log_likelihood_ratio(), 0.5 * i as f64, 1e-12);
+        }
+        assert_eq!(sprt.observe(1.0), SprtDecision::AcceptH1);
+        assert_eq!(sprt.n_observations(), 6);
+
+        let mut sprt = Sprt::new(h0, h1, 0.05, 0.05);
+        for _ in 0..5 {
+            assert_eq!(sprt.observe(0.0), SprtDecision::Continue);
+        }
+        assert_eq!(sprt.observe(0.0), SprtDecision::AcceptH0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sprt_stream() {
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let h0 = Normal::new(0.0, 1.0).unwrap();
+        let h1 = Normal::new(0.5, 1.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(1600);
+        for (truth, expected) in [(h1, SprtDecision::AcceptH1), (h0, SprtDecision::AcceptH0)] {
+            let mut sprt = Sprt::new(h0, h1, 0.01, 0.01);
+            let decision = truth
+                .sample_iter(&mut rng)
+                .take(10_000)
+                .map(|x| sprt.observe(x))
+                .find(|&d| d != SprtDecision::Continue);
+            assert_eq!(decision, Some(expected));
+            assert!(sprt.n_observations() > 1);
+        }
+    }
+
+    #[test]
+    fn test_sprt_impossible_under_both() {
+        use crate::distribution::Uniform;
+
+        let h0 = Uniform::new(0.0, 1.0).unwrap();
+        let h1 = Uniform::new(0.0, 2.0).unwrap();
+        let mut sprt = Sprt::new(h0, h1, 0.05, 0.05);
+        assert_eq!(sprt.observe(0.5), SprtDecision::Continue);
+        let llr = sprt.log_likelihood_ratio();
+        assert_eq!(sprt.observe(-1.0), SprtDecision::Continue);
+        assert_eq!(sprt.observe(3.0), SprtDecision::Continue);
+        assert_eq!(sprt.log_likelihood_ratio(), llr);
+        assert_eq!(sprt.n_observations(), 3);
+        // possible under h1 only
+        assert_eq!(sprt.observe(1.5), SprtDecision::AcceptH1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sprt_bad_error_rates() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        Sprt::new(n, n, 0.6, 0.5);
+    }
+}
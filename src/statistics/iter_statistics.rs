@@ -67,6 +67,36 @@ where
         }
     }
 
+    fn argmin(self) -> Option<usize> {
+        let mut iter = self.into_iter().map(|x| *x.borrow()).enumerate();
+        let (mut idx, mut acc) = iter.next()?;
+        for (i, x) in iter {
+            if acc.is_nan() {
+                break;
+            }
+            if x < acc || x.is_nan() {
+                idx = i;
+                acc = x;
+            }
+        }
+        Some(idx)
+    }
+
+    fn argmax(self) -> Option<usize> {
+        let mut iter = self.into_iter().map(|x| *x.borrow()).enumerate();
+        let (mut idx, mut acc) = iter.next()?;
+        for (i, x) in iter {
+            if acc.is_nan() {
+                break;
+            }
+            if x > acc || x.is_nan() {
+                idx = i;
+                acc = x;
+            }
+        }
+        Some(idx)
+    }
+
     fn mean(self) -> f64 {
         let mut i = 0.0;
         let mut mean = 0.0;
@@ -223,6 +253,41 @@ where
         }
     }
 
+    fn pearson(self, other: Self) -> f64 {
+        let mut n = 0.0;
+        let mut mean1 = 0.0;
+        let mut mean2 = 0.0;
+        let mut moment1 = 0.0;
+        let mut moment2 = 0.0;
+        let mut comoment = 0.0;
+
+        let mut iter = other.into_iter();
+        for x in self {
+            let borrow = *x.borrow();
+            let borrow2 = match iter.next() {
+                None => return f64::NAN,
+                Some(x) => *x.borrow(),
+            };
+            let delta1 = borrow - mean1;
+            let delta2 = borrow2 - mean2;
+            n += 1.0;
+            mean1 += delta1 / n;
+            mean2 += delta2 / n;
+            moment1 += delta1 * (borrow - mean1);
+            moment2 += delta2 * (borrow2 - mean2);
+            comoment += delta1 * (borrow2 - mean2);
+        }
+        if iter.next().is_some() {
+            return f64::NAN;
+        }
+
+        if n > 1.0 {
+            (comoment / (moment1 * moment2).sqrt()).clamp(-1.0, 1.0)
+        } else {
+            f64::NAN
+        }
+    }
+
     fn quadratic_mean(self) -> f64 {
         let mut i = 0.0;
         let mut mean = 0.0;
@@ -246,6 +311,23 @@ mod tests {
     use crate::statistics::Statistics;
     use crate::generate::{InfinitePeriodic, InfiniteSinusoidal};
 
+    #[test]
+    fn test_sample_entropy() {
+        let uniform: Vec<f64> = (0..10_000).map(|i| i as f64 / 10_000.0).collect();
+        assert_almost_eq!((&uniform).sample_entropy(10), 10f64.ln(), 1e-12);
+        assert_almost_eq!((&uniform).sample_entropy(100), 100f64.ln(), 1e-12);
+
+        // two equally likely values, regardless of the number of bins
+        let coin = [0.0, 1.0, 0.0, 1.0, 1.0, 0.0];
+        assert_almost_eq!(coin.sample_entropy(7), 2f64.ln(), 1e-15);
+
+        assert_eq!([3.0, 3.0, 3.0].sample_entropy(5), 0.0);
+        assert!([1.0, 2.0].sample_entropy(0).is_nan());
+        assert!([1.0, f64::NAN].sample_entropy(2).is_nan());
+        assert!([1.0, f64::INFINITY].sample_entropy(2).is_nan());
+        assert!([f64::NEG_INFINITY, 1.0, 2.0].sample_entropy(2).is_nan());
+    }
+
     #[test]
     fn test_empty_data_returns_nan() {
         let data = [0.0; 0];
@@ -257,6 +339,29 @@ mod tests {
         assert!(data.population_variance().is_nan());
     }
 
+    #[test]
+    fn test_argmin_argmax() {
+        let data = [3.0, -1.0, 4.0, -1.0, 5.0, 5.0];
+        assert_eq!(data.argmin(), Some(1));
+        assert_eq!(data.argmax(), Some(4));
+        assert_eq!([2.0].argmin(), Some(0));
+        assert_eq!([2.0].argmax(), Some(0));
+        assert_eq!([0.0; 0].argmin(), None);
+        assert_eq!([0.0; 0].argmax(), None);
+        assert_eq!([f64::NEG_INFINITY, 0.0, f64::INFINITY].argmin(), Some(0));
+        assert_eq!([f64::NEG_INFINITY, 0.0, f64::INFINITY].argmax(), Some(2));
+        assert_eq!(data.iter().map(|x| -x).collect::<Vec<_>>().argmax(), Some(1));
+    }
+
+    #[test]
+    fn test_argmin_argmax_nan() {
+        let data = [1.0, f64::NAN, -5.0, f64::NAN, 7.0];
+        assert_eq!(data.argmin(), Some(1));
+        assert_eq!(data.argmax(), Some(1));
+        assert_eq!([f64::NAN, -5.0].argmin(), Some(0));
+        assert_eq!([f64::NAN, 5.0].argmax(), Some(0));
+    }
+
     // TODO: test github issue 137 (Math.NET)
 
     #[test]
@@ -269,6 +374,56 @@ mod tests {
         assert_almost_eq!((&longer).quadratic_mean(), (0.21875f64).sqrt(), 1e-14);
     }
 
+    #[test]
+    fn test_repeated_calls_on_slice() {
+        // shared references are Copy, so a slice can be queried repeatedly
+        let data: &[f64] = &[2.0, -1.0, 4.0, 3.0];
+        assert_eq!(data.min(), -1.0);
+        assert_eq!(data.max(), 4.0);
+        assert_eq!(data.mean(), 2.0);
+        assert_eq!(data.variance(), 14.0 / 3.0);
+        assert_eq!(data.std_dev(), (14f64 / 3.0).sqrt());
+        assert_eq!(data, &[2.0, -1.0, 4.0, 3.0]);
+
+        let owned = data.to_vec();
+        assert_eq!((&owned).mean(), (&owned).max() - 2.0);
+        assert!((&[] as &[f64]).mean().is_nan());
+    }
+
+    #[test]
+    fn test_pearson() {
+        let x = [1.0, 2.0, 4.0, 7.0, 11.0];
+        let up: Vec<f64> = x.iter().map(|v| 3.0 * v - 5.0).collect();
+        let down: Vec<f64> = x.iter().map(|v| 100.0 - 0.5 * v).collect();
+        assert_eq!(x.iter().pearson(up.iter()), 1.0);
+        assert_eq!(x.iter().pearson(down.iter()), -1.0);
+
+        // agrees with covariance over the standard deviations
+        let y = [2.0, -1.0, 3.5, 0.0, 8.0];
+        let expected = (&x).covariance(&y) / ((&x).std_dev() * (&y).std_dev());
+        assert_almost_eq!((&x).pearson(&y), expected, 1e-15);
+        assert_almost_eq!((&y).pearson(&x), expected, 1e-15);
+
+        assert!([1.0, f64::NAN, 3.0].iter().pearson([1.0, 2.0, 3.0].iter()).is_nan());
+        assert!((&[] as &[f64]).pearson(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_pearson_length_mismatch() {
+        assert!([1.0, 2.0, 3.0].iter().pearson([1.0, 2.0].iter()).is_nan());
+        assert!([1.0, 2.0].iter().pearson([1.0, 2.0, 3.0].iter()).is_nan());
+    }
+
+    #[test]
+    fn test_variance_large_offset() {
+        // the naive sum-of-squares formula loses every digit here
+        let data = [4.0, 7.0, 13.0, 16.0].map(|x: f64| x + 1e9);
+        assert_eq!((&data).mean(), 1e9 + 10.0);
+        assert_almost_eq!((&data).variance(), 30.0, 1e-12);
+        assert_almost_eq!((&data).std_dev(), 30f64.sqrt(), 1e-12);
+        assert!([1.0, f64::NAN, 2.0].iter().variance().is_nan());
+    }
+
     #[test]
     fn test_quadratic_mean_of_sinusoidal() {
         let data = InfiniteSinusoidal::default(64.0, 16.0, 2.0).take(128).collect::<Vec<f64>>();
@@ -0,0 +1,66 @@
+use crate::distribution::Continuous;
+
+/// Computes the cross-entropy of the model `dist` on `data`, the average
+/// negative log-likelihood per observation in nats.
+///
+/// Lower is better; comparing the cross-entropy of fitted models on the
+/// same held-out data ranks them by predictive fit.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `data` is empty, and `f64::INFINITY` if any
+/// observation lies outside the support of `dist`.
+///
+/// # Formula
+///
+/// ```text
+/// -(1 / n) Σ ln(f(x_i))
+/// ```
+///
+/// where `f` is the density of `dist`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Uniform;
+/// use statrs::statistics::cross_entropy;
+///
+/// let n = Uniform::new(0.0, 4.0).unwrap();
+/// assert_eq!(cross_entropy(&n, &[0.5, 1.0, 3.0]), 4f64.ln());
+/// assert_eq!(cross_entropy(&n, &[0.5, 5.0]), f64::INFINITY);
+/// ```
+pub fn cross_entropy<D: Continuous<f64, f64>>(dist: &D, data: &[f64]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    -data.iter().map(|&x| dist.ln_pdf(x)).sum::<f64>() / data.len() as f64
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{ContinuousCDF, Normal};
+    use crate::statistics::Distribution;
+
+    #[test]
+    fn test_well_fit_model_scores_lower() {
+        let truth = Normal::new(1.0, 2.0).unwrap();
+        // evenly spaced quantiles stand in for a large sample
+        let n = 10_000;
+        let data: Vec<f64> = (0..n).map(|i| truth.inverse_cdf((i as f64 + 0.5) / n as f64)).collect();
+
+        let fit = cross_entropy(&truth, &data);
+        // converges to the entropy of the true distribution
+        assert_almost_eq!(fit, truth.entropy().unwrap(), 1e-3);
+        for &(mean, std_dev) in &[(0.0, 2.0), (1.0, 1.0), (1.0, 4.0), (3.0, 0.5)] {
+            let mismatched = Normal::new(mean, std_dev).unwrap();
+            assert!(cross_entropy(&mismatched, &data) > fit);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(cross_entropy(&Normal::standard(), &[]).is_nan());
+    }
+}
@@ -1,12 +1,35 @@
 //! Provides traits for statistical computation
 
+pub use self::ecdf::*;
+pub use self::efficiency::*;
+pub use self::extremes::*;
+#[cfg(feature = "rand")]
+pub use self::importance::*;
+pub use self::inequality::*;
+pub use self::likelihood::*;
+pub use self::moments::*;
 pub use self::order_statistics::*;
+pub use self::running::*;
+pub use self::series::*;
 pub use self::slice_statistics::*;
 pub use self::statistics::*;
 pub use self::traits::*;
 
+mod ecdf;
+mod efficiency;
+mod extremes;
+pub mod gof;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod importance;
+mod inequality;
+pub mod inference;
 mod iter_statistics;
+mod likelihood;
+mod moments;
 mod order_statistics;
+mod running;
+mod series;
 // TODO: fix later
 mod slice_statistics;
 #[allow(clippy::module_inception)]
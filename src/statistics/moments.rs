@@ -0,0 +1,227 @@
+use crate::statistics::{Distribution, Statistics};
+
+/// Computes the skewness from the first three raw moments
+/// `m1 = E[X]`, `m2 = E[X^2]` and `m3 = E[X^3]`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if the implied variance is zero or negative
+///
+/// # Formula
+///
+/// ```text
+/// (m3 - 3 * m1 * m2 + 2 * m1^3) / (m2 - m1^2)^(3 / 2)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::skewness_from_moments;
+///
+/// // raw moments of the exponential distribution with rate 1
+/// assert_eq!(skewness_from_moments(1.0, 2.0, 6.0), 2.0);
+/// ```
+pub fn skewness_from_moments(m1: f64, m2: f64, m3: f64) -> f64 {
+    let var = m2 - m1 * m1;
+    if var <= 0.0 {
+        return f64::NAN;
+    }
+    let mu3 = m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1;
+    mu3 / (var * var.sqrt())
+}
+
+/// Computes the (non-excess) kurtosis from the first four raw moments
+/// `m1 = E[X]`, `m2 = E[X^2]`, `m3 = E[X^3]` and `m4 = E[X^4]`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if the implied variance is zero or negative.
+/// Subtract `3` to obtain the excess kurtosis.
+///
+/// # Formula
+///
+/// ```text
+/// (m4 - 4 * m1 * m3 + 6 * m1^2 * m2 - 3 * m1^4) / (m2 - m1^2)^2
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::kurtosis_from_moments;
+///
+/// // raw moments of the standard normal distribution
+/// assert_eq!(kurtosis_from_moments(0.0, 1.0, 0.0, 3.0), 3.0);
+/// ```
+pub fn kurtosis_from_moments(m1: f64, m2: f64, m3: f64, m4: f64) -> f64 {
+    let var = m2 - m1 * m1;
+    if var <= 0.0 {
+        return f64::NAN;
+    }
+    let m1_sq = m1 * m1;
+    let mu4 = m4 - 4.0 * m1 * m3 + 6.0 * m1_sq * m2 - 3.0 * m1_sq * m1_sq;
+    mu4 / (var * var)
+}
+
+/// The differences between the moments of a distribution and the sample
+/// moments of some data, as returned by [`moment_match_report`].
+///
+/// Each field is the sample moment minus the distribution's moment,
+/// divided by the absolute value of the distribution's moment, or
+/// undivided if that moment is zero. A field is `None` if the
+/// distribution's moment does not exist.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MomentReport {
+    /// The difference in means
+    pub mean: Option<f64>,
+    /// The difference between the variance and the unbiased sample variance
+    pub variance: Option<f64>,
+    /// The difference between the skewness and the sample skewness
+    /// `m3 / m2^(3 / 2)` of the central sample moments
+    pub skewness: Option<f64>,
+    /// The difference between the excess kurtosis and the sample excess
+    /// kurtosis `m4 / m2^2 - 3` of the central sample moments
+    pub excess_kurtosis: Option<f64>,
+}
+
+/// Compares the mean, variance, skewness and excess kurtosis of `dist` with
+/// the sample estimates from `data`, as a quick numeric sense of how well
+/// `dist` fits the data.
+///
+/// # Remarks
+///
+/// The differences are `NaN` if `data` has too few values for the
+/// corresponding sample moment, i.e. none for the mean, fewer than two
+/// for the variance, or if it has no spread, for the skewness and excess
+/// kurtosis.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Uniform;
+/// use statrs::statistics::moment_match_report;
+///
+/// let n = Uniform::new(0.0, 4.0).unwrap();
+/// let report = moment_match_report(&n, &[0.0, 1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(report.mean, Some(0.0));
+/// // a sample variance of 2.5 against 4 / 3
+/// assert!((report.variance.unwrap() - 0.875).abs() < 1e-15);
+/// assert_eq!(report.skewness, Some(0.0));
+/// // a sample excess kurtosis of -1.3 against -1.2
+/// assert!((report.excess_kurtosis.unwrap() + 1.0 / 12.0).abs() < 1e-15);
+/// ```
+pub fn moment_match_report<D: Distribution<f64>>(dist: &D, data: &[f64]) -> MomentReport {
+    let relative = |sample: f64, analytic: f64| {
+        if analytic == 0.0 {
+            sample
+        } else {
+            (sample - analytic) / analytic.abs()
+        }
+    };
+
+    let mean = Statistics::mean(data);
+    let (m2, m3, m4) = data.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), x| {
+        let d = x - mean;
+        let d2 = d * d;
+        (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+    });
+    let n = data.len() as f64;
+    let (m2, m3, m4) = (m2 / n, m3 / n, m4 / n);
+    MomentReport {
+        mean: dist.mean().map(|m| relative(mean, m)),
+        variance: dist
+            .variance()
+            .map(|v| relative(Statistics::variance(data), v)),
+        skewness: dist.skewness().map(|s| relative(m3 / (m2 * m2.sqrt()), s)),
+        excess_kurtosis: dist
+            .excess_kurtosis()
+            .map(|k| relative(m4 / (m2 * m2) - 3.0, k)),
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Exp, Normal};
+    use crate::statistics::Distribution;
+
+    #[test]
+    fn test_normal_moments() {
+        for &(mean, std_dev) in &[(0.0, 1.0), (1.5, 0.5), (-3.0, 2.0)] {
+            let n = Normal::new(mean, std_dev).unwrap();
+            let (mu, s2) = (mean, std_dev * std_dev);
+            let m1 = mu;
+            let m2 = mu * mu + s2;
+            let m3 = mu * mu * mu + 3.0 * mu * s2;
+            let m4 = mu.powi(4) + 6.0 * mu * mu * s2 + 3.0 * s2 * s2;
+
+            assert_almost_eq!(skewness_from_moments(m1, m2, m3), n.skewness().unwrap(), 1e-12);
+            assert_almost_eq!(kurtosis_from_moments(m1, m2, m3, m4), 3.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_exp_moments() {
+        // E[X^k] = k! / λ^k
+        let rate: f64 = 2.0;
+        let n = Exp::new(rate).unwrap();
+        let (m1, m2, m3, m4) = (1.0 / rate, 2.0 / rate.powi(2), 6.0 / rate.powi(3), 24.0 / rate.powi(4));
+
+        assert_almost_eq!(skewness_from_moments(m1, m2, m3), n.skewness().unwrap(), 1e-12);
+        assert_almost_eq!(kurtosis_from_moments(m1, m2, m3, m4), 9.0, 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate_moments() {
+        assert!(skewness_from_moments(1.0, 1.0, 1.0).is_nan());
+        assert!(kurtosis_from_moments(1.0, 1.0, 1.0, 1.0).is_nan());
+        assert!(skewness_from_moments(2.0, 1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_moment_match_report_own_samples() {
+        use crate::distribution::Gamma;
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        let n = Normal::new(2.0, 3.0).unwrap();
+        let data: Vec<f64> = n.sample_iter(&mut rng).take(100_000).collect();
+        let report = moment_match_report(&n, &data);
+        assert!(report.mean.unwrap().abs() < 0.01);
+        assert!(report.variance.unwrap().abs() < 0.01);
+        // the normal skewness is zero, so this is the sample skewness itself
+        assert!(report.skewness.unwrap().abs() < 0.02);
+        assert!(report.excess_kurtosis.unwrap().abs() < 0.05);
+
+        let g = Gamma::new(2.0, 0.5).unwrap();
+        let data: Vec<f64> = g.sample_iter(&mut rng).take(100_000).collect();
+        let report = moment_match_report(&g, &data);
+        assert!(report.mean.unwrap().abs() < 0.01);
+        assert!(report.variance.unwrap().abs() < 0.02);
+        assert!(report.skewness.unwrap().abs() < 0.05);
+        assert!(report.excess_kurtosis.unwrap().abs() < 0.1);
+
+        // a poor fit shows up
+        let report = moment_match_report(&Exp::new(1.0).unwrap(), &data);
+        assert!(report.mean.unwrap() > 2.9);
+    }
+
+    #[test]
+    fn test_moment_match_report_missing_moments() {
+        use crate::distribution::{Cauchy, StudentsT};
+
+        let data = [1.0, 2.0, 4.0];
+        let report = moment_match_report(&Cauchy::new(0.0, 1.0).unwrap(), &data);
+        assert_eq!(report, MomentReport { mean: None, variance: None, skewness: None, excess_kurtosis: None });
+        let report = moment_match_report(&StudentsT::new(0.0, 1.0, 1.5).unwrap(), &data);
+        assert_eq!(report.mean, Some(7.0 / 3.0));
+        assert!(report.variance.is_none());
+
+        let report = moment_match_report(&Exp::new(1.0).unwrap(), &[1.0]);
+        assert_eq!(report.mean, Some(0.0));
+        assert!(report.variance.unwrap().is_nan());
+        assert!(report.skewness.unwrap().is_nan());
+        assert!(report.excess_kurtosis.unwrap().is_nan());
+    }
+}
@@ -0,0 +1,166 @@
+/// Accumulates the sample mean vector and covariance matrix of a stream of
+/// fixed-length observations without storing them.
+///
+/// Each call to [`push`](RunningCovariance::push) applies the multivariate
+/// Welford update, which stays numerically stable when the observations have
+/// a large mean relative to their spread.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::RunningCovariance;
+///
+/// let mut acc = RunningCovariance::new(2);
+/// acc.push(&[1.0, 2.0]);
+/// acc.push(&[3.0, 0.0]);
+/// assert_eq!(acc.mean(), [2.0, 1.0]);
+/// assert_eq!(acc.covariance(), [[2.0, -2.0], [-2.0, 2.0]]);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct RunningCovariance {
+    n: usize,
+    mean: Vec<f64>,
+    // sum of outer products of deviations from the mean, row-major
+    comoment: Vec<f64>,
+}
+
+impl RunningCovariance {
+    /// Constructs an empty accumulator for observations of length `dim`
+    pub fn new(dim: usize) -> Self {
+        RunningCovariance {
+            n: 0,
+            mean: vec![0.0; dim],
+            comoment: vec![0.0; dim * dim],
+        }
+    }
+
+    /// Adds the observation `x` to the accumulator
+    ///
+    /// # Panics
+    ///
+    /// If `x` does not have the dimension the accumulator was created with
+    pub fn push(&mut self, x: &[f64]) {
+        let dim = self.dim();
+        assert_eq!(x.len(), dim, "observation has the wrong dimension");
+        self.n += 1;
+        let n = self.n as f64;
+        let delta: Vec<f64> = x.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        for (m, d) in self.mean.iter_mut().zip(&delta) {
+            *m += d / n;
+        }
+        // (x - old mean)(x - new mean)^T, written so the update is symmetric
+        let scale = (n - 1.0) / n;
+        for (i, di) in delta.iter().enumerate() {
+            let row = &mut self.comoment[i * dim..(i + 1) * dim];
+            for (c, dj) in row.iter_mut().zip(&delta) {
+                *c += scale * (di * dj);
+            }
+        }
+    }
+
+    /// Returns the number of observations pushed so far
+    pub fn count(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the dimension of the observations
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the sample mean of each component
+    ///
+    /// # Remarks
+    ///
+    /// Returns a vector of `f64::NAN` if no observations have been pushed
+    pub fn mean(&self) -> Vec<f64> {
+        if self.n == 0 {
+            vec![f64::NAN; self.dim()]
+        } else {
+            self.mean.clone()
+        }
+    }
+
+    /// Returns the unbiased sample covariance matrix, with an `N-1`
+    /// normalizer
+    ///
+    /// # Remarks
+    ///
+    /// Every entry is `f64::NAN` if fewer than two observations have been
+    /// pushed
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        let dim = self.dim();
+        if self.n < 2 {
+            return vec![vec![f64::NAN; dim]; dim];
+        }
+        let denom = (self.n - 1) as f64;
+        self.comoment
+            .chunks(dim.max(1))
+            .take(dim)
+            .map(|row| row.iter().map(|c| c / denom).collect())
+            .collect()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::RunningCovariance;
+    use crate::statistics::Statistics;
+
+    #[test]
+    fn test_matches_batch() {
+        let data = [
+            [1.0, 2.0, -3.0],
+            [4.5, -1.0, 0.0],
+            [2.0, 2.5, 7.0],
+            [-0.5, 3.0, 1.0],
+            [3.0, 0.0, 2.0],
+        ];
+        let mut acc = RunningCovariance::new(3);
+        for x in &data {
+            acc.push(x);
+        }
+        assert_eq!(acc.count(), 5);
+        assert_eq!(acc.dim(), 3);
+
+        let column = |j: usize| data.iter().map(|x| x[j]).collect::<Vec<f64>>();
+        let mean = acc.mean();
+        let cov = acc.covariance();
+        for (i, row) in cov.iter().enumerate() {
+            assert_almost_eq!(mean[i], column(i).mean(), 1e-14);
+            for (j, &c) in row.iter().enumerate() {
+                assert_almost_eq!(c, column(i).covariance(column(j)), 1e-13);
+                assert_eq!(c, cov[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_offset() {
+        let mut acc = RunningCovariance::new(2);
+        for &(a, b) in &[(1e9 + 4.0, 1e9 + 1.0), (1e9 + 7.0, 1e9 - 1.0), (1e9 + 13.0, 1e9 + 3.0), (1e9 + 16.0, 1e9 + 1.0)] {
+            acc.push(&[a, b]);
+        }
+        let cov = acc.covariance();
+        assert_almost_eq!(cov[0][0], 30.0, 1e-9);
+        assert_almost_eq!(cov[1][1], 8.0 / 3.0, 1e-9);
+        assert_almost_eq!(cov[0][1], 4.0, 1e-9);
+    }
+
+    #[test]
+    fn test_too_few_observations() {
+        let mut acc = RunningCovariance::new(2);
+        assert!(acc.mean().iter().all(|m| m.is_nan()));
+        acc.push(&[1.0, 2.0]);
+        assert_eq!(acc.mean(), [1.0, 2.0]);
+        assert!(acc.covariance().iter().flatten().all(|c| c.is_nan()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrong_dimension() {
+        let mut acc = RunningCovariance::new(2);
+        acc.push(&[1.0, 2.0, 3.0]);
+    }
+}
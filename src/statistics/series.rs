@@ -0,0 +1,177 @@
+/// Returns the sample autocorrelation function of `data` at lags
+/// `0..=max_lag`, so the first entry is always `1`.
+///
+/// # Remarks
+///
+/// Lags beyond `data.len() - 1` have no overlapping pairs and are left out,
+/// so the result may be shorter than `max_lag + 1`. Returns an empty vector
+/// if `data` is empty, contains a `NaN`, or has no spread.
+///
+/// # Formula
+///
+/// ```text
+/// r_k = Σ_{t=1}^{n-k} (x_t - x̄)(x_{t+k} - x̄) / Σ_{t=1}^{n} (x_t - x̄)^2
+/// ```
+///
+/// Both sums use the full-sample mean and the denominator is not adjusted
+/// for the shrinking number of pairs, which keeps the estimate positive
+/// semi-definite at the cost of a bias toward zero at long lags.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::autocorrelation;
+///
+/// let acf = autocorrelation(&[1.0, 2.0, 3.0, 4.0], 2);
+/// assert_eq!(acf, vec![1.0, 0.25, -0.3]);
+/// ```
+pub fn autocorrelation(data: &[f64], max_lag: usize) -> Vec<f64> {
+    match centered(data) {
+        Some((deviations, denom)) => (0..=max_lag.min(data.len() - 1))
+            .map(|k| lag_product(&deviations, k) / denom)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns `data` less its mean together with the sum of squared
+/// deviations, or `None` if `data` is empty, contains a `NaN`, or has no
+/// spread
+fn centered(data: &[f64]) -> Option<(Vec<f64>, f64)> {
+    if data.is_empty() || data.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let mean = data.iter().sum::<f64>() / data.len() as f64;
+    let centered: Vec<f64> = data.iter().map(|x| x - mean).collect();
+    let denom: f64 = centered.iter().map(|x| x * x).sum();
+    if denom <= 0.0 || !denom.is_finite() {
+        return None;
+    }
+    Some((centered, denom))
+}
+
+/// Sums the products of the centered values `k` steps apart
+fn lag_product(centered: &[f64], k: usize) -> f64 {
+    centered
+        .iter()
+        .zip(&centered[k..])
+        .map(|(a, b)| a * b)
+        .sum()
+}
+
+/// Returns the effective sample size of the correlated draws in `data`,
+/// the number of independent draws that would estimate the mean about as
+/// precisely.
+///
+/// # Remarks
+///
+/// The integrated autocorrelation time sums the sample autocorrelations
+/// from lag one up to, but not including, the first lag where they turn
+/// negative, at which point the remainder is assumed to be noise. The
+/// result is capped at `data.len()`. Returns `f64::NAN` if `data` is empty,
+/// contains a `NaN`, or has no spread.
+///
+/// # Formula
+///
+/// ```text
+/// n / (1 + 2 Σ_{k=1}^{K} r_k)
+/// ```
+///
+/// where `r_k` is the [`autocorrelation`] at lag `k` and `K` is the last
+/// lag before the first negative `r_k`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::effective_sample_size;
+///
+/// // alternating draws are anticorrelated, so they count as n
+/// let data = [1.0, -1.0, 1.0, -1.0, 1.0, -1.0];
+/// assert_eq!(effective_sample_size(&data), 6.0);
+/// ```
+pub fn effective_sample_size(data: &[f64]) -> f64 {
+    let (deviations, denom) = match centered(data) {
+        Some(centered) => centered,
+        None => return f64::NAN,
+    };
+    // lags are computed one at a time, up to the first negative one
+    let positive: f64 = (1..data.len())
+        .map(|k| lag_product(&deviations, k) / denom)
+        .take_while(|r| *r >= 0.0)
+        .sum();
+    let tau = 1.0 + 2.0 * positive;
+    let n = data.len() as f64;
+    (n / tau).min(n)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_series() {
+        let acf = autocorrelation(&[1.0, 2.0, 3.0, 4.0], 10);
+        assert_eq!(acf.len(), 4);
+        assert_eq!(acf[0], 1.0);
+        assert_almost_eq!(acf[1], 0.25, 1e-15);
+        assert_almost_eq!(acf[2], -0.3, 1e-15);
+        assert_almost_eq!(acf[3], -0.45, 1e-15);
+    }
+
+    #[test]
+    fn test_trend() {
+        let data: Vec<f64> = (0..200).map(|t| t as f64).collect();
+        assert!(autocorrelation(&data, 1)[1] > 0.95);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_iid() {
+        use crate::distribution::Normal;
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let data: Vec<f64> = Normal::standard().sample_iter(StdRng::seed_from_u64(1005)).take(5000).collect();
+        let acf = autocorrelation(&data, 20);
+        // the standard error at each lag is about 1 / √n ≈ 0.014
+        for r in &acf[1..] {
+            assert!(r.abs() < 0.06, "lag autocorrelation {} too large", r);
+        }
+    }
+
+    #[test]
+    fn test_bad_data() {
+        assert!(autocorrelation(&[], 3).is_empty());
+        assert!(autocorrelation(&[1.0, f64::NAN], 1).is_empty());
+        assert!(autocorrelation(&[2.0, 2.0, 2.0], 1).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_effective_sample_size() {
+        use crate::distribution::Normal;
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+        let n = 5000;
+        let noise: Vec<f64> = Normal::standard().sample_iter(StdRng::seed_from_u64(1006)).take(n).collect();
+        let iid_ess = effective_sample_size(&noise);
+        assert!(iid_ess > 0.8 * n as f64, "iid ess {}", iid_ess);
+
+        // AR(1) with φ = 0.9 has τ = (1 + φ) / (1 - φ) = 19
+        let mut ar = Vec::with_capacity(n);
+        let mut x = 0.0;
+        for e in &noise {
+            x = 0.9 * x + e;
+            ar.push(x);
+        }
+        let ar_ess = effective_sample_size(&ar);
+        assert!(ar_ess > n as f64 / 40.0 && ar_ess < n as f64 / 10.0, "ar(1) ess {}", ar_ess);
+    }
+
+    #[test]
+    fn test_effective_sample_size_bad_data() {
+        assert!(effective_sample_size(&[]).is_nan());
+        assert!(effective_sample_size(&[1.0, f64::NAN]).is_nan());
+        assert!(effective_sample_size(&[2.0, 2.0]).is_nan());
+    }
+}
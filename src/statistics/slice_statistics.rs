@@ -1,3 +1,4 @@
+use crate::function::beta;
 use crate::statistics::*;
 use core::ops::{Index, IndexMut};
 
@@ -64,6 +65,59 @@ impl<D: AsMut<[f64]> + AsRef<[f64]>> Data<D> {
         self.0.as_ref().iter()
     }
 
+    /// Estimates the tau-th quantile from the data with the Harrell-Davis
+    /// estimator, a weighted average of all the order statistics.
+    ///
+    /// The `i`-th smallest of `n` values has weight
+    /// `I_(i/n)(a, b) - I_((i-1)/n)(a, b)`, where `I` is the regularized
+    /// incomplete beta function, `a = tau (n + 1)` and `b = (1 - tau) (n + 1)`.
+    /// This is smoother than [`quantile`](OrderStatistics::quantile) and has
+    /// lower variance for small samples.
+    ///
+    /// # Remarks
+    ///
+    /// Sorts the data. Tau must be between `0` and `1` inclusive, the
+    /// extremes giving the minimum and maximum. Returns `f64::NAN` if data
+    /// is empty or tau is outside the inclusive range.
+    ///
+    /// # Panics
+    ///
+    /// If the data contains `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Data;
+    ///
+    /// let mut x = Data::new([3.0, 1.0, 2.0]);
+    /// assert!((x.harrell_davis_quantile(0.5) - 2.0).abs() < 1e-15);
+    /// assert!(x.harrell_davis_quantile(1.5).is_nan());
+    /// ```
+    pub fn harrell_davis_quantile(&mut self, tau: f64) -> f64 {
+        if !(0.0..=1.0).contains(&tau) || self.is_empty() {
+            return f64::NAN;
+        }
+        if tau == 0.0 {
+            return self.min();
+        }
+        if tau == 1.0 {
+            return self.max();
+        }
+
+        let data = self.0.as_mut();
+        data.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = data.len() as f64;
+        let a = tau * (n + 1.0);
+        let b = (1.0 - tau) * (n + 1.0);
+        let mut prev = 0.0;
+        data.iter().enumerate().fold(0.0, |acc, (i, &x)| {
+            let next = beta::beta_reg(a, b, (i + 1) as f64 / n);
+            let weight = next - prev;
+            prev = next;
+            acc + weight * x
+        })
+    }
+
     // Selection algorithm from Numerical Recipes
     // See: https://en.wikipedia.org/wiki/Selection_algorithm
     fn select_inplace(&mut self, rank: usize) -> f64 {
@@ -445,6 +499,61 @@ mod tests {
         assert_almost_eq!(data.quantile(0.325), -37.0 / 240.0, 1e-15);
     }
 
+    #[test]
+    fn test_harrell_davis_quantile_short() {
+        // the weights of three values at the median are 7/27, 13/27, 7/27
+        let mut data = Data::new([10.0, 1.0, 2.0]);
+        assert_almost_eq!(data.harrell_davis_quantile(0.5), 103.0 / 27.0, 1e-14);
+        assert_eq!(data.harrell_davis_quantile(0.0), 1.0);
+        assert_eq!(data.harrell_davis_quantile(1.0), 10.0);
+        assert!(data.harrell_davis_quantile(-0.1).is_nan());
+        assert!(Data::new([0.0; 0]).harrell_davis_quantile(0.5).is_nan());
+        assert_eq!(Data::new([4.0]).harrell_davis_quantile(0.3), 4.0);
+        // increasing in tau
+        let mut data = Data::new([-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0]);
+        let q: Vec<f64> = (1..20)
+            .map(|i| data.harrell_davis_quantile(i as f64 / 20.0))
+            .collect();
+        assert!(q.windows(2).all(|w| w[0] < w[1]));
+        // every value carries some weight, unlike with the linear estimate
+        let mut data = Data::new([f64::NEG_INFINITY, 1.0, 2.0, 3.0, f64::INFINITY]);
+        assert!(data.harrell_davis_quantile(0.5).is_nan());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_harrell_davis_quantile_sampled() {
+        use crate::distribution::Normal;
+        use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1600);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        // agrees with the linear estimate on a large sample
+        let mut data = Data::new(
+            normal
+                .sample_iter(&mut rng)
+                .take(10_000)
+                .collect::<Vec<_>>(),
+        );
+        for &tau in &[0.05, 0.25, 0.5, 0.9] {
+            let hd = data.harrell_davis_quantile(tau);
+            let linear = data.quantile(tau);
+            assert_almost_eq!(hd, linear, 0.01);
+        }
+
+        // and varies less across small samples
+        let (mut hd, mut linear) = (Vec::new(), Vec::new());
+        for _ in 0..2000 {
+            let mut data = Data::new(normal.sample_iter(&mut rng).take(10).collect::<Vec<_>>());
+            hd.push(data.harrell_davis_quantile(0.75));
+            linear.push(data.quantile(0.75));
+        }
+        let hd_var = hd.variance();
+        let linear_var = linear.variance();
+        assert!(hd_var < 0.95 * linear_var, "{} {}", hd_var, linear_var);
+    }
+
     #[test]
     fn test_ranks() {
         let sorted_distinct = [1.0, 2.0, 4.0, 7.0, 8.0, 9.0, 10.0, 12.0];
@@ -108,6 +108,56 @@ pub trait Statistics<T> {
     /// ```
     fn abs_max(self) -> T;
 
+    /// Returns the index of the minimum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if data is empty. Ties resolve to the first
+    /// occurrence, and if an entry is `f64::NAN` the index of the first
+    /// `NaN` is returned, matching `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: &[f64] = &[];
+    /// assert_eq!(x.argmin(), None);
+    ///
+    /// let y = &[0.0, f64::NAN, 3.0, -2.0];
+    /// assert_eq!(y.argmin(), Some(1));
+    ///
+    /// let z = &[0.0, -2.0, 3.0, -2.0];
+    /// assert_eq!(z.argmin(), Some(1));
+    /// ```
+    fn argmin(self) -> Option<usize>;
+
+    /// Returns the index of the maximum value in the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if data is empty. Ties resolve to the first
+    /// occurrence, and if an entry is `f64::NAN` the index of the first
+    /// `NaN` is returned, matching `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: &[f64] = &[];
+    /// assert_eq!(x.argmax(), None);
+    ///
+    /// let y = &[0.0, f64::NAN, 3.0, -2.0];
+    /// assert_eq!(y.argmax(), Some(1));
+    ///
+    /// let z = &[0.0, 3.0, -2.0, 3.0];
+    /// assert_eq!(z.argmax(), Some(1));
+    /// ```
+    fn argmax(self) -> Option<usize>;
+
     /// Evaluates the sample mean, an estimate of the population
     /// mean.
     ///
@@ -396,6 +446,57 @@ pub trait Statistics<T> {
     /// ```
     fn population_covariance(self, other: Self) -> T;
 
+    /// Evaluates the Pearson correlation coefficient between the two provided
+    /// samples, their covariance divided by the product of their standard
+    /// deviations
+    ///
+    /// # Remarks
+    ///
+    /// The result is clamped to `[-1, 1]` to absorb rounding.
+    ///
+    /// Returns `f64::NAN` if data has less than two entries, if the two
+    /// samples differ in length, if either sample has no spread or if any
+    /// entry is `f64::NAN`
+    ///
+    /// The default implementation divides the [`covariance`](Self::covariance)
+    /// by the product of the [`std_dev`](Self::std_dev)s, so a length
+    /// mismatch is handled as by `covariance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x = &[1.0];
+    /// assert!(x.pearson(&[2.0]).is_nan());
+    ///
+    /// let y1 = &[0.0, 1.0, 2.0, 3.0];
+    /// let y2 = &[1.0, 3.0, 5.0, 7.0];
+    /// assert_eq!(y1.pearson(y2), 1.0);
+    ///
+    /// let z1 = &[0.0, 3.0, -2.0];
+    /// let z2 = &[2.0, 2.0, 2.0];
+    /// assert!(z1.pearson(z2).is_nan());
+    ///
+    /// let (y1, z1): (&[f64], &[f64]) = (y1, z1);
+    /// assert!(y1.pearson(z1).is_nan());
+    /// ```
+    fn pearson(self, other: Self) -> T
+    where
+        Self: Sized + Clone,
+        T: num_traits::Float,
+    {
+        let r = self.clone().covariance(other.clone()) / (self.std_dev() * other.std_dev());
+        if r > T::one() {
+            T::one()
+        } else if r < -T::one() {
+            -T::one()
+        } else {
+            r
+        }
+    }
+
     /// Estimates the quadratic mean (Root Mean Square) of the data
     ///
     /// # Remarks
@@ -424,4 +525,69 @@ pub trait Statistics<T> {
     /// # }
     /// ```
     fn quadratic_mean(self) -> T;
+
+    /// Estimates the Shannon entropy, in nats, of the distribution the data
+    /// was drawn from by binning it into an equal-width histogram with `bins`
+    /// bins spanning the range of the data and computing the entropy of the
+    /// bin proportions (the plug-in estimator)
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty, `bins` is zero, or any entry is
+    /// `f64::NAN` or infinite. Returns `0.0` if every entry is equal.
+    ///
+    /// The estimate is biased downward: it can never exceed `ln(bins)`, and
+    /// with few samples per bin the empirical proportions understate the
+    /// spread of the underlying distribution. It is also the entropy of the
+    /// discretized data rather than the differential entropy of the
+    /// underlying continuous distribution, so it depends on the choice of
+    /// `bins`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: &[f64] = &[];
+    /// assert!(x.sample_entropy(4).is_nan());
+    ///
+    /// let y = &[0.0, 1.0, 2.0, 3.0];
+    /// assert_eq!(y.sample_entropy(4), 4f64.ln());
+    /// ```
+    fn sample_entropy(self, bins: usize) -> T
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: std::borrow::Borrow<f64>,
+        T: num_traits::Float,
+    {
+        use std::borrow::Borrow;
+
+        let data: Vec<f64> = self.into_iter().map(|x| *x.borrow()).collect();
+        if data.is_empty() || bins == 0 || data.iter().any(|x| !x.is_finite()) {
+            return T::nan();
+        }
+        let lo = data.iter().fold(f64::INFINITY, |acc, &x| acc.min(x));
+        let hi = data.iter().fold(f64::NEG_INFINITY, |acc, &x| acc.max(x));
+        let width = (hi - lo) / bins as f64;
+        if width == 0.0 {
+            return T::zero();
+        }
+        let mut counts = vec![0usize; bins];
+        for x in &data {
+            // the maximum lands on the upper edge and belongs to the last bin
+            let i = (((x - lo) / width) as usize).min(bins - 1);
+            counts[i] += 1;
+        }
+        let n = data.len() as f64;
+        let entropy: f64 = counts
+            .iter()
+            .filter(|&&c| c > 0)
+            .map(|&c| {
+                let p = c as f64 / n;
+                -p * p.ln()
+            })
+            .sum();
+        T::from(entropy).unwrap()
+    }
 }
@@ -113,6 +113,21 @@ pub trait Distribution<T: Float> {
     fn entropy(&self) -> Option<T> {
         None
     }
+    /// Returns the entropy in bits (base 2) rather than nats, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 4.0).unwrap();
+    /// assert_eq!(2.0, n.entropy_bits().unwrap());
+    /// ```
+    fn entropy_bits(&self) -> Option<T> {
+        let ln_2 = T::from(crate::consts::LN_2).unwrap();
+        self.entropy().map(|h| h / ln_2)
+    }
     /// Returns the skewness, if it exists.
     ///
     /// # Examples
@@ -127,6 +142,242 @@ pub trait Distribution<T: Float> {
     fn skewness(&self) -> Option<T> {
         None
     }
+    /// Returns the excess kurtosis, i.e. the kurtosis minus `3`, if it
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(-1.2, n.excess_kurtosis().unwrap());
+    /// ```
+    fn excess_kurtosis(&self) -> Option<T> {
+        None
+    }
+    /// Returns whether the raw moment `E[X^order]` is finite.
+    ///
+    /// The default implementation only inspects the moments the
+    /// distribution reports: order `0` always exists, orders `1` to `4`
+    /// exist if the mean, variance, skewness or excess kurtosis
+    /// respectively is reported and finite, and higher orders are assumed
+    /// not to. It can therefore report `false` for a moment that exists, so
+    /// every distribution in this crate whose moments are known overrides
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::{Cauchy, StudentsT};
+    ///
+    /// let n = StudentsT::new(0.0, 1.0, 3.0).unwrap();
+    /// assert!(n.moment_exists(2));
+    /// assert!(!n.moment_exists(3));
+    ///
+    /// let n = Cauchy::new(0.0, 1.0).unwrap();
+    /// assert!(!n.moment_exists(1));
+    /// ```
+    fn moment_exists(&self, order: u32) -> bool {
+        let moment = match order {
+            0 => return true,
+            1 => self.mean(),
+            2 => self.variance(),
+            3 => self.skewness(),
+            4 => self.excess_kurtosis(),
+            _ => None,
+        };
+        matches!(moment, Some(m) if m.is_finite())
+    }
+    /// Classifies the weight of the tails by the sign of the [excess
+    /// kurtosis](Distribution::excess_kurtosis), if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Distribution, TailType};
+    /// use statrs::distribution::{Laplace, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert_eq!(Some(TailType::Mesokurtic), n.tail_classification());
+    ///
+    /// let n = Laplace::new(0.0, 1.0).unwrap();
+    /// assert_eq!(Some(TailType::Leptokurtic), n.tail_classification());
+    /// ```
+    fn tail_classification(&self) -> Option<TailType> {
+        let kurtosis = self.excess_kurtosis()?;
+        if kurtosis > T::zero() {
+            Some(TailType::Leptokurtic)
+        } else if kurtosis < T::zero() {
+            Some(TailType::Platykurtic)
+        } else if kurtosis.is_zero() {
+            Some(TailType::Mesokurtic)
+        } else {
+            None
+        }
+    }
+    /// Returns the coefficient of variation `std_dev / mean`, if the mean and
+    /// standard deviation exist.
+    ///
+    /// This is infinite when the mean is zero and the standard deviation
+    /// is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 2.0).unwrap();
+    /// assert_eq!((1f64 / 3f64).sqrt(), n.coefficient_of_variation().unwrap());
+    /// ```
+    fn coefficient_of_variation(&self) -> Option<T> {
+        Some(self.std_dev()? / self.mean()?)
+    }
+    /// Returns the signal-to-noise ratio `mean / std_dev`, the reciprocal of
+    /// the [coefficient of
+    /// variation](Distribution::coefficient_of_variation), if the mean and
+    /// standard deviation exist.
+    ///
+    /// Returns `INFINITY` if the standard deviation is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::{Dirac, Normal};
+    ///
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// assert_eq!(5.0, n.signal_to_noise_ratio().unwrap());
+    ///
+    /// let n = Dirac::new(3.0).unwrap();
+    /// assert_eq!(f64::INFINITY, n.signal_to_noise_ratio().unwrap());
+    /// ```
+    fn signal_to_noise_ratio(&self) -> Option<T> {
+        let mean = self.mean()?;
+        let std_dev = self.std_dev()?;
+        if std_dev.is_zero() {
+            Some(T::infinity())
+        } else {
+            Some(mean / std_dev)
+        }
+    }
+    /// Returns the normal approximation, by the central limit theorem, to
+    /// the distribution of the sum of `n` independent draws, i.e. a normal
+    /// distribution with mean `n * mean` and variance `n * variance`.
+    ///
+    /// Returns `None` if `n` is zero, or the mean or variance do not exist,
+    /// are not finite, or the variance is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::{Cauchy, Uniform};
+    ///
+    /// let sum = Uniform::new(0.0, 1.0).unwrap().clt_approximation(12).unwrap();
+    /// assert_eq!(sum.mean().unwrap(), 6.0);
+    /// assert!((sum.variance().unwrap() - 1.0).abs() < 1e-15);
+    /// assert!(Cauchy::new(0.0, 1.0).unwrap().clt_approximation(12).is_none());
+    /// ```
+    fn clt_approximation(&self, n: usize) -> Option<crate::distribution::Normal> {
+        let mean = self.mean()?.to_f64()?;
+        let std_dev = self.std_dev()?.to_f64()?;
+        if n == 0 || !mean.is_finite() || !std_dev.is_finite() {
+            return None;
+        }
+        let n = n as f64;
+        crate::distribution::Normal::new(n * mean, n.sqrt() * std_dev).ok()
+    }
+    /// Returns a distribution whose samples are the samples of `self`
+    /// transformed by `f`.
+    ///
+    /// Named so as not to clash with `rand`'s `Distribution::map`, which
+    /// is in scope whenever sampling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{distributions::Distribution as _, rngs::StdRng, SeedableRng};
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let n = Uniform::new(0.0, 1.0).unwrap().map_samples(|x| 2.0 * x + 1.0);
+    /// let x = n.sample(&mut rng);
+    /// assert!((1.0..3.0).contains(&x));
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    fn map_samples<F: Fn(T) -> T>(self, f: F) -> crate::distribution::MappedDistribution<Self, F>
+    where
+        Self: Sized,
+    {
+        crate::distribution::MappedDistribution::new(self, f)
+    }
+    /// Draws `n` samples in parallel, reproducibly for a given master
+    /// `seed`.
+    ///
+    /// The output is split into consecutive chunks of 4096 samples. Chunk
+    /// `i` is filled from its own `StdRng`, seeded with child `i` of a
+    /// [`SeedStream`](crate::rng::SeedStream) with master seed `seed`. The
+    /// samples therefore depend only on `seed` and `n`, not on the number of
+    /// threads or how the chunks are scheduled. They differ from those of a
+    /// single `StdRng` seeded with `seed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rayon")] {
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::Distribution;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// let x = n.par_sample_n(42, 10_000);
+    /// assert_eq!(x.len(), 10_000);
+    /// assert_eq!(x, n.par_sample_n(42, 10_000));
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn par_sample_n(&self, seed: u64, n: usize) -> Vec<T>
+    where
+        Self: ::rand::distributions::Distribution<T> + Sync,
+        T: Send,
+    {
+        use ::rand::rngs::StdRng;
+        use ::rayon::prelude::*;
+
+        const CHUNK_LEN: usize = 4096;
+        let seeds = crate::rng::SeedStream::new(seed);
+        let mut samples = vec![T::zero(); n];
+        samples
+            .par_chunks_mut(CHUNK_LEN)
+            .enumerate()
+            .for_each(|(i, chunk)| {
+                let mut rng: StdRng = seeds.child_rng(i as u64);
+                for x in chunk {
+                    *x = self.sample(&mut rng);
+                }
+            });
+        samples
+    }
+}
+
+/// Classification of a distribution's tail weight relative to the normal
+/// distribution, as returned by [`Distribution::tail_classification`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TailType {
+    /// Negative excess kurtosis: lighter tails than the normal distribution,
+    /// e.g. the uniform distribution
+    Platykurtic,
+    /// Zero excess kurtosis, e.g. the normal distribution
+    Mesokurtic,
+    /// Positive excess kurtosis: heavier tails than the normal distribution,
+    /// e.g. the Laplace distribution
+    Leptokurtic,
 }
 
 /// The `Mean` trait implements the calculation of a mean.
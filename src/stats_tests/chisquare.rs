@@ -113,6 +113,96 @@ pub fn chisquare(
     Ok((stat, pvalue))
 }
 
+/// Perform a binned chi-square goodness-of-fit test of `data` against the
+/// continuous distribution `dist`
+///
+/// Returns the chi-square test statistic and p-value
+///
+/// # Remarks
+///
+/// The range of `data` is split into `bins` equal-width bins, with the
+/// outermost bins extended to the support of `dist` so the expected counts
+/// `n * (cdf(upper) - cdf(lower))` sum to the number of observations. `ddof`
+/// should be set to the number of parameters of `dist` that were estimated
+/// from `data`, giving `bins - 1 - ddof` degrees of freedom. Bins that are
+/// empty and have an expected count of zero, as can happen outside a
+/// bounded support, are left out and do not count towards `bins`.
+///
+/// # Errors
+///
+/// Returns `FObsInvalid` if `bins` is less than 2, or if `data` is empty,
+/// contains non-finite values or has no spread, and `DdofInvalid` if `ddof`
+/// leaves fewer than one degree of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Uniform;
+/// use statrs::stats_tests::chisquare::chi_squared_continuous_gof;
+///
+/// let data: Vec<f64> = (0..100).map(|i| (i as f64 + 0.5) / 100.0).collect();
+/// let (statistic, pvalue) =
+///     chi_squared_continuous_gof(&data, &Uniform::standard(), 10, None).unwrap();
+/// assert!(pvalue > 0.05);
+/// ```
+pub fn chi_squared_continuous_gof<D: ContinuousCDF<f64, f64>>(
+    data: &[f64],
+    dist: &D,
+    bins: usize,
+    ddof: Option<usize>,
+) -> Result<(f64, f64), ChiSquareTestError> {
+    if bins <= 1 || data.is_empty() || data.iter().any(|x| !x.is_finite()) {
+        return Err(ChiSquareTestError::FObsInvalid);
+    }
+    let lo = data.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if lo == hi {
+        return Err(ChiSquareTestError::FObsInvalid);
+    }
+
+    let ddof = ddof.unwrap_or(0);
+    if ddof >= bins - 1 {
+        return Err(ChiSquareTestError::DdofInvalid);
+    }
+
+    let width = (hi - lo) / bins as f64;
+    let mut f_obs = vec![0.0; bins];
+    for &x in data {
+        let i = (((x - lo) / width) as usize).min(bins - 1);
+        f_obs[i] += 1.0;
+    }
+
+    let n = data.len() as f64;
+    let mut lower_cdf = 0.0;
+    let mut stat = 0.0;
+    let mut used = bins;
+    for (i, o) in f_obs.into_iter().enumerate() {
+        let upper_cdf = if i == bins - 1 {
+            1.0
+        } else {
+            dist.cdf(lo + (i + 1) as f64 * width)
+        };
+        let e = n * (upper_cdf - lower_cdf);
+        lower_cdf = upper_cdf;
+        // a bin the distribution gives no mass, with nothing in it, is
+        // dropped rather than contributing 0 / 0
+        if e == 0.0 && o == 0.0 {
+            used -= 1;
+            continue;
+        }
+        stat += (o - e).powi(2) / e;
+    }
+    if ddof >= used.saturating_sub(1) {
+        return Err(ChiSquareTestError::DdofInvalid);
+    }
+    let dof = used - 1 - ddof;
+
+    let chi_dist = ChiSquared::new(dof as f64).expect("ddof validity should already be checked");
+    let pvalue = chi_dist.sf(stat);
+
+    Ok((stat, pvalue))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +264,76 @@ mod tests {
         let result = chisquare(&[16, 18, 16, 14, 12, 12], None, Some(100));
         assert_eq!(result, Err(ChiSquareTestError::DdofInvalid));
     }
+
+    #[test]
+    fn test_continuous_gof_normal() {
+        use crate::distribution::Normal;
+        use crate::statistics::Statistics;
+
+        let truth = Normal::new(3.0, 2.0).unwrap();
+        let data: Vec<f64> = (0..500)
+            .map(|i| truth.inverse_cdf((i as f64 + 0.5) / 500.0))
+            .collect();
+        let fitted = Normal::new(data.iter().mean(), data.iter().std_dev()).unwrap();
+
+        let (statistic, pvalue) = chi_squared_continuous_gof(&data, &fitted, 10, Some(2)).unwrap();
+        assert!(statistic >= 0.0);
+        assert!(pvalue > 0.05);
+    }
+
+    #[test]
+    fn test_continuous_gof_rejects_wrong_distribution() {
+        use crate::distribution::{Normal, Uniform};
+
+        let data: Vec<f64> = (0..500).map(|i| (i as f64 + 0.5) / 500.0).collect();
+        let (_, pvalue) =
+            chi_squared_continuous_gof(&data, &Uniform::standard(), 10, None).unwrap();
+        assert!(pvalue > 0.05);
+        let normal = Normal::new(0.5, 0.1).unwrap();
+        let (_, pvalue) = chi_squared_continuous_gof(&data, &normal, 10, None).unwrap();
+        assert!(pvalue < 1e-6);
+    }
+
+    #[test]
+    fn test_continuous_gof_empty_expected_bins() {
+        use crate::distribution::Normal;
+
+        // the bins between the bulk and the outlier have no expected mass
+        let n = Normal::standard();
+        let mut data: Vec<f64> = (0..500)
+            .map(|i| n.inverse_cdf((i as f64 + 0.5) / 500.0))
+            .collect();
+        data.push(12.0);
+        let (statistic, pvalue) = chi_squared_continuous_gof(&data, &n, 10, None).unwrap();
+        assert_eq!(statistic, f64::INFINITY);
+        assert_eq!(pvalue, 0.0);
+    }
+
+    #[test]
+    fn test_continuous_gof_bad_input() {
+        use crate::distribution::Uniform;
+
+        let u = Uniform::standard();
+        let data = [0.1, 0.2, 0.3, 0.4];
+        assert_eq!(
+            chi_squared_continuous_gof(&data, &u, 1, None),
+            Err(ChiSquareTestError::FObsInvalid)
+        );
+        assert_eq!(
+            chi_squared_continuous_gof(&[], &u, 4, None),
+            Err(ChiSquareTestError::FObsInvalid)
+        );
+        assert_eq!(
+            chi_squared_continuous_gof(&[0.5, f64::NAN], &u, 4, None),
+            Err(ChiSquareTestError::FObsInvalid)
+        );
+        assert_eq!(
+            chi_squared_continuous_gof(&[0.5, 0.5], &u, 4, None),
+            Err(ChiSquareTestError::FObsInvalid)
+        );
+        assert_eq!(
+            chi_squared_continuous_gof(&data, &u, 4, Some(3)),
+            Err(ChiSquareTestError::DdofInvalid)
+        );
+    }
 }